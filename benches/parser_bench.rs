@@ -26,11 +26,18 @@ fn find_largest_jsonl(dir: &Path) -> Option<PathBuf> {
         .max_by_key(|path| std::fs::metadata(path).map(|m| m.len()).unwrap_or(0))
 }
 
+/// First default Claude root that exists on this machine
+fn existing_data_dir(parser: &ClaudeCodeParser) -> Option<&Path> {
+    parser
+        .data_dirs()
+        .iter()
+        .map(PathBuf::as_path)
+        .find(|d| d.exists())
+}
+
 /// Get test file: prefer real Claude data, fallback to fixture
 fn get_bench_file(parser: &ClaudeCodeParser) -> PathBuf {
-    let real_data_dir = parser.data_dir();
-
-    if real_data_dir.exists() {
+    if let Some(real_data_dir) = existing_data_dir(parser) {
         if let Some(largest) = find_largest_jsonl(real_data_dir) {
             let size = std::fs::metadata(&largest).map(|m| m.len()).unwrap_or(0);
             if size > 0 {
@@ -97,12 +104,10 @@ fn bench_parse_line(c: &mut Criterion) {
 
 fn bench_parse_all_files(c: &mut Criterion) {
     let parser = ClaudeCodeParser::new();
-    let data_dir = parser.data_dir();
-
-    if !data_dir.exists() {
+    let Some(data_dir) = existing_data_dir(&parser) else {
         eprintln!("Skipping parse_all_files: no real Claude data found");
         return;
-    }
+    };
 
     let files = find_all_jsonl(data_dir);
     if files.is_empty() {
@@ -146,12 +151,10 @@ fn bench_parse_all_files(c: &mut Criterion) {
 
 fn bench_parse_recent_files(c: &mut Criterion) {
     let parser = ClaudeCodeParser::new();
-    let data_dir = parser.data_dir();
-
-    if !data_dir.exists() {
+    let Some(data_dir) = existing_data_dir(&parser) else {
         eprintln!("Skipping parse_recent_files: no real Claude data found");
         return;
-    }
+    };
 
     let all_files = find_all_jsonl(data_dir);
     if all_files.is_empty() {
@@ -0,0 +1,61 @@
+#!/usr/bin/env bash
+# Refresh the pricing snapshot bundled into the binary (assets/pricing_snapshot.json).
+# Only the models listed in MODELS are kept: the ones Claude Code, Codex CLI, Gemini CLI
+# and OpenCode write to their logs, plus their dated IDs. The full LiteLLM table is
+# several MB, and the snapshot is only the offline fallback, so it stays this small.
+# Add a model here when a parser starts seeing it, then re-run the script.
+# Usage: scripts/update-pricing-snapshot.sh [model_prices_and_context_window.json]
+# Without an argument the price table is downloaded from LiteLLM's main branch.
+set -euo pipefail
+
+URL="https://raw.githubusercontent.com/BerriAI/litellm/main/model_prices_and_context_window.json"
+OUT="$(dirname "$0")/../assets/pricing_snapshot.json"
+
+MODELS=(
+  # Claude
+  claude-3-5-haiku-20241022 claude-3-5-haiku-latest
+  claude-3-5-sonnet-20241022 claude-3-5-sonnet-latest
+  claude-3-7-sonnet-20250219 claude-3-7-sonnet-latest
+  claude-haiku-4-5 claude-haiku-4-5-20251001
+  claude-opus-4 claude-opus-4-20250514
+  claude-opus-4-1 claude-opus-4-1-20250805
+  claude-opus-4-5 claude-opus-4-5-20251101
+  claude-sonnet-4 claude-sonnet-4-20250514
+  claude-sonnet-4-5 claude-sonnet-4-5-20250929
+  # OpenAI
+  codex-mini-latest
+  gpt-4.1 gpt-4.1-2025-04-14
+  gpt-4.1-mini gpt-4.1-mini-2025-04-14
+  gpt-4.1-nano gpt-4.1-nano-2025-04-14
+  gpt-4o gpt-4o-2024-08-06
+  gpt-4o-mini gpt-4o-mini-2024-07-18
+  gpt-5 gpt-5-2025-08-07 gpt-5-codex
+  gpt-5-mini gpt-5-mini-2025-08-07
+  gpt-5-nano gpt-5-nano-2025-08-07
+  gpt-5.1 gpt-5.1-codex gpt-5.1-codex-mini
+  o1 o1-2024-12-17
+  o3 o3-2025-04-16
+  o3-mini o3-mini-2025-01-31
+  o4-mini o4-mini-2025-04-16
+  # Gemini
+  gemini-2.0-flash gemini-2.0-flash-001
+  gemini-2.5-flash gemini-2.5-flash-lite gemini-2.5-pro
+  gemini-3-pro-preview
+)
+
+KEEP="$(printf '%s\n' "${MODELS[@]}" | jq -R . | jq -s .)"
+
+if [ $# -gt 0 ]; then cat "$1"; else curl -fsSL "$URL"; fi | jq \
+  --argjson now "$(date +%s)" --argjson keep "$KEEP" '{
+  fetched_at: $now,
+  models: with_entries(select(.key as $k | $keep | index($k)))
+}' > "$OUT.tmp"
+
+missing=$(jq -r --argjson keep "$KEEP" '$keep - (.models | keys) | join(" ")' "$OUT.tmp")
+if [ -n "$missing" ]; then
+  rm -f "$OUT.tmp"
+  echo "Missing from the LiteLLM table: $missing" >&2
+  exit 1
+fi
+mv "$OUT.tmp" "$OUT"
+echo "Wrote $(jq '.models | length' "$OUT") models to $OUT"
@@ -0,0 +1,315 @@
+//! Rendering for `toktrack config show|init`
+//!
+//! Both outputs are valid TOML: `show` prints the effective value of every key
+//! (marking defaults), `init` writes the same keys commented out.
+
+use std::path::PathBuf;
+
+use crate::parsers::ParserRegistry;
+use crate::services::budget::{Budget, BudgetPeriod};
+use crate::services::config::weekday_name;
+use crate::services::Config;
+
+/// One configurable key
+struct Entry {
+    section: &'static str,
+    key: String,
+    /// Value as a TOML literal
+    value: String,
+    help: &'static str,
+    is_default: bool,
+}
+
+fn toml_string(s: &str) -> String {
+    toml::Value::String(s.to_string()).to_string()
+}
+
+/// A single root as a string, several as an array
+fn toml_paths(paths: &[PathBuf]) -> String {
+    let mut values = paths
+        .iter()
+        .map(|p| toml::Value::String(p.display().to_string()));
+    if paths.len() == 1 {
+        values.next().map(|v| v.to_string()).unwrap_or_default()
+    } else {
+        toml::Value::Array(values.collect()).to_string()
+    }
+}
+
+fn toml_float(f: f64) -> String {
+    toml::Value::Float(f).to_string()
+}
+
+/// Bare key when TOML allows one, quoted otherwise
+fn toml_key(key: &str) -> String {
+    let bare = !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if bare {
+        key.to_string()
+    } else {
+        toml_string(key)
+    }
+}
+
+/// Every key with its effective value
+fn entries(config: &Config) -> Vec<Entry> {
+    let registry = ParserRegistry::from_config(config);
+    let mut entries: Vec<Entry> = registry
+        .parsers()
+        .iter()
+        .map(|p| {
+            let key = p.name().to_string();
+            let is_default = config.is_default(&format!("data_dirs.{}", key));
+            Entry {
+                section: "data_dirs",
+                value: toml_paths(p.data_dirs()),
+                key,
+                help: "",
+                is_default,
+            }
+        })
+        .collect();
+
+    let mut push = |section, key: &str, value, help| {
+        entries.push(Entry {
+            section,
+            key: key.to_string(),
+            value,
+            help,
+            is_default: config.is_default(&format!("{}.{}", section, key)),
+        })
+    };
+    push(
+        "cache",
+        "pricing_ttl_secs",
+        config.pricing_ttl_secs.to_string(),
+        "Seconds before the LiteLLM pricing cache is refreshed",
+    );
+    push(
+        "cache",
+        "warm_window_hours",
+        config.warm_window_hours.to_string(),
+        "Files modified within this many hours are re-parsed on each run",
+    );
+    push(
+        "display",
+        "week_start",
+        toml_string(weekday_name(config.week_start)),
+        "First day of the week for weekly reports and this-week/last-week",
+    );
+    push(
+        "display",
+        "spike_elevated",
+        toml_float(config.spike_elevated),
+        "Daily cost >= average x this ratio is highlighted as elevated",
+    );
+    push(
+        "display",
+        "spike_high",
+        toml_float(config.spike_high),
+        "Daily cost >= average x this ratio is highlighted as high",
+    );
+    for (i, (provider, policy)) in config.provider_costs.iter().enumerate() {
+        entries.push(Entry {
+            section: "provider_costs",
+            key: toml_key(provider),
+            value: toml_string(policy.name()),
+            help: if i == 0 {
+                "How each provider's usage is costed: auto, calculate, reported or free"
+            } else {
+                ""
+            },
+            is_default: config.is_default(&format!("provider_costs.{}", provider)),
+        });
+    }
+    for (i, (tier, multiplier)) in config.service_tiers.iter().enumerate() {
+        entries.push(Entry {
+            section: "service_tiers",
+            key: toml_key(tier),
+            value: toml_float(multiplier),
+            help: if i == 0 {
+                "Token-cost multiplier per service tier; unlisted tiers use list rates"
+            } else {
+                ""
+            },
+            is_default: config.is_default(&format!("service_tiers.{}", tier)),
+        });
+    }
+    entries
+}
+
+/// Group entries under `[section]` headers, rendering each line with `line`
+fn render_sections(entries: &[Entry], line: impl Fn(&Entry) -> String) -> String {
+    let mut out = String::new();
+    let mut section = "";
+    for entry in entries {
+        if entry.section != section {
+            if !section.is_empty() {
+                out.push('\n');
+            }
+            out.push_str(&format!("[{}]\n", entry.section));
+            section = entry.section;
+        }
+        out.push_str(&line(entry));
+    }
+    out
+}
+
+/// `[[budgets]]` tables as TOML lines
+fn budget_lines(budgets: &[Budget]) -> Vec<String> {
+    let mut lines = Vec::new();
+    for budget in budgets {
+        lines.push(String::new());
+        lines.push("[[budgets]]".to_string());
+        lines.push(format!("period = {}", toml_string(budget.period.name())));
+        lines.push(format!("limit_usd = {}", toml_float(budget.limit_usd)));
+        if let Some(source) = &budget.source {
+            lines.push(format!("source = {}", toml_string(source)));
+        }
+        if let Some(model) = &budget.model {
+            lines.push(format!("model = {}", toml_string(model)));
+        }
+    }
+    lines
+}
+
+/// Effective configuration, with defaults marked
+pub fn render_show(config: &Config, path: &str, exists: bool) -> String {
+    let status = if exists {
+        ""
+    } else {
+        " (not found, using defaults)"
+    };
+    let body = render_sections(&entries(config), |e| {
+        let marker = if e.is_default { "  # default" } else { "" };
+        format!("{} = {}{}\n", e.key, e.value, marker)
+    });
+    let budgets: String = budget_lines(&config.budgets)
+        .iter()
+        .map(|l| format!("{}\n", l))
+        .collect();
+    format!("# {}{}\n\n{}{}", path, status, body, budgets)
+}
+
+/// Commented-out template listing every key at its default value
+pub fn render_template() -> String {
+    let body = render_sections(&entries(&Config::default()), |e| {
+        let help = if e.help.is_empty() {
+            String::new()
+        } else {
+            format!("# {}\n", e.help)
+        };
+        format!("{}# {} = {}\n", help, e.key, e.value)
+    });
+    let example = Budget {
+        period: BudgetPeriod::Monthly,
+        limit_usd: 100.0,
+        source: Some("claude-code".into()),
+        model: None,
+    };
+    let budgets: String = budget_lines(&[example])
+        .iter()
+        .map(|l| {
+            if l.is_empty() {
+                "\n".into()
+            } else {
+                format!("# {}\n", l)
+            }
+        })
+        .collect();
+    format!(
+        "# toktrack configuration\n# Uncomment a line to override its default.\n\n{}{}",
+        body,
+        budgets.replacen(
+            "\n# [[budgets]]\n",
+            "\n# Spending limits (period: daily, weekly or monthly; source and model are optional)\n# [[budgets]]\n",
+            1
+        )
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_show_marks_defaults() {
+        let config = Config::from_toml_str("[display]\nweek_start = \"monday\"").unwrap();
+        let out = render_show(&config, "/tmp/config.toml", true);
+        assert!(out.starts_with("# /tmp/config.toml\n"));
+        assert!(out.contains("week_start = \"monday\"\n"));
+        assert!(out.contains("spike_high = 2.0  # default\n"));
+        assert!(out.contains("[data_dirs]\n"));
+        assert!(out.contains("[cache]\n"));
+    }
+
+    #[test]
+    fn test_show_lists_multiple_roots() {
+        let config =
+            Config::from_toml_str("[data_dirs]\ncodex = [\"/a/sessions\", \"/b/sessions\"]")
+                .unwrap();
+        let out = render_show(&config, "/tmp/config.toml", true);
+        assert!(out.contains("codex = [\"/a/sessions\", \"/b/sessions\"]\n"));
+    }
+
+    #[test]
+    fn test_show_round_trips_budgets() {
+        let config = Config::from_toml_str(
+            "[[budgets]]\nperiod = \"daily\"\nlimit_usd = 5\nmodel = \"gpt-5*\"",
+        )
+        .unwrap();
+        let out = render_show(&config, "/tmp/config.toml", true);
+        assert!(out.contains("[[budgets]]\nperiod = \"daily\"\nlimit_usd = 5.0\n"));
+        assert_eq!(Config::from_toml_str(&out).unwrap().budgets, config.budgets);
+    }
+
+    #[test]
+    fn test_show_lists_provider_costs() {
+        let config = Config::from_toml_str(
+            "[provider_costs]\nopenrouter = \"reported\"\n\"azure.openai\" = \"calculate\"",
+        )
+        .unwrap();
+        let out = render_show(&config, "/tmp/config.toml", true);
+        assert!(out.contains("[provider_costs]\n"));
+        assert!(out.contains("\"azure.openai\" = \"calculate\"\n"));
+        assert!(out.contains("github-copilot = \"free\"  # default\n"));
+        assert!(out.contains("openrouter = \"reported\"\n"));
+        let reparsed = Config::from_toml_str(&out).unwrap();
+        assert_eq!(reparsed.provider_costs, config.provider_costs);
+    }
+
+    #[test]
+    fn test_show_lists_service_tiers() {
+        let config = Config::from_toml_str(
+            "[service_tiers]
+priority = 2.0",
+        )
+        .unwrap();
+        let out = render_show(&config, "/tmp/config.toml", true);
+        assert!(out.contains("[service_tiers]\n"));
+        assert!(out.contains("batch = 0.5  # default\n"));
+        assert!(out.contains("priority = 2.0\n"));
+        let reparsed = Config::from_toml_str(&out).unwrap();
+        assert_eq!(reparsed.service_tiers, config.service_tiers);
+    }
+
+    #[test]
+    fn test_show_is_valid_toml() {
+        let out = render_show(&Config::default(), "/tmp/config.toml", false);
+        assert!(out.contains("not found"));
+        let reparsed = Config::from_toml_str(&out).unwrap();
+        assert_eq!(reparsed.week_start, Config::default().week_start);
+    }
+
+    #[test]
+    fn test_template_parses_to_defaults() {
+        let template = render_template();
+        assert!(template.contains("# pricing_ttl_secs = 3600\n"));
+        assert!(template.contains("# week_start = \"sunday\"\n"));
+        assert!(template.contains("[provider_costs]\n# How each provider's usage"));
+        assert!(template.contains("# [[budgets]]\n# period = \"monthly\"\n"));
+        assert_eq!(Config::from_toml_str(&template).unwrap(), Config::default());
+    }
+}
@@ -1,12 +1,27 @@
 //! CLI command handling
 
+mod config;
+mod output;
+
+use std::collections::BTreeMap;
+use std::io::{IsTerminal, Write};
+
+use chrono::Local;
+
 use clap::{Parser, Subcommand};
 
-use crate::services::{Aggregator, DataLoaderService};
-use crate::tui::widgets::daily::DailyViewMode;
+use crate::parsers::ParserRegistry;
+
+use crate::services::{
+    budget, canonical_source, forecast, reprice, Aggregator, BudgetStatus, Config,
+    DailySummaryCacheService, DataLoaderService, DateRange, DateSpec, PricingService, UsageFilter,
+};
+use crate::tui::theme::{SpikeThresholds, Theme};
+use crate::tui::widgets::daily::{DailyData, DailyView, DailyViewMode};
 use crate::tui::widgets::tabs::Tab;
 use crate::tui::TuiConfig;
-use crate::types::{DailySummary, Result, StatsData, ToktrackError};
+use crate::types::{DailySummary, Result, StatsData, ToktrackError, UnpricedUsage};
+use output::{OutputFormat, Period};
 
 /// Ultra-fast AI CLI token usage tracker
 #[derive(Parser)]
@@ -15,6 +30,32 @@ use crate::types::{DailySummary, Result, StatsData, ToktrackError};
 pub struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
+
+    /// Only include usage on or after DATE
+    /// (YYYY-MM-DD, Nd, Nw, today, yesterday, this-week, last-week, this-month, last-month, this-year)
+    #[arg(long, global = true, value_name = "DATE")]
+    since: Option<DateSpec>,
+
+    /// Only include usage on or before DATE (same formats as --since)
+    #[arg(long, global = true, value_name = "DATE")]
+    until: Option<DateSpec>,
+
+    /// Only include these source CLIs (comma-separated, e.g. claude,codex)
+    #[arg(long, global = true, value_name = "SOURCES", value_delimiter = ',')]
+    source: Vec<String>,
+
+    /// Only include models matching these glob patterns (comma-separated, e.g. 'claude-opus*')
+    #[arg(long, global = true, value_name = "PATTERNS", value_delimiter = ',')]
+    model: Vec<String>,
+
+    /// Only include usage served by these providers (comma-separated, e.g. anthropic,amazon-bedrock)
+    #[arg(long, global = true, value_name = "PROVIDERS", value_delimiter = ',')]
+    provider: Vec<String>,
+
+    /// Print plain-text tables instead of launching the TUI
+    /// (automatic when stdout is not a terminal)
+    #[arg(long, global = true)]
+    no_tui: bool,
 }
 
 #[derive(Subcommand)]
@@ -24,138 +65,534 @@ enum Commands {
 
     /// Show daily usage (TUI daily tab, or JSON with --json)
     Daily {
-        /// Output as JSON
+        /// Output as JSON (shorthand for --format json)
         #[arg(long)]
         json: bool,
+
+        /// Output format instead of the TUI
+        #[arg(long, value_enum, value_name = "FORMAT")]
+        format: Option<OutputFormat>,
     },
 
     /// Show usage statistics (TUI stats tab, or JSON with --json)
     Stats {
-        /// Output as JSON
+        /// Output as JSON (shorthand for --format json)
         #[arg(long)]
         json: bool,
+
+        /// Output format instead of the TUI
+        #[arg(long, value_enum, value_name = "FORMAT")]
+        format: Option<OutputFormat>,
     },
 
     /// Show weekly usage (TUI daily tab weekly mode, or JSON with --json)
     Weekly {
-        /// Output as JSON
+        /// Output as JSON (shorthand for --format json)
         #[arg(long)]
         json: bool,
+
+        /// Output format instead of the TUI
+        #[arg(long, value_enum, value_name = "FORMAT")]
+        format: Option<OutputFormat>,
     },
 
     /// Show monthly usage (TUI daily tab monthly mode, or JSON with --json)
     Monthly {
-        /// Output as JSON
+        /// Output as JSON (shorthand for --format json)
+        #[arg(long)]
+        json: bool,
+
+        /// Output format instead of the TUI
+        #[arg(long, value_enum, value_name = "FORMAT")]
+        format: Option<OutputFormat>,
+    },
+
+    /// Show per-session usage (TUI sessions tab, or JSON with --json)
+    Sessions {
+        /// Output as JSON (shorthand for --format json)
+        #[arg(long)]
+        json: bool,
+
+        /// Output format instead of the TUI
+        #[arg(long, value_enum, value_name = "FORMAT")]
+        format: Option<OutputFormat>,
+    },
+
+    /// Show usage per project, most expensive first (TUI: `p` on the Models tab)
+    Projects {
+        /// Output as JSON (shorthand for --format json)
+        #[arg(long)]
+        json: bool,
+
+        /// Output format (default: table)
+        #[arg(long, value_enum, value_name = "FORMAT")]
+        format: Option<OutputFormat>,
+    },
+
+    /// Check spending against the [[budgets]] in the config file
+    Budget {
+        #[command(subcommand)]
+        action: BudgetAction,
+    },
+
+    /// Recompute cached costs from token counts with the current pricing and overrides.
+    /// Prints a per-model before/after diff, then rewrites the caches.
+    /// Honors --since/--until/--source/--model.
+    Reprice {
+        /// Show the diff without rewriting the caches
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Inspect or create the config file (~/.toktrack/config.toml)
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum BudgetAction {
+    /// Show spend for the current period of each budget.
+    /// Exits with status 1 if any budget is exceeded.
+    /// Budgets carry their own source/model scope; report filters are ignored.
+    Status {
+        /// Output as JSON (shorthand for --format json)
         #[arg(long)]
         json: bool,
+
+        /// Output format (default: table)
+        #[arg(long, value_enum, value_name = "FORMAT")]
+        format: Option<OutputFormat>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// Print the effective configuration, marking values left at their defaults
+    Show,
+
+    /// Print the config file path
+    Path,
+
+    /// Write a config file listing every option at its default (commented out)
+    Init {
+        /// Overwrite an existing config file
+        #[arg(long)]
+        force: bool,
     },
 }
 
 impl Cli {
     pub fn run(self) -> anyhow::Result<()> {
+        // Runs before loading the config file so a broken file can still be inspected/replaced
+        if let Some(Commands::Config { action }) = &self.command {
+            return Ok(run_config(action)?);
+        }
+
+        let config = Config::load()?;
+        let filter = self.build_filter(&config)?;
+        let plain = self.no_tui || !std::io::stdout().is_terminal();
+
         match self.command {
-            None | Some(Commands::Tui) => crate::tui::run(TuiConfig::default()),
-            Some(Commands::Daily { json }) => {
-                if json {
-                    Ok(run_daily_json()?)
+            None | Some(Commands::Tui) => {
+                if plain {
+                    Ok(run_plain(filter, &config, DailyViewMode::Daily)?)
+                } else {
+                    crate::tui::run(TuiConfig {
+                        filter,
+                        user_config: config,
+                        ..TuiConfig::default()
+                    })
+                }
+            }
+            Some(Commands::Daily { json, format }) => {
+                if let Some(format) = resolve_format(json, format) {
+                    Ok(run_daily(filter, &config, format)?)
+                } else if plain {
+                    Ok(run_plain(filter, &config, DailyViewMode::Daily)?)
                 } else {
                     crate::tui::run(TuiConfig {
                         initial_tab: Tab::Daily,
                         initial_view_mode: DailyViewMode::Daily,
+                        filter,
+                        user_config: config,
                     })
                 }
             }
-            Some(Commands::Stats { json }) => {
-                if json {
-                    Ok(run_stats_json()?)
+            Some(Commands::Stats { json, format }) => {
+                if let Some(format) = resolve_format(json, format) {
+                    Ok(run_stats(filter, &config, format)?)
+                } else if plain {
+                    Ok(run_stats(filter, &config, OutputFormat::Table)?)
                 } else {
                     crate::tui::run(TuiConfig {
                         initial_tab: Tab::Stats,
                         initial_view_mode: DailyViewMode::default(),
+                        filter,
+                        user_config: config,
                     })
                 }
             }
-            Some(Commands::Weekly { json }) => {
-                if json {
-                    Ok(run_weekly_json()?)
+            Some(Commands::Weekly { json, format }) => {
+                if let Some(format) = resolve_format(json, format) {
+                    Ok(run_weekly(filter, &config, format)?)
+                } else if plain {
+                    Ok(run_plain(filter, &config, DailyViewMode::Weekly)?)
                 } else {
                     crate::tui::run(TuiConfig {
                         initial_tab: Tab::Daily,
                         initial_view_mode: DailyViewMode::Weekly,
+                        filter,
+                        user_config: config,
                     })
                 }
             }
-            Some(Commands::Monthly { json }) => {
-                if json {
-                    Ok(run_monthly_json()?)
+            Some(Commands::Monthly { json, format }) => {
+                if let Some(format) = resolve_format(json, format) {
+                    Ok(run_monthly(filter, &config, format)?)
+                } else if plain {
+                    Ok(run_plain(filter, &config, DailyViewMode::Monthly)?)
                 } else {
                     crate::tui::run(TuiConfig {
                         initial_tab: Tab::Daily,
                         initial_view_mode: DailyViewMode::Monthly,
+                        filter,
+                        user_config: config,
                     })
                 }
             }
+            Some(Commands::Sessions { json, format }) => {
+                if let Some(format) = resolve_format(json, format) {
+                    Ok(run_sessions(filter, &config, format)?)
+                } else if plain {
+                    Ok(run_sessions(filter, &config, OutputFormat::Table)?)
+                } else {
+                    crate::tui::run(TuiConfig {
+                        initial_tab: Tab::Sessions,
+                        initial_view_mode: DailyViewMode::default(),
+                        filter,
+                        user_config: config,
+                    })
+                }
+            }
+            Some(Commands::Projects { json, format }) => {
+                let format = resolve_format(json, format).unwrap_or(OutputFormat::Table);
+                Ok(run_projects(filter, &config, format)?)
+            }
+            Some(Commands::Budget {
+                action: BudgetAction::Status { json, format },
+            }) => {
+                let format = resolve_format(json, format).unwrap_or(OutputFormat::Table);
+                if run_budget_status(&config, format)? {
+                    std::process::exit(1);
+                }
+                Ok(())
+            }
+            Some(Commands::Reprice { dry_run }) => Ok(run_reprice(&filter, &config, dry_run)?),
+            Some(Commands::Config { .. }) => unreachable!("config command handled above"),
+        }
+    }
+
+    /// Build the report filter from --since/--until/--source/--model/--provider
+    fn build_filter(&self, config: &Config) -> Result<UsageFilter> {
+        let date_range = DateRange::from_specs_local(self.since, self.until, config.week_start)?;
+
+        let registry = ParserRegistry::new();
+        for source in &self.source {
+            if registry.get(&canonical_source(source)).is_none() {
+                let known: Vec<&str> = registry.parsers().iter().map(|p| p.name()).collect();
+                return Err(ToktrackError::Config(format!(
+                    "unknown source '{}' (expected one of: {})",
+                    source,
+                    known.join(", ")
+                )));
+            }
         }
+
+        let non_empty = |values: &[String]| -> Option<Vec<String>> {
+            (!values.is_empty()).then(|| values.to_vec())
+        };
+        Ok(UsageFilter {
+            date_range,
+            sources: non_empty(&self.source),
+            models: non_empty(&self.model),
+            providers: non_empty(&self.provider),
+        })
     }
 }
 
 /// Load and process usage data from all CLI parsers.
 /// Uses cache-first strategy via DataLoaderService.
-fn load_data() -> Result<Vec<DailySummary>> {
-    let result = DataLoaderService::new().load()?;
+fn load_data(filter: UsageFilter, config: &Config) -> Result<Vec<DailySummary>> {
+    let result = DataLoaderService::from_config(config)
+        .with_filter(filter)
+        .load()?;
+    warn_unpriced(&result.unpriced);
     Ok(result.summaries)
 }
 
-/// Output daily summaries as JSON
-fn run_daily_json() -> Result<()> {
-    let mut summaries = load_data()?;
-    summaries.sort_by(|a, b| b.date.cmp(&a.date));
-    println!(
+/// Note on stderr that costs are a lower bound, naming the unpriced models
+fn warn_unpriced(unpriced: &UnpricedUsage) {
+    if unpriced.is_empty() {
+        return;
+    }
+    let models: Vec<&str> = unpriced.models.keys().map(String::as_str).collect();
+    eprintln!(
+        "[toktrack] Warning: no pricing for {} ({} tokens); costs are a lower bound",
+        models.join(", "),
+        unpriced.total_tokens
+    );
+}
+
+/// `--format` wins over `--json`; `None` means launch the TUI
+fn resolve_format(json: bool, format: Option<OutputFormat>) -> Option<OutputFormat> {
+    format.or(json.then_some(OutputFormat::Json))
+}
+
+/// Print daily summaries (most recent first)
+fn run_daily(filter: UsageFilter, config: &Config, format: OutputFormat) -> Result<()> {
+    let mut summaries = load_data(filter, config)?;
+    summaries.sort_by_key(|s| std::cmp::Reverse(s.date));
+    print!(
         "{}",
-        serde_json::to_string_pretty(&summaries)
-            .map_err(|e| ToktrackError::Parse(e.to_string()))?
+        output::render_summaries(&summaries, Period::Day, format)?
     );
     Ok(())
 }
 
-/// Output weekly summaries as JSON
-fn run_weekly_json() -> Result<()> {
-    let summaries = load_data()?;
-    let mut weekly = Aggregator::weekly(&summaries);
-    weekly.sort_by(|a, b| b.date.cmp(&a.date));
-    println!(
+/// Print weekly summaries (most recent first)
+fn run_weekly(filter: UsageFilter, config: &Config, format: OutputFormat) -> Result<()> {
+    let summaries = load_data(filter, config)?;
+    let mut weekly = Aggregator::weekly(&summaries, config.week_start);
+    weekly.sort_by_key(|s| std::cmp::Reverse(s.date));
+    print!(
         "{}",
-        serde_json::to_string_pretty(&weekly).map_err(|e| ToktrackError::Parse(e.to_string()))?
+        output::render_summaries(&weekly, Period::Week, format)?
     );
     Ok(())
 }
 
-/// Output monthly summaries as JSON
-fn run_monthly_json() -> Result<()> {
-    let summaries = load_data()?;
+/// Print monthly summaries (most recent first)
+fn run_monthly(filter: UsageFilter, config: &Config, format: OutputFormat) -> Result<()> {
+    let summaries = load_data(filter, config)?;
     let mut monthly = Aggregator::monthly(&summaries);
-    monthly.sort_by(|a, b| b.date.cmp(&a.date));
-    println!(
+    monthly.sort_by_key(|s| std::cmp::Reverse(s.date));
+    print!(
         "{}",
-        serde_json::to_string_pretty(&monthly).map_err(|e| ToktrackError::Parse(e.to_string()))?
+        output::render_summaries(&monthly, Period::Month, format)?
     );
     Ok(())
 }
 
-/// Output stats as JSON
-fn run_stats_json() -> Result<()> {
-    let summaries = load_data()?;
-    let stats = StatsData::from_daily_summaries(&summaries);
+/// Print session summaries (most recent first)
+fn run_sessions(filter: UsageFilter, config: &Config, format: OutputFormat) -> Result<()> {
+    let entries = DataLoaderService::from_config(config)
+        .with_filter(filter)
+        .load_entries()?;
+    let mut sessions = Aggregator::by_session(&entries);
+    sessions.sort_by_key(|s| std::cmp::Reverse(s.start));
+    print!("{}", output::render_sessions(&sessions, format)?);
+    Ok(())
+}
+
+/// Print per-project totals (most expensive first)
+fn run_projects(filter: UsageFilter, config: &Config, format: OutputFormat) -> Result<()> {
+    let summaries = load_data(filter, config)?;
+    let mut projects: Vec<_> = Aggregator::by_project_from_daily(&summaries)
+        .into_iter()
+        .collect();
+    projects.sort_by(|a, b| b.1.cost_usd.total_cmp(&a.1.cost_usd).then(a.0.cmp(&b.0)));
+    print!("{}", output::render_projects(&projects, format)?);
+    Ok(())
+}
+
+/// Print the Daily tab table as plain text (oldest first, like the TUI).
+/// Colored and fitted to the terminal width when stdout is a terminal.
+fn run_plain(filter: UsageFilter, config: &Config, mode: DailyViewMode) -> Result<()> {
+    let summaries = load_data(filter, config)?;
+    let avg_cost = StatsData::from_daily_summaries(&summaries).daily_avg_cost;
+    let data = DailyData::from_daily_summaries(summaries, config.week_start);
+
+    let tty = std::io::stdout().is_terminal();
+    let color = tty && std::env::var_os("NO_COLOR").is_none();
+    let theme = if color {
+        Theme::detect()
+    } else {
+        Theme::default()
+    };
+    let width = if tty {
+        crossterm::terminal::size().map_or(u16::MAX, |(w, _)| w)
+    } else {
+        u16::MAX
+    };
+
+    let view = DailyView::new(&data, 0, mode, theme, avg_cost)
+        .with_spike_thresholds(SpikeThresholds::from_config(config));
+    print!("{}", view.render_plain(width, color));
+    Ok(())
+}
+
+/// Re-cost every cached day in the filter from its token counts.
+/// All caches are repriced before any is written; only caches whose days
+/// changed are rewritten, each atomically.
+fn run_reprice(filter: &UsageFilter, config: &Config, dry_run: bool) -> Result<()> {
+    let pricing = PricingService::from_config(config)
+        .ok_or_else(|| ToktrackError::Pricing("No pricing data available".into()))?;
+    let cache_service = DailySummaryCacheService::new()?;
+
+    let mut changes = BTreeMap::new();
+    let mut repriced = Vec::new();
+    let registry = ParserRegistry::from_config(config);
+    for parser in registry.parsers() {
+        if !filter.matches_source(parser.name()) {
+            continue;
+        }
+        let Some(mut summaries) = cache_service.load_all(parser.name())? else {
+            continue;
+        };
+        let before = summaries.clone();
+        reprice::reprice_summaries(
+            &mut summaries,
+            &pricing,
+            filter,
+            &config.provider_costs,
+            &mut changes,
+        );
+        if summaries != before {
+            repriced.push((parser.name(), summaries));
+        }
+    }
+
+    if changes.is_empty() {
+        println!("No cached usage with known pricing to reprice.");
+        return Ok(());
+    }
+
+    print!("{}", output::render_reprice(&changes));
+    let before: f64 = changes.values().map(|c| c.before_usd).sum();
+    let after: f64 = changes.values().map(|c| c.after_usd).sum();
     println!(
-        "{}",
-        serde_json::to_string_pretty(&stats).map_err(|e| ToktrackError::Parse(e.to_string()))?
+        "\nTotal: ${:.2} -> ${:.2} ({})",
+        before,
+        after,
+        output::format_delta(after - before)
     );
+
+    if dry_run {
+        println!("Dry run: caches left unchanged.");
+        return Ok(());
+    }
+    // Try every cache, so one failure doesn't leave the others unwritten
+    let failed: Vec<String> = repriced
+        .iter()
+        .filter_map(|(source, summaries)| {
+            let result = cache_service.save_cache(source, summaries);
+            result.err().map(|e| format!("{}: {}", source, e))
+        })
+        .collect();
+    if !failed.is_empty() {
+        return Err(ToktrackError::Cache(format!(
+            "failed to rewrite {} of {} cache file(s): {}",
+            failed.len(),
+            repriced.len(),
+            failed.join("; ")
+        )));
+    }
+    println!("Rewrote {} cache file(s).", repriced.len());
+    Ok(())
+}
+
+/// Print each budget's spend for its current period.
+/// Returns whether any budget is exceeded.
+fn run_budget_status(config: &Config, format: OutputFormat) -> Result<bool> {
+    if config.budgets.is_empty() {
+        eprintln!(
+            "No budgets configured; add [[budgets]] to {}",
+            Config::default_path()?.display()
+        );
+        print!("{}", output::render_budgets(&[], format)?);
+        return Ok(false);
+    }
+
+    // Only load as far back as the longest budget period reaches
+    let today = Local::now().date_naive();
+    let since = config
+        .budgets
+        .iter()
+        .map(|b| b.period.span(today, config.week_start).0)
+        .min();
+    let filter = UsageFilter {
+        date_range: DateRange { since, until: None },
+        ..UsageFilter::default()
+    };
+    let result = DataLoaderService::from_config(config)
+        .with_filter(filter)
+        .load()?;
+
+    let statuses = budget::evaluate(
+        &config.budgets,
+        &result.source_summaries,
+        today,
+        config.week_start,
+    );
+    print!("{}", output::render_budgets(&statuses, format)?);
+    std::io::stdout().flush()?;
+    Ok(statuses.iter().any(BudgetStatus::is_exceeded))
+}
+
+/// Handle `toktrack config show|path|init`
+fn run_config(action: &ConfigAction) -> Result<()> {
+    let path = Config::default_path()?;
+    match action {
+        ConfigAction::Path => println!("{}", path.display()),
+        ConfigAction::Show => {
+            let config = Config::load_from(&path)?;
+            print!(
+                "{}",
+                config::render_show(&config, &path.display().to_string(), path.exists())
+            );
+        }
+        ConfigAction::Init { force } => {
+            if path.exists() && !force {
+                return Err(ToktrackError::Config(format!(
+                    "{} already exists (use --force to overwrite)",
+                    path.display()
+                )));
+            }
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&path, config::render_template())?;
+            println!("Wrote {}", path.display());
+        }
+    }
+    Ok(())
+}
+
+/// Print headline stats
+fn run_stats(filter: UsageFilter, config: &Config, format: OutputFormat) -> Result<()> {
+    let range = filter.date_range;
+    let result = DataLoaderService::from_config(config)
+        .with_filter(filter)
+        .load()?;
+    warn_unpriced(&result.unpriced);
+    let summaries = result.summaries;
+    let stats = forecast::with_current(
+        StatsData::from_daily_summaries(&summaries).with_pricing(result.pricing),
+        &summaries,
+        &range,
+        Local::now().date_naive(),
+        config.week_start,
+    );
+    print!("{}", output::render_stats(&stats, format)?);
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::Datelike;
 
     #[test]
     fn test_cli_parse_no_args() {
@@ -166,25 +603,49 @@ mod tests {
     #[test]
     fn test_cli_parse_daily() {
         let cli = Cli::try_parse_from(["toktrack", "daily"]).unwrap();
-        assert!(matches!(cli.command, Some(Commands::Daily { json: false })));
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Daily {
+                json: false,
+                format: None
+            })
+        ));
     }
 
     #[test]
     fn test_cli_parse_daily_json() {
         let cli = Cli::try_parse_from(["toktrack", "daily", "--json"]).unwrap();
-        assert!(matches!(cli.command, Some(Commands::Daily { json: true })));
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Daily {
+                json: true,
+                format: None
+            })
+        ));
     }
 
     #[test]
     fn test_cli_parse_stats() {
         let cli = Cli::try_parse_from(["toktrack", "stats"]).unwrap();
-        assert!(matches!(cli.command, Some(Commands::Stats { json: false })));
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Stats {
+                json: false,
+                format: None
+            })
+        ));
     }
 
     #[test]
     fn test_cli_parse_stats_json() {
         let cli = Cli::try_parse_from(["toktrack", "stats", "--json"]).unwrap();
-        assert!(matches!(cli.command, Some(Commands::Stats { json: true })));
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Stats {
+                json: true,
+                format: None
+            })
+        ));
     }
 
     #[test]
@@ -192,14 +653,23 @@ mod tests {
         let cli = Cli::try_parse_from(["toktrack", "weekly"]).unwrap();
         assert!(matches!(
             cli.command,
-            Some(Commands::Weekly { json: false })
+            Some(Commands::Weekly {
+                json: false,
+                format: None
+            })
         ));
     }
 
     #[test]
     fn test_cli_parse_weekly_json() {
         let cli = Cli::try_parse_from(["toktrack", "weekly", "--json"]).unwrap();
-        assert!(matches!(cli.command, Some(Commands::Weekly { json: true })));
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Weekly {
+                json: true,
+                format: None
+            })
+        ));
     }
 
     #[test]
@@ -207,7 +677,10 @@ mod tests {
         let cli = Cli::try_parse_from(["toktrack", "monthly"]).unwrap();
         assert!(matches!(
             cli.command,
-            Some(Commands::Monthly { json: false })
+            Some(Commands::Monthly {
+                json: false,
+                format: None
+            })
         ));
     }
 
@@ -216,8 +689,244 @@ mod tests {
         let cli = Cli::try_parse_from(["toktrack", "monthly", "--json"]).unwrap();
         assert!(matches!(
             cli.command,
-            Some(Commands::Monthly { json: true })
+            Some(Commands::Monthly {
+                json: true,
+                format: None
+            })
+        ));
+    }
+
+    #[test]
+    fn test_cli_parse_sessions() {
+        let cli = Cli::try_parse_from(["toktrack", "sessions"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Sessions {
+                json: false,
+                format: None
+            })
+        ));
+    }
+
+    #[test]
+    fn test_cli_parse_sessions_json() {
+        let cli = Cli::try_parse_from(["toktrack", "sessions", "--json"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Sessions {
+                json: true,
+                format: None
+            })
+        ));
+    }
+
+    #[test]
+    fn test_cli_parse_projects() {
+        let cli = Cli::try_parse_from(["toktrack", "projects", "--format", "csv"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Projects {
+                json: false,
+                format: Some(OutputFormat::Csv)
+            })
+        ));
+    }
+
+    #[test]
+    fn test_cli_parse_date_range_flags() {
+        let cli = Cli::try_parse_from([
+            "toktrack",
+            "daily",
+            "--json",
+            "--since",
+            "2026-09-01",
+            "--until",
+            "last-week",
+        ])
+        .unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Daily {
+                json: true,
+                format: None
+            })
+        ));
+        assert_eq!(
+            cli.since,
+            Some(DateSpec::Date(
+                chrono::NaiveDate::from_ymd_opt(2026, 9, 1).unwrap()
+            ))
+        );
+        assert_eq!(cli.until, Some(DateSpec::LastWeek));
+    }
+
+    #[test]
+    fn test_cli_parse_since_before_subcommand() {
+        let cli = Cli::try_parse_from(["toktrack", "--since", "7d", "stats"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Stats {
+                json: false,
+                format: None
+            })
+        ));
+        assert_eq!(cli.since, Some(DateSpec::Days(7)));
+        assert!(cli.until.is_none());
+    }
+
+    #[test]
+    fn test_cli_parse_invalid_since_rejected() {
+        assert!(Cli::try_parse_from(["toktrack", "daily", "--since", "someday"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parse_source_and_model_filters() {
+        let cli = Cli::try_parse_from([
+            "toktrack",
+            "daily",
+            "--source",
+            "claude,codex",
+            "--model",
+            "claude-opus*",
+        ])
+        .unwrap();
+        assert_eq!(cli.source, vec!["claude", "codex"]);
+        assert_eq!(cli.model, vec!["claude-opus*"]);
+
+        let filter = cli.build_filter(&Config::default()).unwrap();
+        assert_eq!(
+            filter.sources,
+            Some(vec!["claude".to_string(), "codex".to_string()])
+        );
+        assert_eq!(filter.models, Some(vec!["claude-opus*".to_string()]));
+    }
+
+    #[test]
+    fn test_cli_parse_provider_filter() {
+        let cli = Cli::try_parse_from([
+            "toktrack",
+            "weekly",
+            "--provider",
+            "amazon-bedrock,openrouter",
+        ])
+        .unwrap();
+        let filter = cli.build_filter(&Config::default()).unwrap();
+        assert_eq!(
+            filter.providers,
+            Some(vec!["amazon-bedrock".to_string(), "openrouter".to_string()])
+        );
+        assert_eq!(filter.sources, None);
+    }
+
+    #[test]
+    fn test_cli_build_filter_defaults_to_no_restriction() {
+        let cli = Cli::try_parse_from(["toktrack", "stats"]).unwrap();
+        assert_eq!(
+            cli.build_filter(&Config::default()).unwrap(),
+            UsageFilter::default()
+        );
+    }
+
+    #[test]
+    fn test_cli_build_filter_rejects_unknown_source() {
+        let cli = Cli::try_parse_from(["toktrack", "daily", "--source", "cursor"]).unwrap();
+        let err = cli.build_filter(&Config::default()).unwrap_err();
+        assert!(err.to_string().contains("unknown source 'cursor'"));
+    }
+
+    #[test]
+    fn test_cli_parse_format() {
+        let cli = Cli::try_parse_from(["toktrack", "monthly", "--format", "csv"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Monthly {
+                json: false,
+                format: Some(OutputFormat::Csv)
+            })
+        ));
+        assert!(Cli::try_parse_from(["toktrack", "daily", "--format", "xml"]).is_err());
+    }
+
+    #[test]
+    fn test_resolve_format() {
+        assert_eq!(resolve_format(false, None), None);
+        assert_eq!(resolve_format(true, None), Some(OutputFormat::Json));
+        assert_eq!(
+            resolve_format(true, Some(OutputFormat::Markdown)),
+            Some(OutputFormat::Markdown)
+        );
+    }
+
+    #[test]
+    fn test_cli_parse_no_tui() {
+        let cli = Cli::try_parse_from(["toktrack", "daily", "--no-tui"]).unwrap();
+        assert!(cli.no_tui);
+        let cli = Cli::try_parse_from(["toktrack", "--no-tui"]).unwrap();
+        assert!(cli.no_tui);
+        assert!(cli.command.is_none());
+    }
+
+    #[test]
+    fn test_cli_parse_config_actions() {
+        let cli = Cli::try_parse_from(["toktrack", "config", "show"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Config {
+                action: ConfigAction::Show
+            })
+        ));
+        let cli = Cli::try_parse_from(["toktrack", "config", "init", "--force"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Config {
+                action: ConfigAction::Init { force: true }
+            })
+        ));
+        assert!(Cli::try_parse_from(["toktrack", "config", "edit"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_build_filter_uses_configured_week_start() {
+        let cli = Cli::try_parse_from(["toktrack", "--since", "this-week"]).unwrap();
+        let config = Config {
+            week_start: chrono::Weekday::Mon,
+            ..Config::default()
+        };
+        let since = cli.build_filter(&config).unwrap().date_range.since.unwrap();
+        assert_eq!(since.weekday(), chrono::Weekday::Mon);
+    }
+
+    #[test]
+    fn test_cli_parse_budget_status() {
+        let cli = Cli::try_parse_from(["toktrack", "budget", "status", "--json"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Budget {
+                action: BudgetAction::Status {
+                    json: true,
+                    format: None
+                }
+            })
+        ));
+        assert!(Cli::try_parse_from(["toktrack", "budget"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parse_reprice() {
+        let cli = Cli::try_parse_from(["toktrack", "reprice"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Reprice { dry_run: false })
+        ));
+
+        let cli =
+            Cli::try_parse_from(["toktrack", "reprice", "--since", "2025-01-01", "--dry-run"])
+                .unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Reprice { dry_run: true })
         ));
+        assert!(cli.since.is_some());
     }
 
     #[test]
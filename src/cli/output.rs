@@ -0,0 +1,800 @@
+//! Tabular output formats for report commands
+//!
+//! JSON keeps the nested serde shape; every other format flattens reports
+//! into one row per (period, model) with a fixed column order.
+//! Period reports in JSON are an object: the `summaries` array plus an
+//! `unpriced` section, as in `stats`.
+
+use std::collections::BTreeMap;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::services::budget::{BudgetState, BudgetStatus};
+use crate::services::display_name;
+use crate::services::reprice::CostChange;
+use crate::tui::widgets::overview::format_number;
+use crate::types::{
+    DailySummary, ModelUsage, Result, SessionSummary, StatsData, ToktrackError, UnpricedUsage,
+};
+
+/// Output format for report commands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Json,
+    Csv,
+    Tsv,
+    Markdown,
+    Table,
+}
+
+/// Period granularity, used for the first column name and label
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Period {
+    Day,
+    Week,
+    Month,
+}
+
+impl Period {
+    fn column(self) -> &'static str {
+        match self {
+            Self::Day => "date",
+            Self::Week => "week",
+            Self::Month => "month",
+        }
+    }
+
+    fn label(self, summary: &DailySummary) -> String {
+        match self {
+            Self::Day | Self::Week => summary.date.format("%Y-%m-%d").to_string(),
+            Self::Month => summary.date.format("%Y-%m").to_string(),
+        }
+    }
+}
+
+/// A typed cell; formatting depends on the output format
+#[derive(Debug, Clone, PartialEq)]
+enum Cell {
+    Text(String),
+    Int(u64),
+    Cost(f64),
+    /// Signed cost difference
+    Delta(f64),
+}
+
+impl Cell {
+    /// Raw value for machine-readable formats (CSV/TSV)
+    fn raw(&self) -> String {
+        match self {
+            Self::Text(s) => s.clone(),
+            Self::Int(n) => n.to_string(),
+            Self::Cost(c) => format!("{:.4}", c),
+            Self::Delta(d) => format!("{:+.4}", d),
+        }
+    }
+
+    /// Human-readable value for Markdown/table
+    fn pretty(&self) -> String {
+        match self {
+            Self::Text(s) => s.clone(),
+            Self::Int(n) => format_number(*n),
+            Self::Cost(c) => format!("${:.2}", c),
+            Self::Delta(d) => format_delta(*d),
+        }
+    }
+
+    fn is_numeric(&self) -> bool {
+        !matches!(self, Self::Text(_))
+    }
+}
+
+/// Signed dollar amount, e.g. "+$1.25" or "-$0.40"
+pub fn format_delta(usd: f64) -> String {
+    let sign = if usd < 0.0 { '-' } else { '+' };
+    format!("{}${:.2}", sign, usd.abs())
+}
+
+/// Flattened report: header plus rows of typed cells
+#[derive(Debug, Clone, PartialEq)]
+struct Table {
+    headers: Vec<&'static str>,
+    rows: Vec<Vec<Cell>>,
+}
+
+/// Token and charge columns shared by every per-model row, in output order.
+/// `web_search_usd` and `service_tier_usd` are line items included in `cost_usd`;
+/// the per-type `*_usd` token costs include the service-tier adjustment and add
+/// up to `cost_usd` with `web_search_usd`; `unpriced_tokens` had no known price
+/// and are missing from it.
+const TOKEN_COLUMNS: [&str; 16] = [
+    "input_tokens",
+    "output_tokens",
+    "cache_read_tokens",
+    "cache_creation_tokens",
+    "thinking_tokens",
+    "total_tokens",
+    "web_search_requests",
+    "input_usd",
+    "output_usd",
+    "cache_read_usd",
+    "cache_write_usd",
+    "thinking_usd",
+    "web_search_usd",
+    "service_tier_usd",
+    "cost_usd",
+    "unpriced_tokens",
+];
+
+fn token_cells(usage: &crate::types::ModelUsage) -> [Cell; 16] {
+    let total = usage
+        .input_tokens
+        .saturating_add(usage.output_tokens)
+        .saturating_add(usage.cache_read_tokens)
+        .saturating_add(usage.cache_creation_tokens)
+        .saturating_add(usage.thinking_tokens);
+    [
+        Cell::Int(usage.input_tokens),
+        Cell::Int(usage.output_tokens),
+        Cell::Int(usage.cache_read_tokens),
+        Cell::Int(usage.cache_creation_tokens),
+        Cell::Int(usage.thinking_tokens),
+        Cell::Int(total),
+        Cell::Int(usage.web_search_requests),
+        Cell::Cost(usage.token_costs.input_usd),
+        Cell::Cost(usage.token_costs.output_usd),
+        Cell::Cost(usage.token_costs.cache_read_usd),
+        Cell::Cost(usage.token_costs.cache_write_usd),
+        Cell::Cost(usage.token_costs.thinking_usd),
+        Cell::Cost(usage.web_search_cost_usd),
+        Cell::Cost(usage.service_tier_cost_usd),
+        Cell::Cost(usage.cost_usd),
+        Cell::Int(usage.unpriced_tokens),
+    ]
+}
+
+/// One row per (period, model), keeping the summaries' order and sorting models by name
+fn summaries_table(summaries: &[DailySummary], period: Period) -> Table {
+    let mut headers = vec![period.column(), "model"];
+    headers.extend(TOKEN_COLUMNS);
+
+    let mut rows = Vec::new();
+    for summary in summaries {
+        let label = period.label(summary);
+        let mut models: Vec<_> = summary.models.iter().collect();
+        models.sort_by(|a, b| a.0.cmp(b.0));
+        for (model, usage) in models {
+            let mut row = vec![Cell::Text(label.clone()), Cell::Text(model.clone())];
+            row.extend(token_cells(usage));
+            rows.push(row);
+        }
+    }
+    Table { headers, rows }
+}
+
+/// One row per (session, model)
+fn sessions_table(sessions: &[SessionSummary]) -> Table {
+    let mut headers = vec![
+        "start",
+        "end",
+        "duration_secs",
+        "source",
+        "session_id",
+        "project",
+        "model",
+    ];
+    headers.extend(TOKEN_COLUMNS);
+
+    let mut rows = Vec::new();
+    for session in sessions {
+        let mut models: Vec<_> = session.models.iter().collect();
+        models.sort_by(|a, b| a.0.cmp(b.0));
+        for (model, usage) in models {
+            let mut row = vec![
+                Cell::Text(session.start.to_rfc3339()),
+                Cell::Text(session.end.to_rfc3339()),
+                Cell::Int(session.duration_secs.max(0) as u64),
+                Cell::Text(session.source.clone()),
+                Cell::Text(session.session_id.clone()),
+                Cell::Text(session.project.clone().unwrap_or_default()),
+                Cell::Text(model.clone()),
+            ];
+            row.extend(token_cells(usage));
+            rows.push(row);
+        }
+    }
+    Table { headers, rows }
+}
+
+/// One row per project, in the given order
+fn projects_table(projects: &[(String, ModelUsage)]) -> Table {
+    let mut headers = vec!["project"];
+    headers.extend(TOKEN_COLUMNS);
+
+    let rows = projects
+        .iter()
+        .map(|(project, usage)| {
+            let mut row = vec![Cell::Text(project.clone())];
+            row.extend(token_cells(usage));
+            row
+        })
+        .collect();
+    Table { headers, rows }
+}
+
+/// Single-row table of headline stats
+fn stats_table(stats: &StatsData) -> Table {
+    let (peak_date, peak_tokens) = match stats.peak_day {
+        Some((date, tokens)) => (date.format("%Y-%m-%d").to_string(), tokens),
+        None => (String::new(), 0),
+    };
+    Table {
+        headers: vec![
+            "total_tokens",
+            "daily_avg_tokens",
+            "peak_day",
+            "peak_day_tokens",
+            "total_cost_usd",
+            "daily_avg_cost_usd",
+            "active_days",
+            "unpriced_tokens",
+        ],
+        rows: vec![vec![
+            Cell::Int(stats.total_tokens),
+            Cell::Int(stats.daily_avg_tokens),
+            Cell::Text(peak_date),
+            Cell::Int(peak_tokens),
+            Cell::Cost(stats.total_cost),
+            Cell::Cost(stats.daily_avg_cost),
+            Cell::Int(u64::from(stats.active_days)),
+            Cell::Int(stats.unpriced.total_tokens),
+        ]],
+    }
+}
+
+/// One row per repriced model
+fn reprice_table(changes: &BTreeMap<String, CostChange>) -> Table {
+    Table {
+        headers: vec!["model", "days", "before_usd", "after_usd", "change_usd"],
+        rows: changes
+            .iter()
+            .map(|(model, change)| {
+                vec![
+                    Cell::Text(model.clone()),
+                    Cell::Int(u64::from(change.days)),
+                    Cell::Cost(change.before_usd),
+                    Cell::Cost(change.after_usd),
+                    Cell::Delta(change.delta_usd()),
+                ]
+            })
+            .collect(),
+    }
+}
+
+/// One row per budget
+fn budgets_table(statuses: &[BudgetStatus]) -> Table {
+    Table {
+        headers: vec![
+            "budget",
+            "period_start",
+            "period_end",
+            "limit_usd",
+            "spent_usd",
+            "used_pct",
+            "status",
+        ],
+        rows: statuses
+            .iter()
+            .map(|status| {
+                vec![
+                    Cell::Text(status.budget.label()),
+                    Cell::Text(status.start.format("%Y-%m-%d").to_string()),
+                    Cell::Text(status.end.format("%Y-%m-%d").to_string()),
+                    Cell::Cost(status.budget.limit_usd),
+                    Cell::Cost(status.spent_usd),
+                    Cell::Int((status.ratio() * 100.0).round() as u64),
+                    Cell::Text(state_name(status.state()).to_string()),
+                ]
+            })
+            .collect(),
+    }
+}
+
+fn state_name(state: BudgetState) -> &'static str {
+    match state {
+        BudgetState::Ok => "ok",
+        BudgetState::Warning => "warning",
+        BudgetState::Exceeded => "exceeded",
+    }
+}
+
+/// JSON shape for a period report: the summaries plus tokens left unpriced
+#[derive(Serialize)]
+struct SummariesJson<'a> {
+    summaries: &'a [DailySummary],
+    unpriced: UnpricedUsage,
+}
+
+/// JSON shape for a project: its path plus its usage
+#[derive(Serialize)]
+struct ProjectJson<'a> {
+    project: &'a str,
+    #[serde(flatten)]
+    usage: &'a ModelUsage,
+}
+
+/// JSON shape for a budget: the status plus derived fields
+#[derive(Serialize)]
+struct BudgetJson<'a> {
+    #[serde(flatten)]
+    status: &'a BudgetStatus,
+    used_pct: f64,
+    state: BudgetState,
+}
+
+impl Table {
+    fn render(&self, format: OutputFormat) -> String {
+        match format {
+            // JSON is handled by callers via serde; fall back to CSV shape here
+            OutputFormat::Json | OutputFormat::Csv => self.render_delimited(','),
+            OutputFormat::Tsv => self.render_delimited('\t'),
+            OutputFormat::Markdown => self.render_markdown(),
+            OutputFormat::Table => self.render_aligned(),
+        }
+    }
+
+    fn render_delimited(&self, sep: char) -> String {
+        let escape = |s: &str| -> String {
+            if sep == ',' {
+                // RFC 4180: quote fields containing separators, quotes or newlines
+                if s.contains([',', '"', '\n', '\r']) {
+                    format!("\"{}\"", s.replace('"', "\"\""))
+                } else {
+                    s.to_string()
+                }
+            } else {
+                s.replace(['\t', '\n', '\r'], " ")
+            }
+        };
+        let sep = sep.to_string();
+
+        let mut out = self.headers.join(&sep);
+        out.push('\n');
+        for row in &self.rows {
+            let cells: Vec<String> = row.iter().map(|c| escape(&c.raw())).collect();
+            out.push_str(&cells.join(&sep));
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Whether column `i` holds numbers (right-aligned)
+    fn numeric_column(&self, i: usize) -> bool {
+        self.rows
+            .first()
+            .and_then(|r| r.get(i))
+            .is_some_and(Cell::is_numeric)
+    }
+
+    fn pretty_rows(&self, model_col: Option<usize>) -> Vec<Vec<String>> {
+        self.rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .map(|(i, c)| match c {
+                        Cell::Text(name) if Some(i) == model_col => display_name(name),
+                        _ => c.pretty(),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn model_column(&self) -> Option<usize> {
+        self.headers.iter().position(|h| *h == "model")
+    }
+
+    fn render_markdown(&self) -> String {
+        let escape = |s: &str| s.replace('|', "\\|");
+        let mut out = format!("| {} |\n", self.headers.join(" | "));
+        let align: Vec<&str> = (0..self.headers.len())
+            .map(|i| {
+                if self.numeric_column(i) {
+                    "---:"
+                } else {
+                    "---"
+                }
+            })
+            .collect();
+        out.push_str(&format!("| {} |\n", align.join(" | ")));
+        for row in self.pretty_rows(self.model_column()) {
+            let cells: Vec<String> = row.iter().map(|c| escape(c)).collect();
+            out.push_str(&format!("| {} |\n", cells.join(" | ")));
+        }
+        out
+    }
+
+    fn render_aligned(&self) -> String {
+        let rows = self.pretty_rows(self.model_column());
+        let widths: Vec<usize> = (0..self.headers.len())
+            .map(|i| {
+                rows.iter()
+                    .map(|r| r[i].chars().count())
+                    .chain(std::iter::once(self.headers[i].len()))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        let format_row = |cells: &[String]| -> String {
+            let padded: Vec<String> = cells
+                .iter()
+                .enumerate()
+                .map(|(i, c)| {
+                    if self.numeric_column(i) {
+                        format!("{:>w$}", c, w = widths[i])
+                    } else {
+                        format!("{:<w$}", c, w = widths[i])
+                    }
+                })
+                .collect();
+            padded.join("  ").trim_end().to_string()
+        };
+
+        let headers: Vec<String> = self.headers.iter().map(|h| h.to_string()).collect();
+        let mut out = format_row(&headers);
+        out.push('\n');
+        let rule: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+        out.push_str(&rule.join("  "));
+        out.push('\n');
+        for row in &rows {
+            out.push_str(&format_row(row));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+fn to_json<T: Serialize + ?Sized>(value: &T) -> Result<String> {
+    serde_json::to_string_pretty(value)
+        .map(|json| json + "\n")
+        .map_err(|e| ToktrackError::Parse(e.to_string()))
+}
+
+/// Render daily/weekly/monthly summaries in the given format
+pub fn render_summaries(
+    summaries: &[DailySummary],
+    period: Period,
+    format: OutputFormat,
+) -> Result<String> {
+    match format {
+        OutputFormat::Json => to_json(&SummariesJson {
+            summaries,
+            unpriced: UnpricedUsage::from_daily_summaries(summaries),
+        }),
+        _ => Ok(summaries_table(summaries, period).render(format)),
+    }
+}
+
+/// Render session summaries in the given format
+pub fn render_sessions(sessions: &[SessionSummary], format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => to_json(sessions),
+        _ => Ok(sessions_table(sessions).render(format)),
+    }
+}
+
+/// Render per-project totals in the given format
+pub fn render_projects(projects: &[(String, ModelUsage)], format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => {
+            let json: Vec<ProjectJson> = projects
+                .iter()
+                .map(|(project, usage)| ProjectJson { project, usage })
+                .collect();
+            to_json(&json)
+        }
+        _ => Ok(projects_table(projects).render(format)),
+    }
+}
+
+/// Render stats in the given format
+pub fn render_stats(stats: &StatsData, format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => to_json(stats),
+        _ => Ok(stats_table(stats).render(format)),
+    }
+}
+
+/// Render the per-model cost changes of `toktrack reprice` as a table
+pub fn render_reprice(changes: &BTreeMap<String, CostChange>) -> String {
+    reprice_table(changes).render(OutputFormat::Table)
+}
+
+/// Render budget statuses in the given format
+pub fn render_budgets(statuses: &[BudgetStatus], format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => {
+            let json: Vec<BudgetJson> = statuses
+                .iter()
+                .map(|status| BudgetJson {
+                    status,
+                    used_pct: (status.ratio() * 1000.0).round() / 10.0,
+                    state: status.state(),
+                })
+                .collect();
+            to_json(&json)
+        }
+        _ => Ok(budgets_table(statuses).render(format)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::budget::{Budget, BudgetPeriod};
+    use crate::types::{ModelUsage, PricingInfo, PricingSource, TokenCosts};
+    use chrono::NaiveDate;
+    use std::collections::{BTreeMap, HashMap};
+
+    fn usage(input: u64, output: u64, cost: f64) -> ModelUsage {
+        ModelUsage {
+            input_tokens: input,
+            output_tokens: output,
+            cache_read_tokens: 0,
+            cache_creation_tokens: 0,
+            cache_creation_1h_tokens: 0,
+            thinking_tokens: 0,
+            cost_usd: cost,
+            count: 1,
+            web_search_requests: 0,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            unpriced_tokens: 0,
+            token_costs: TokenCosts {
+                input_usd: cost / 4.0,
+                output_usd: cost * 3.0 / 4.0,
+                ..TokenCosts::default()
+            },
+            cost_reported: false,
+            max_prompt_tokens: None,
+            tier_tokens: BTreeMap::new(),
+        }
+    }
+
+    fn make_summary() -> DailySummary {
+        DailySummary {
+            date: NaiveDate::from_ymd_opt(2026, 9, 1).unwrap(),
+            total_input_tokens: 1500,
+            total_output_tokens: 700,
+            total_cache_read_tokens: 0,
+            total_cache_creation_tokens: 0,
+            total_thinking_tokens: 0,
+            total_cost_usd: 1.25,
+            total_token_costs: TokenCosts::default(),
+            models: HashMap::from([
+                ("gpt-5".to_string(), usage(500, 200, 0.25)),
+                ("claude-sonnet-4".to_string(), usage(1000, 500, 1.0)),
+            ]),
+            projects: HashMap::new(),
+            providers: HashMap::new(),
+            sources: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_csv_one_row_per_model_sorted() {
+        let out = render_summaries(&[make_summary()], Period::Day, OutputFormat::Csv).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(
+            lines[0],
+            "date,model,input_tokens,output_tokens,cache_read_tokens,cache_creation_tokens,thinking_tokens,total_tokens,web_search_requests,input_usd,output_usd,cache_read_usd,cache_write_usd,thinking_usd,web_search_usd,service_tier_usd,cost_usd,unpriced_tokens"
+        );
+        assert_eq!(
+            lines[1],
+            "2026-09-01,claude-sonnet-4,1000,500,0,0,0,1500,0,0.2500,0.7500,0.0000,0.0000,0.0000,0.0000,0.0000,1.0000,0"
+        );
+        assert_eq!(
+            lines[2],
+            "2026-09-01,gpt-5,500,200,0,0,0,700,0,0.0625,0.1875,0.0000,0.0000,0.0000,0.0000,0.0000,0.2500,0"
+        );
+        assert_eq!(lines.len(), 3);
+    }
+
+    #[test]
+    fn test_csv_quotes_special_characters() {
+        let mut summary = make_summary();
+        summary.models = HashMap::from([("odd,\"model\"".to_string(), usage(1, 1, 0.0))]);
+        let out = render_summaries(&[summary], Period::Day, OutputFormat::Csv).unwrap();
+        assert!(out.contains("\"odd,\"\"model\"\"\""));
+    }
+
+    #[test]
+    fn test_tsv_uses_tabs() {
+        let out = render_summaries(&[make_summary()], Period::Month, OutputFormat::Tsv).unwrap();
+        let first_row = out.lines().nth(1).unwrap();
+        assert!(out.starts_with("month\tmodel\t"));
+        assert!(first_row.starts_with("2026-09\tclaude-sonnet-4\t1000\t"));
+    }
+
+    #[test]
+    fn test_markdown_table() {
+        let out =
+            render_summaries(&[make_summary()], Period::Week, OutputFormat::Markdown).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert!(lines[0].starts_with("| week | model | input_tokens"));
+        assert!(lines[1].starts_with("| --- | --- | ---: |"));
+        assert!(lines[2].contains("| 1,000 |"));
+        assert!(lines[2].contains("| $1.00 |"));
+    }
+
+    #[test]
+    fn test_aligned_table_right_aligns_numbers() {
+        let out = render_summaries(&[make_summary()], Period::Day, OutputFormat::Table).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert!(lines[1].starts_with("----------"));
+        // Same width for every line (numbers right-aligned up to the last column)
+        assert_eq!(lines[2].chars().count(), lines[3].chars().count());
+        assert!(lines[3].ends_with(&format!("$0.25  {:>15}", 0)));
+    }
+
+    #[test]
+    fn test_json_keeps_nested_shape() {
+        let out = render_summaries(&[make_summary()], Period::Day, OutputFormat::Json).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&out).unwrap();
+        let summaries: Vec<DailySummary> =
+            serde_json::from_value(parsed["summaries"].clone()).unwrap();
+        assert_eq!(summaries, vec![make_summary()]);
+    }
+
+    #[test]
+    fn test_summaries_json_lists_unpriced_models() {
+        let mut summary = make_summary();
+        let mut internal = usage(800, 200, 0.0);
+        internal.unpriced_tokens = 1000;
+        summary.models.insert("internal-llm".to_string(), internal);
+
+        let out = render_summaries(&[summary], Period::Week, OutputFormat::Json).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(
+            parsed["unpriced"],
+            serde_json::json!({"total_tokens": 1000, "models": {"internal-llm": 1000}})
+        );
+    }
+
+    #[test]
+    fn test_projects_csv_and_json() {
+        let projects = vec![
+            ("/work/my_app".to_string(), usage(1000, 500, 1.0)),
+            ("/work/side".to_string(), usage(500, 200, 0.25)),
+        ];
+        let out = render_projects(&projects, OutputFormat::Csv).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert!(lines[0].starts_with("project,input_tokens,"));
+        assert!(lines[1].starts_with("/work/my_app,1000,500,"));
+        assert_eq!(lines.len(), 3);
+
+        let out = render_projects(&projects, OutputFormat::Json).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(parsed[1]["project"], "/work/side");
+        assert_eq!(parsed[1]["input_tokens"], 500);
+        assert_eq!(parsed[1]["cost_usd"], 0.25);
+    }
+
+    #[test]
+    fn test_stats_csv_single_row() {
+        let stats = StatsData::from_daily_summaries(&[make_summary()]);
+        let out = render_stats(&stats, OutputFormat::Csv).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1], "2200,2200,2026-09-01,2200,1.2500,1.2500,1,0");
+    }
+
+    #[test]
+    fn test_stats_json_reports_pricing_source() {
+        let stats =
+            StatsData::from_daily_summaries(&[make_summary()]).with_pricing(Some(PricingInfo {
+                source: PricingSource::Snapshot,
+                as_of: NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(),
+            }));
+        let out = render_stats(&stats, OutputFormat::Json).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(
+            parsed["pricing"],
+            serde_json::json!({"source": "snapshot", "as_of": "2026-10-01"})
+        );
+    }
+
+    #[test]
+    fn test_stats_json_lists_unpriced_models() {
+        let mut summary = make_summary();
+        let mut internal = usage(800, 200, 0.0);
+        internal.unpriced_tokens = 1000;
+        summary.models.insert("internal-llm".to_string(), internal);
+
+        let stats = StatsData::from_daily_summaries(&[summary]);
+        let out = render_stats(&stats, OutputFormat::Json).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(
+            parsed["unpriced"],
+            serde_json::json!({"total_tokens": 1000, "models": {"internal-llm": 1000}})
+        );
+    }
+
+    #[test]
+    fn test_reprice_table_shows_signed_change() {
+        let changes = BTreeMap::from([
+            (
+                "claude-sonnet-4-5".to_string(),
+                CostChange {
+                    before_usd: 10.0,
+                    after_usd: 8.5,
+                    days: 3,
+                },
+            ),
+            (
+                "gpt-5".to_string(),
+                CostChange {
+                    before_usd: 0.0,
+                    after_usd: 2.25,
+                    days: 1,
+                },
+            ),
+        ]);
+        let out = render_reprice(&changes);
+        let lines: Vec<&str> = out.lines().collect();
+        assert!(lines[0].starts_with("model"));
+        assert!(lines[2].starts_with("Sonnet 4.5"));
+        assert!(lines[2].ends_with("-$1.50"));
+        assert!(lines[3].ends_with("+$2.25"));
+        assert_eq!(format_delta(0.0), "+$0.00");
+    }
+
+    fn budget_status(spent_usd: f64) -> BudgetStatus {
+        BudgetStatus {
+            budget: Budget {
+                period: BudgetPeriod::Monthly,
+                limit_usd: 100.0,
+                source: Some("codex".into()),
+                model: None,
+            },
+            start: NaiveDate::from_ymd_opt(2026, 9, 1).unwrap(),
+            end: NaiveDate::from_ymd_opt(2026, 9, 30).unwrap(),
+            spent_usd,
+        }
+    }
+
+    #[test]
+    fn test_budgets_csv() {
+        let out = render_budgets(&[budget_status(85.0)], OutputFormat::Csv).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(
+            lines[0],
+            "budget,period_start,period_end,limit_usd,spent_usd,used_pct,status"
+        );
+        assert_eq!(
+            lines[1],
+            "monthly codex,2026-09-01,2026-09-30,100.0000,85.0000,85,warning"
+        );
+    }
+
+    #[test]
+    fn test_budgets_json() {
+        let out = render_budgets(&[budget_status(123.45)], OutputFormat::Json).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&out).unwrap();
+        let budget = &parsed[0];
+        assert_eq!(budget["period"], "monthly");
+        assert_eq!(budget["source"], "codex");
+        assert!(budget.get("model").is_none());
+        assert_eq!(budget["start"], "2026-09-01");
+        assert_eq!(budget["used_pct"], 123.5);
+        assert_eq!(budget["state"], "exceeded");
+    }
+
+    #[test]
+    fn test_empty_report_has_header_only() {
+        let out = render_summaries(&[], Period::Day, OutputFormat::Csv).unwrap();
+        assert_eq!(out.lines().count(), 1);
+    }
+}
@@ -1,13 +1,15 @@
 //! Claude Code JSONL parser
 
-use crate::types::{Result, ToktrackError, UsageEntry};
+use crate::types::{Result, TokenCosts, ToktrackError, UsageEntry};
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
-use super::CLIParser;
+use super::{env_dirs, home_dir, CLIParser};
 
 /// Claude Code JSONL line structure (assistant messages with usage)
 #[derive(Deserialize)]
@@ -18,6 +20,8 @@ struct ClaudeJsonLine<'a> {
     message: Option<ClaudeMessage<'a>>,
     #[serde(rename = "costUSD")]
     cost_usd: Option<f64>,
+    /// Working directory of the session, i.e. the project
+    cwd: Option<&'a str>,
 }
 
 #[derive(Deserialize)]
@@ -33,32 +37,87 @@ struct ClaudeUsage {
     output_tokens: u64,
     cache_creation_input_tokens: Option<u64>,
     cache_read_input_tokens: Option<u64>,
+    /// Cache writes split by TTL (newer Claude Code versions)
+    #[serde(default)]
+    cache_creation: Option<ClaudeCacheCreation>,
+    #[serde(default)]
+    server_tool_use: Option<ClaudeServerToolUse>,
+    #[serde(default)]
+    service_tier: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ClaudeServerToolUse {
+    #[serde(default)]
+    web_search_requests: u64,
+}
+
+#[derive(Deserialize)]
+struct ClaudeCacheCreation {
+    #[serde(default)]
+    ephemeral_5m_input_tokens: u64,
+    #[serde(default)]
+    ephemeral_1h_input_tokens: u64,
 }
 
 /// Parser for Claude Code usage data
 pub struct ClaudeCodeParser {
-    data_dir: PathBuf,
+    data_dirs: Vec<PathBuf>,
+    /// Decoded project paths by encoded directory name, for records without
+    /// `cwd`; decoding probes the filesystem and every session file of a
+    /// project shares its directory
+    projects: Mutex<HashMap<String, String>>,
 }
 
 impl ClaudeCodeParser {
-    /// Create a new parser with default data directory (~/.claude/projects/)
+    /// Create a new parser with the default data directories:
+    /// `<dir>/projects` for each entry in `CLAUDE_CONFIG_DIR` (comma-separated) if set,
+    /// otherwise `$XDG_CONFIG_HOME/claude/projects` (~/.config) and `~/.claude/projects`
     pub fn new() -> Self {
-        let home = directories::BaseDirs::new()
-            .map(|d| d.home_dir().to_path_buf())
-            .unwrap_or_else(|| PathBuf::from("."));
-        Self {
-            data_dir: home.join(".claude").join("projects"),
-        }
+        Self::with_data_dirs(default_data_dirs(
+            std::env::var("CLAUDE_CONFIG_DIR").ok().as_deref(),
+            std::env::var("XDG_CONFIG_HOME").ok().as_deref(),
+            &home_dir(),
+        ))
     }
 
     /// Create a parser with a custom data directory (for testing)
     #[allow(dead_code)] // Used in tests
     pub fn with_data_dir(data_dir: PathBuf) -> Self {
-        Self { data_dir }
+        Self::with_data_dirs(vec![data_dir])
+    }
+
+    /// Create a parser scanning several roots (config override)
+    pub fn with_data_dirs(data_dirs: Vec<PathBuf>) -> Self {
+        Self {
+            data_dirs,
+            projects: Mutex::new(HashMap::new()),
+        }
     }
 
-    /// Parse a single JSONL line (zero-copy with borrowed strings)
-    fn parse_line(&self, line: &mut [u8]) -> Option<UsageEntry> {
+    /// Project path for a session file, derived from its top-level directory
+    /// under its root (`<root>/<encoded-project>/<session>.jsonl`).
+    /// Only a fallback: the encoding is lossy, records carry the real path in `cwd`.
+    fn project_for(&self, path: &Path) -> Option<String> {
+        let relative = self
+            .data_dirs
+            .iter()
+            .find_map(|root| path.strip_prefix(root).ok())?;
+        let mut components = relative.components();
+        let project_dir = components.next()?.as_os_str().to_str()?;
+        // Files directly under a root have no project directory
+        components.next()?;
+
+        let mut projects = self.projects.lock().unwrap_or_else(|e| e.into_inner());
+        let project = projects
+            .entry(project_dir.to_string())
+            .or_insert_with(|| decode_project_dir(project_dir));
+        Some(project.clone())
+    }
+
+    /// Parse a single JSONL line (zero-copy with borrowed strings).
+    /// The project is taken from the record's `cwd`, if any.
+    fn parse_line(&self, line: &mut [u8], session_id: Option<&str>) -> Option<UsageEntry> {
         if line.is_empty() {
             return None;
         }
@@ -85,23 +144,125 @@ impl ClaudeCodeParser {
             }
         };
 
+        let cache_creation_1h_tokens = usage
+            .cache_creation
+            .as_ref()
+            .map_or(0, |c| c.ephemeral_1h_input_tokens);
+        let cache_creation_tokens = usage.cache_creation_input_tokens.unwrap_or_else(|| {
+            usage.cache_creation.as_ref().map_or(0, |c| {
+                c.ephemeral_5m_input_tokens + c.ephemeral_1h_input_tokens
+            })
+        });
+
         Some(UsageEntry {
             timestamp,
             model: message.model.map(String::from),
+            // Anthropic reports input excluding cache reads/writes
             input_tokens: usage.input_tokens,
             output_tokens: usage.output_tokens,
             cache_read_tokens: usage.cache_read_input_tokens.unwrap_or(0),
-            cache_creation_tokens: usage.cache_creation_input_tokens.unwrap_or(0),
+            cache_creation_tokens,
+            cache_creation_1h_tokens,
             thinking_tokens: 0,
             cost_usd: data.cost_usd,
             message_id: message.id.map(String::from),
             request_id: data.request_id.map(String::from),
             source: Some("claude".into()),
             provider: None,
+            project: data.cwd.filter(|cwd| !cwd.is_empty()).map(String::from),
+            session_id: session_id.map(String::from),
+            web_search_requests: usage
+                .server_tool_use
+                .as_ref()
+                .map_or(0, |t| t.web_search_requests),
+            service_tier: usage.service_tier.clone(),
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            token_costs: TokenCosts::default(),
+            long_context_tier: None,
+            cost_reported: false,
         })
     }
 }
 
+/// Decode a Claude Code project directory name back into a filesystem path.
+///
+/// Claude Code replaces every character of the working directory other than
+/// letters and digits with `-` (e.g. `/Users/me/my_app` → `-Users-me-my-app`),
+/// which is ambiguous. Existing directories are probed to tell separators from
+/// literal dashes; if the path no longer exists, every dash is treated as a
+/// separator. Only used for records without `cwd`.
+fn decode_project_dir(encoded: &str) -> String {
+    decode_project_dir_with(encoded, |p| p.is_dir())
+}
+
+fn decode_project_dir_with(encoded: &str, is_dir: impl Fn(&Path) -> bool) -> String {
+    let Some(rest) = encoded.strip_prefix('-') else {
+        // Not an absolute Unix path encoding (e.g. Windows drive), keep as-is
+        return encoded.to_string();
+    };
+    if rest.is_empty() {
+        return "/".to_string();
+    }
+
+    let tokens: Vec<&str> = rest.split('-').collect();
+    if let Some(path) = resolve_segments(PathBuf::from("/"), &tokens, &is_dir) {
+        return path.to_string_lossy().into_owned();
+    }
+
+    format!("/{}", rest.replace("--", "/.").replace('-', "/"))
+}
+
+/// Depth-first search for the split of `tokens` into existing directories.
+fn resolve_segments(
+    base: PathBuf,
+    tokens: &[&str],
+    is_dir: &impl Fn(&Path) -> bool,
+) -> Option<PathBuf> {
+    if tokens.is_empty() {
+        return Some(base);
+    }
+
+    for take in 1..=tokens.len() {
+        let joined = tokens[..take].join("-");
+        // An empty token means two separators in a row, i.e. a hidden dir ("/." → "--")
+        let segment = match joined.strip_prefix('-') {
+            Some(hidden) => format!(".{}", hidden),
+            None => joined,
+        };
+        if segment.is_empty() {
+            continue;
+        }
+        let candidate = base.join(&segment);
+        if is_dir(&candidate) {
+            if let Some(found) = resolve_segments(candidate, &tokens[take..], is_dir) {
+                return Some(found);
+            }
+        }
+    }
+
+    None
+}
+
+/// Resolve default roots from `CLAUDE_CONFIG_DIR` / `XDG_CONFIG_HOME`
+fn default_data_dirs(
+    claude_config_dir: Option<&str>,
+    xdg_config_home: Option<&str>,
+    home: &Path,
+) -> Vec<PathBuf> {
+    if let Some(dirs) = env_dirs(claude_config_dir) {
+        return dirs.into_iter().map(|d| d.join("projects")).collect();
+    }
+    let xdg_config = xdg_config_home
+        .filter(|s| !s.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| home.join(".config"));
+    vec![
+        xdg_config.join("claude").join("projects"),
+        home.join(".claude").join("projects"),
+    ]
+}
+
 impl Default for ClaudeCodeParser {
     fn default() -> Self {
         Self::new()
@@ -113,8 +274,8 @@ impl CLIParser for ClaudeCodeParser {
         "claude-code"
     }
 
-    fn data_dir(&self) -> &Path {
-        &self.data_dir
+    fn data_dirs(&self) -> &[PathBuf] {
+        &self.data_dirs
     }
 
     fn file_pattern(&self) -> &str {
@@ -125,6 +286,10 @@ impl CLIParser for ClaudeCodeParser {
         let file = File::open(path).map_err(ToktrackError::Io)?;
         let reader = BufReader::new(file);
         let mut entries = Vec::new();
+        // Decoded from the directory name only if a record lacks `cwd`
+        let mut fallback_project = None;
+        // Each JSONL file holds one session, named by its session UUID
+        let session_id = path.file_stem().and_then(|s| s.to_str());
 
         // Stream line-by-line to avoid loading entire file into memory
         for line_result in reader.lines() {
@@ -139,7 +304,12 @@ impl CLIParser for ClaudeCodeParser {
 
             // Convert to mutable bytes for simd-json
             let mut line_bytes = line.into_bytes();
-            if let Some(entry) = self.parse_line(&mut line_bytes) {
+            if let Some(mut entry) = self.parse_line(&mut line_bytes, session_id) {
+                if entry.project.is_none() {
+                    entry.project = fallback_project
+                        .get_or_insert_with(|| self.project_for(path))
+                        .clone();
+                }
                 entries.push(entry);
             }
         }
@@ -186,6 +356,7 @@ mod tests {
         assert_eq!(first.cache_read_tokens, 20);
         assert_eq!(first.message_id, Some("msg-001".to_string()));
         assert_eq!(first.request_id, Some("req-001".to_string()));
+        assert_eq!(first.session_id, Some("claude-sample".to_string()));
     }
 
     #[test]
@@ -215,6 +386,57 @@ mod tests {
         assert_eq!(third.request_id, None);
     }
 
+    #[test]
+    fn test_parse_cache_creation_ttl_split() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ttl.jsonl");
+        std::fs::write(
+            &path,
+            concat!(
+                r#"{"type":"assistant","timestamp":"2026-01-15T10:00:00.000Z","requestId":"req-1","message":{"model":"claude-sonnet-4-5","id":"msg-1","usage":{"input_tokens":10,"output_tokens":5,"cache_creation_input_tokens":300,"cache_creation":{"ephemeral_5m_input_tokens":100,"ephemeral_1h_input_tokens":200}}}}"#,
+                "\n",
+                r#"{"type":"assistant","timestamp":"2026-01-15T10:01:00.000Z","requestId":"req-2","message":{"model":"claude-sonnet-4-5","id":"msg-2","usage":{"input_tokens":10,"output_tokens":5,"cache_creation":{"ephemeral_5m_input_tokens":40,"ephemeral_1h_input_tokens":60}}}}"#,
+                "\n",
+            ),
+        )
+        .unwrap();
+
+        let parser = ClaudeCodeParser::with_data_dir(dir.path().to_path_buf());
+        let entries = parser.parse_file(&path).unwrap();
+
+        assert_eq!(entries[0].cache_creation_tokens, 300);
+        assert_eq!(entries[0].cache_creation_1h_tokens, 200);
+        // Without the flat total, the breakdown is summed
+        assert_eq!(entries[1].cache_creation_tokens, 100);
+        assert_eq!(entries[1].cache_creation_1h_tokens, 60);
+    }
+
+    #[test]
+    fn test_parse_server_tool_use_and_service_tier() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tools.jsonl");
+        std::fs::write(
+            &path,
+            concat!(
+                r#"{"type":"assistant","timestamp":"2026-01-15T10:00:00.000Z","requestId":"req-1","message":{"model":"claude-sonnet-4-5","id":"msg-1","usage":{"input_tokens":10,"output_tokens":5,"server_tool_use":{"web_search_requests":3},"service_tier":"batch"}}}"#,
+                "\n",
+            ),
+        )
+        .unwrap();
+
+        let parser = ClaudeCodeParser::with_data_dir(dir.path().to_path_buf());
+        let entries = parser.parse_file(&path).unwrap();
+        assert_eq!(entries[0].web_search_requests, 3);
+        assert_eq!(entries[0].service_tier.as_deref(), Some("batch"));
+
+        // Older logs have neither
+        let entries = parser
+            .parse_file(&fixture_path("claude-sample.jsonl"))
+            .unwrap();
+        assert_eq!(entries[0].web_search_requests, 0);
+        assert_eq!(entries[0].service_tier, None);
+    }
+
     #[test]
     fn test_skip_invalid_lines() {
         let parser = ClaudeCodeParser::with_data_dir(PathBuf::from("tests/fixtures"));
@@ -278,6 +500,138 @@ mod tests {
         assert!(entries.is_empty());
     }
 
+    #[test]
+    fn test_project_from_directory() {
+        let parser = ClaudeCodeParser::with_data_dir(PathBuf::from("tests/fixtures"));
+        let entries = parser
+            .parse_file(Path::new("tests/fixtures/multi/file1.jsonl"))
+            .unwrap();
+
+        // "multi" is not a dash-encoded absolute path, so it is kept verbatim
+        assert_eq!(entries[0].project, Some("multi".to_string()));
+    }
+
+    #[test]
+    fn test_project_from_cwd_with_directory_fallback() {
+        let root = tempfile::tempdir().unwrap();
+        let dir = root.path().join("-nonexistent-me-my-app");
+        std::fs::create_dir(&dir).unwrap();
+        let usage = r#""message":{"model":"claude-sonnet-4-5","usage":{"input_tokens":1,"output_tokens":1}}"#;
+        std::fs::write(
+            dir.join("session.jsonl"),
+            format!(
+                "{{\"timestamp\":\"2026-01-15T10:00:00Z\",\"cwd\":\"/nonexistent/me/my_app\",{}}}\n\
+                 {{\"timestamp\":\"2026-01-15T10:01:00Z\",{}}}\n",
+                usage, usage
+            ),
+        )
+        .unwrap();
+
+        let parser = ClaudeCodeParser::with_data_dir(root.path().to_path_buf());
+        let entries = parser.parse_file(&dir.join("session.jsonl")).unwrap();
+
+        // `cwd` keeps the underscore the directory name encodes as a dash
+        assert_eq!(
+            entries[0].project.as_deref(),
+            Some("/nonexistent/me/my_app")
+        );
+        // Records without it fall back to decoding the directory name
+        assert_eq!(
+            entries[1].project.as_deref(),
+            Some("/nonexistent/me/my/app")
+        );
+    }
+
+    #[test]
+    fn test_project_decoded_once_per_directory() {
+        let parser = ClaudeCodeParser::with_data_dir(PathBuf::from("tests/fixtures"));
+        parser
+            .projects
+            .lock()
+            .unwrap()
+            .insert("multi".to_string(), "/cached/project".to_string());
+
+        // Both files reuse the cached decoding instead of probing the filesystem
+        for file in ["file1.jsonl", "file2.jsonl"] {
+            let entries = parser
+                .parse_file(&Path::new("tests/fixtures/multi").join(file))
+                .unwrap();
+            assert_eq!(entries[0].project.as_deref(), Some("/cached/project"));
+        }
+        assert_eq!(parser.projects.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_project_none_for_top_level_file() {
+        let parser = ClaudeCodeParser::with_data_dir(PathBuf::from("tests/fixtures"));
+        let entries = parser
+            .parse_file(Path::new("tests/fixtures/claude-sample.jsonl"))
+            .unwrap();
+
+        assert!(entries.iter().all(|e| e.project.is_none()));
+    }
+
+    #[test]
+    fn test_decode_project_dir_keeps_dashes_of_existing_dirs() {
+        let existing = ["/Users", "/Users/me", "/Users/me/my-app"];
+        let is_dir = |p: &Path| existing.iter().any(|e| Path::new(e) == p);
+
+        assert_eq!(
+            decode_project_dir_with("-Users-me-my-app", is_dir),
+            "/Users/me/my-app"
+        );
+    }
+
+    #[test]
+    fn test_decode_project_dir_hidden_directory() {
+        let existing = ["/Users", "/Users/me", "/Users/me/.config"];
+        let is_dir = |p: &Path| existing.iter().any(|e| Path::new(e) == p);
+
+        assert_eq!(
+            decode_project_dir_with("-Users-me--config", is_dir),
+            "/Users/me/.config"
+        );
+    }
+
+    #[test]
+    fn test_decode_project_dir_missing_path_falls_back() {
+        assert_eq!(
+            decode_project_dir_with("-home-dev-old-repo", |_| false),
+            "/home/dev/old/repo"
+        );
+    }
+
+    #[test]
+    fn test_decode_project_dir_non_unix_kept() {
+        assert_eq!(
+            decode_project_dir_with("C--Users-dev", |_| false),
+            "C--Users-dev"
+        );
+    }
+
+    #[test]
+    fn test_default_data_dirs_from_env() {
+        let home = Path::new("/home/u");
+        assert_eq!(
+            default_data_dirs(Some("/a,/b"), Some("/xdg"), home),
+            vec![PathBuf::from("/a/projects"), PathBuf::from("/b/projects")]
+        );
+        assert_eq!(
+            default_data_dirs(None, Some("/xdg"), home),
+            vec![
+                PathBuf::from("/xdg/claude/projects"),
+                PathBuf::from("/home/u/.claude/projects")
+            ]
+        );
+        assert_eq!(
+            default_data_dirs(Some(""), None, home),
+            vec![
+                PathBuf::from("/home/u/.config/claude/projects"),
+                PathBuf::from("/home/u/.claude/projects")
+            ]
+        );
+    }
+
     #[test]
     fn test_skip_synthetic_model() {
         let parser = ClaudeCodeParser::with_data_dir(PathBuf::from("tests/fixtures"));
@@ -1,13 +1,13 @@
 //! Codex CLI JSONL parser
 
-use crate::types::{Result, ToktrackError, UsageEntry};
+use crate::types::{Result, TokenCosts, ToktrackError, UsageEntry};
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
 
-use super::CLIParser;
+use super::{env_dirs, home_dir, CLIParser};
 
 /// Codex JSONL line types
 #[derive(Deserialize)]
@@ -33,46 +33,82 @@ struct CodexPayload {
 
 #[derive(Deserialize)]
 struct CodexInfo {
+    /// Cumulative usage for the session so far
+    #[serde(default)]
     total_token_usage: Option<CodexTokenUsage>,
+    /// Usage of the latest turn (absent in older Codex versions)
+    #[serde(default)]
+    last_token_usage: Option<CodexTokenUsage>,
 }
 
-#[derive(Deserialize)]
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
 struct CodexTokenUsage {
     input_tokens: u64,
     output_tokens: u64,
     #[serde(default)]
     cached_input_tokens: u64,
+    /// Reasoning tokens, included in `output_tokens`
+    #[serde(default)]
+    reasoning_output_tokens: u64,
+}
+
+impl CodexTokenUsage {
+    fn saturating_sub(self, other: Self) -> Self {
+        Self {
+            input_tokens: self.input_tokens.saturating_sub(other.input_tokens),
+            output_tokens: self.output_tokens.saturating_sub(other.output_tokens),
+            cached_input_tokens: self
+                .cached_input_tokens
+                .saturating_sub(other.cached_input_tokens),
+            reasoning_output_tokens: self
+                .reasoning_output_tokens
+                .saturating_sub(other.reasoning_output_tokens),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// A `token_count` event
+struct TokenCount {
+    timestamp: DateTime<Utc>,
+    /// Raw timestamp, used to identify the turn for dedup
+    raw_timestamp: String,
+    total: Option<CodexTokenUsage>,
+    last: Option<CodexTokenUsage>,
 }
 
 /// Parser for Codex CLI usage data
 pub struct CodexParser {
-    data_dir: PathBuf,
+    data_dirs: Vec<PathBuf>,
 }
 
 impl CodexParser {
-    /// Create a new parser with default data directory (~/.codex/sessions/)
+    /// Create a new parser with the default data directories:
+    /// `<dir>/sessions` for each entry in `CODEX_HOME` (comma-separated) if set,
+    /// otherwise `~/.codex/sessions`
     pub fn new() -> Self {
-        let home = directories::BaseDirs::new()
-            .map(|d| d.home_dir().to_path_buf())
-            .unwrap_or_else(|| PathBuf::from("."));
-        Self {
-            data_dir: home.join(".codex").join("sessions"),
-        }
+        Self::with_data_dirs(default_data_dirs(
+            std::env::var("CODEX_HOME").ok().as_deref(),
+            &home_dir(),
+        ))
     }
 
     /// Create a parser with a custom data directory (for testing)
-    #[allow(dead_code)]
+    #[allow(dead_code)] // Used in tests
     pub fn with_data_dir(data_dir: PathBuf) -> Self {
-        Self { data_dir }
+        Self::with_data_dirs(vec![data_dir])
     }
 
-    /// Parse a single JSONL line and return optional UsageEntry with model info
-    fn parse_line(
-        &self,
-        line: &mut [u8],
-        current_model: &Option<String>,
-        session_id: &Option<String>,
-    ) -> ParseResult {
+    /// Create a parser scanning several roots (config override)
+    pub fn with_data_dirs(data_dirs: Vec<PathBuf>) -> Self {
+        Self { data_dirs }
+    }
+
+    /// Parse a single JSONL line
+    fn parse_line(&self, line: &mut [u8]) -> ParseResult {
         if line.is_empty() {
             return ParseResult::Skip;
         }
@@ -123,10 +159,9 @@ impl CodexParser {
             None => return ParseResult::Skip,
         };
 
-        let usage = match &info.total_token_usage {
-            Some(u) => u,
-            None => return ParseResult::Skip,
-        };
+        if info.total_token_usage.is_none() && info.last_token_usage.is_none() {
+            return ParseResult::Skip;
+        }
 
         let timestamp = match DateTime::parse_from_rfc3339(data.timestamp) {
             Ok(dt) => dt.with_timezone(&Utc),
@@ -139,22 +174,51 @@ impl CodexParser {
             }
         };
 
-        let entry = UsageEntry {
+        ParseResult::TokenCount(Box::new(TokenCount {
             timestamp,
-            model: current_model.clone(),
-            input_tokens: usage.input_tokens,
-            output_tokens: usage.output_tokens,
-            cache_read_tokens: usage.cached_input_tokens,
-            cache_creation_tokens: 0,
-            thinking_tokens: 0,
-            cost_usd: None,
-            message_id: session_id.clone(),
-            request_id: None,
-            source: Some("codex".into()),
-            provider: None,
-        };
+            raw_timestamp: data.timestamp.to_string(),
+            total: info.total_token_usage,
+            last: info.last_token_usage,
+        }))
+    }
+}
 
-        ParseResult::Entry(entry)
+/// Usage entry for one turn.
+///
+/// Codex (OpenAI) input includes cached tokens and output includes reasoning;
+/// both are split out into the canonical disjoint fields.
+fn turn_entry(
+    count: &TokenCount,
+    usage: CodexTokenUsage,
+    model: &Option<String>,
+    session_id: &Option<String>,
+) -> UsageEntry {
+    UsageEntry {
+        timestamp: count.timestamp,
+        model: model.clone(),
+        input_tokens: usage.input_tokens.saturating_sub(usage.cached_input_tokens),
+        output_tokens: usage
+            .output_tokens
+            .saturating_sub(usage.reasoning_output_tokens),
+        cache_read_tokens: usage.cached_input_tokens,
+        cache_creation_tokens: 0,
+        cache_creation_1h_tokens: 0,
+        thinking_tokens: usage.reasoning_output_tokens,
+        cost_usd: None,
+        // session + turn timestamp identifies a turn across copies of the session
+        message_id: session_id.clone(),
+        request_id: Some(count.raw_timestamp.clone()),
+        source: Some("codex".into()),
+        provider: None,
+        project: None,
+        session_id: session_id.clone(),
+        web_search_requests: 0,
+        service_tier: None,
+        web_search_cost_usd: 0.0,
+        service_tier_cost_usd: 0.0,
+        token_costs: TokenCosts::default(),
+        long_context_tier: None,
+        cost_reported: false,
     }
 }
 
@@ -163,7 +227,16 @@ enum ParseResult {
     Skip,
     Model(String),
     SessionId(String),
-    Entry(UsageEntry),
+    TokenCount(Box<TokenCount>),
+}
+
+/// Resolve default roots from `CODEX_HOME`
+fn default_data_dirs(codex_home: Option<&str>, home: &Path) -> Vec<PathBuf> {
+    env_dirs(codex_home)
+        .unwrap_or_else(|| vec![home.join(".codex")])
+        .into_iter()
+        .map(|d| d.join("sessions"))
+        .collect()
 }
 
 impl Default for CodexParser {
@@ -177,8 +250,8 @@ impl CLIParser for CodexParser {
         "codex"
     }
 
-    fn data_dir(&self) -> &Path {
-        &self.data_dir
+    fn data_dirs(&self) -> &[PathBuf] {
+        &self.data_dirs
     }
 
     fn file_pattern(&self) -> &str {
@@ -188,7 +261,8 @@ impl CLIParser for CodexParser {
     fn parse_file(&self, path: &Path) -> Result<Vec<UsageEntry>> {
         let file = File::open(path).map_err(ToktrackError::Io)?;
         let reader = BufReader::new(file);
-        let mut last_entry: Option<UsageEntry> = None;
+        let mut entries = Vec::new();
+        let mut previous_total: Option<CodexTokenUsage> = None;
         let mut current_model: Option<String> = None;
         let mut session_id: Option<String> = None;
 
@@ -203,17 +277,36 @@ impl CLIParser for CodexParser {
             }
 
             let mut line_bytes = line.into_bytes();
-            match self.parse_line(&mut line_bytes, &current_model, &session_id) {
+            match self.parse_line(&mut line_bytes) {
                 ParseResult::Skip => {}
                 ParseResult::Model(m) => current_model = Some(m),
                 ParseResult::SessionId(id) => session_id = Some(id),
-                // total_token_usage is cumulative; keep only the last
-                // token_count event which holds the session total.
-                ParseResult::Entry(entry) => last_entry = Some(entry),
+                ParseResult::TokenCount(count) => {
+                    // Codex repeats token_count events without a new turn;
+                    // an unchanged cumulative total means nothing was used.
+                    if count.total.is_some() && count.total == previous_total {
+                        continue;
+                    }
+                    // Prefer the per-turn delta; older logs only carry the
+                    // cumulative total, so diff it against the previous one.
+                    let usage = match (count.last, count.total) {
+                        (Some(last), _) => last,
+                        (None, Some(total)) => {
+                            total.saturating_sub(previous_total.unwrap_or_default())
+                        }
+                        (None, None) => continue,
+                    };
+                    if count.total.is_some() {
+                        previous_total = count.total;
+                    }
+                    if !usage.is_empty() {
+                        entries.push(turn_entry(&count, usage, &current_model, &session_id));
+                    }
+                }
             }
         }
 
-        Ok(last_entry.into_iter().collect())
+        Ok(entries)
     }
 }
 
@@ -231,35 +324,95 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_codex_jsonl_uses_last_cumulative_total() {
+    fn test_parse_emits_one_entry_per_turn() {
         let parser = CodexParser::with_data_dir(PathBuf::from("tests/fixtures/codex"));
         let entries = parser
-            .parse_file(&fixture_path("sample-session.jsonl"))
+            .parse_file(&fixture_path("turns-session.jsonl"))
+            .unwrap();
+
+        // The repeated token_count event with an unchanged total is skipped
+        assert_eq!(entries.len(), 2);
+
+        let first = &entries[0];
+        assert_eq!(first.model, Some("gpt-5-codex".to_string()));
+        // Cached input and reasoning are split out of input and output
+        assert_eq!(first.input_tokens, 800);
+        assert_eq!(first.cache_read_tokens, 200);
+        assert_eq!(first.output_tokens, 200);
+        assert_eq!(first.thinking_tokens, 100);
+        assert_eq!(first.source, Some("codex".into()));
+        assert_eq!(first.session_id, Some("session-002".to_string()));
+
+        // Second turn uses last_token_usage and the model switched to before it
+        let second = &entries[1];
+        assert_eq!(second.model, Some("gpt-5".to_string()));
+        assert_eq!(second.input_tokens, 300);
+        assert_eq!(second.cache_read_tokens, 300);
+        assert_eq!(second.output_tokens, 100);
+        assert_eq!(second.thinking_tokens, 50);
+    }
+
+    #[test]
+    fn test_turns_spanning_midnight_keep_their_own_dates() {
+        let parser = CodexParser::with_data_dir(PathBuf::from("tests/fixtures/codex"));
+        let entries = parser
+            .parse_file(&fixture_path("turns-session.jsonl"))
             .unwrap();
 
-        // total_token_usage is cumulative; only the last token_count
-        // event should be emitted per session file.
-        assert_eq!(entries.len(), 1);
+        let dates: Vec<_> = entries.iter().map(|e| e.timestamp.date_naive()).collect();
+        assert_eq!(
+            dates,
+            vec![
+                chrono::NaiveDate::from_ymd_opt(2026, 1, 15).unwrap(),
+                chrono::NaiveDate::from_ymd_opt(2026, 1, 16).unwrap()
+            ]
+        );
     }
 
     #[test]
-    fn test_parse_last_entry_has_final_totals() {
+    fn test_turns_have_distinct_dedup_hashes() {
+        let parser = CodexParser::with_data_dir(PathBuf::from("tests/fixtures/codex"));
+        let entries = parser
+            .parse_file(&fixture_path("turns-session.jsonl"))
+            .unwrap();
+
+        let first = entries[0].dedup_hash().unwrap();
+        let second = entries[1].dedup_hash().unwrap();
+        assert_ne!(first, second);
+
+        // Re-parsing (e.g. a copy under another root) yields the same hashes
+        let again = parser
+            .parse_file(&fixture_path("turns-session.jsonl"))
+            .unwrap();
+        assert_eq!(again[0].dedup_hash().unwrap(), first);
+    }
+
+    #[test]
+    fn test_parse_diffs_cumulative_totals_without_last_usage() {
         let parser = CodexParser::with_data_dir(PathBuf::from("tests/fixtures/codex"));
         let entries = parser
             .parse_file(&fixture_path("sample-session.jsonl"))
             .unwrap();
 
-        // The single entry should carry the last cumulative totals
-        // and the model active at that point.
-        let entry = &entries[0];
-        assert_eq!(entry.model, Some("gpt-4.1".to_string()));
-        assert_eq!(entry.input_tokens, 500);
-        assert_eq!(entry.output_tokens, 200);
-        assert_eq!(entry.cache_read_tokens, 100);
-        assert_eq!(entry.cache_creation_tokens, 0);
-        assert_eq!(entry.thinking_tokens, 0);
-        assert_eq!(entry.source, Some("codex".into()));
-        assert_eq!(entry.message_id, Some("session-001".to_string()));
+        // Older logs only carry total_token_usage; each turn is the
+        // difference from the previous total, under the model active then.
+        assert_eq!(entries.len(), 2);
+
+        let first = &entries[0];
+        assert_eq!(first.model, Some("o4-mini".to_string()));
+        assert_eq!(first.input_tokens, 125);
+        assert_eq!(first.output_tokens, 75);
+        assert_eq!(first.cache_read_tokens, 25);
+
+        let second = &entries[1];
+        assert_eq!(second.model, Some("gpt-4.1".to_string()));
+        assert_eq!(second.input_tokens, 275);
+        assert_eq!(second.output_tokens, 125);
+        assert_eq!(second.cache_read_tokens, 75);
+        assert_eq!(second.cache_creation_tokens, 0);
+        assert_eq!(second.thinking_tokens, 0);
+        assert_eq!(second.message_id, Some("session-001".to_string()));
+        assert_eq!(second.session_id, Some("session-001".to_string()));
     }
 
     #[test]
@@ -269,9 +422,8 @@ mod tests {
             .parse_file(&fixture_path("sample-session.jsonl"))
             .unwrap();
 
-        // Invalid JSON line and other event types should be skipped;
-        // only one entry (last cumulative total) should remain.
-        assert_eq!(entries.len(), 1);
+        // Invalid JSON line and other event types should be skipped
+        assert_eq!(entries.len(), 2);
     }
 
     #[test]
@@ -286,6 +438,22 @@ mod tests {
         assert_eq!(parser.file_pattern(), "**/*.jsonl");
     }
 
+    #[test]
+    fn test_default_data_dirs_from_env() {
+        let home = Path::new("/home/u");
+        assert_eq!(
+            default_data_dirs(None, home),
+            vec![PathBuf::from("/home/u/.codex/sessions")]
+        );
+        assert_eq!(
+            default_data_dirs(Some("/work/codex, /old/codex"), home),
+            vec![
+                PathBuf::from("/work/codex/sessions"),
+                PathBuf::from("/old/codex/sessions")
+            ]
+        );
+    }
+
     #[test]
     fn test_parse_nonexistent_file() {
         let parser = CodexParser::new();
@@ -1,6 +1,6 @@
 //! Gemini CLI JSON parser
 
-use crate::types::{Result, ToktrackError, UsageEntry};
+use crate::types::{Result, TokenCosts, ToktrackError, UsageEntry};
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use std::fs;
@@ -8,7 +8,7 @@ use std::path::{Path, PathBuf};
 
 // Using simd_json for consistency with other parsers
 
-use super::CLIParser;
+use super::{home_dir, CLIParser};
 
 /// Gemini session JSON structure
 #[derive(Deserialize)]
@@ -42,24 +42,23 @@ struct GeminiTokens {
 
 /// Parser for Gemini CLI usage data
 pub struct GeminiParser {
-    data_dir: PathBuf,
+    data_dirs: Vec<PathBuf>,
 }
 
 impl GeminiParser {
     /// Create a new parser with default data directory (~/.gemini/tmp/)
     pub fn new() -> Self {
-        let home = directories::BaseDirs::new()
-            .map(|d| d.home_dir().to_path_buf())
-            .unwrap_or_else(|| PathBuf::from("."));
-        Self {
-            data_dir: home.join(".gemini").join("tmp"),
-        }
+        Self::with_data_dir(home_dir().join(".gemini").join("tmp"))
     }
 
     /// Create a parser with a custom data directory (for testing)
-    #[allow(dead_code)]
     pub fn with_data_dir(data_dir: PathBuf) -> Self {
-        Self { data_dir }
+        Self::with_data_dirs(vec![data_dir])
+    }
+
+    /// Create a parser scanning several roots (config override)
+    pub fn with_data_dirs(data_dirs: Vec<PathBuf>) -> Self {
+        Self { data_dirs }
     }
 }
 
@@ -74,8 +73,8 @@ impl CLIParser for GeminiParser {
         "gemini"
     }
 
-    fn data_dir(&self) -> &Path {
-        &self.data_dir
+    fn data_dirs(&self) -> &[PathBuf] {
+        &self.data_dirs
     }
 
     fn file_pattern(&self) -> &str {
@@ -116,16 +115,28 @@ impl CLIParser for GeminiParser {
             entries.push(UsageEntry {
                 timestamp,
                 model: msg.model.clone().or_else(|| session.model.clone()),
-                input_tokens: tokens.input,
+                // Gemini's prompt count includes cached tokens; thoughts
+                // are already reported separately from output.
+                input_tokens: tokens.input.saturating_sub(tokens.cached),
                 output_tokens: tokens.output,
                 cache_read_tokens: tokens.cached,
                 cache_creation_tokens: 0,
+                cache_creation_1h_tokens: 0,
                 thinking_tokens: tokens.thoughts,
                 cost_usd: None,
                 message_id: Some(msg.id),
                 request_id: Some(session.session_id.clone()),
                 source: Some("gemini".into()),
                 provider: None,
+                project: None,
+                session_id: Some(session.session_id.clone()),
+                web_search_requests: 0,
+                service_tier: None,
+                web_search_cost_usd: 0.0,
+                service_tier_cost_usd: 0.0,
+                token_costs: TokenCosts::default(),
+                long_context_tier: None,
+                cost_reported: false,
             });
         }
 
@@ -164,7 +175,8 @@ mod tests {
 
         let first = &entries[0];
         assert_eq!(first.model, Some("gemini-2.5-pro".to_string()));
-        assert_eq!(first.input_tokens, 100);
+        // Prompt count 100 includes the 20 cached tokens
+        assert_eq!(first.input_tokens, 80);
         assert_eq!(first.output_tokens, 50);
         assert_eq!(first.cache_read_tokens, 20);
         assert_eq!(first.cache_creation_tokens, 0);
@@ -172,6 +184,7 @@ mod tests {
         assert_eq!(first.source, Some("gemini".into()));
         assert_eq!(first.message_id, Some("msg-002".to_string()));
         assert_eq!(first.request_id, Some("abc123".to_string()));
+        assert_eq!(first.session_id, Some("abc123".to_string()));
     }
 
     #[test]
@@ -180,7 +193,7 @@ mod tests {
         let entries = parser.parse_file(&fixture_path()).unwrap();
 
         let second = &entries[1];
-        assert_eq!(second.input_tokens, 250);
+        assert_eq!(second.input_tokens, 200);
         assert_eq!(second.output_tokens, 150);
         assert_eq!(second.cache_read_tokens, 50);
         assert_eq!(second.thinking_tokens, 100);
@@ -220,11 +233,11 @@ mod tests {
         let parser = GeminiParser::with_data_dir(PathBuf::from("tests/fixtures/gemini"));
         let entries = parser.parse_file(&fixture_path()).unwrap();
 
-        // First entry: 100 + 50 + 20 + 0 + 30 = 200
-        assert_eq!(entries[0].total_tokens(), 200);
+        // First entry: 80 + 50 + 20 + 0 + 30 = 180
+        assert_eq!(entries[0].total_tokens(), 180);
 
-        // Second entry: 250 + 150 + 50 + 0 + 100 = 550
-        assert_eq!(entries[1].total_tokens(), 550);
+        // Second entry: 200 + 150 + 50 + 0 + 100 = 500
+        assert_eq!(entries[1].total_tokens(), 500);
     }
 
     fn fixture_no_session_model_path() -> PathBuf {
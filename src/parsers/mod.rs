@@ -10,6 +10,7 @@ pub use codex::CodexParser;
 pub use gemini::GeminiParser;
 pub use opencode::OpenCodeParser;
 
+use crate::services::config::Config;
 use crate::types::{Result, UsageEntry};
 use rayon::prelude::*;
 use std::collections::HashSet;
@@ -22,8 +23,8 @@ pub trait CLIParser: Send + Sync {
     #[allow(dead_code)] // Part of trait API, used in tests
     fn name(&self) -> &str;
 
-    /// Data directory to scan for usage files
-    fn data_dir(&self) -> &Path;
+    /// Root directories to scan for usage files
+    fn data_dirs(&self) -> &[PathBuf];
 
     /// Glob pattern for finding usage files (e.g., "**/*.jsonl")
     fn file_pattern(&self) -> &str;
@@ -53,12 +54,42 @@ pub trait CLIParser: Send + Sync {
         Self::parse_and_dedup(self, &recent)
     }
 
-    /// Collect all files matching the glob pattern
+    /// Collect all files matching the glob pattern under every root.
+    /// With multiple roots, a file reachable from two roots (overlapping or symlinked)
+    /// or a same-size copy at the same relative path is only collected once.
     fn collect_files(&self) -> Vec<PathBuf> {
-        let pattern = self.data_dir().join(self.file_pattern());
-        glob::glob(&pattern.to_string_lossy())
-            .map(|paths| paths.filter_map(|e| e.ok()).collect())
-            .unwrap_or_default()
+        let roots = self.data_dirs();
+        let glob_root = |root: &PathBuf| -> Vec<PathBuf> {
+            let pattern = root.join(self.file_pattern());
+            glob::glob(&pattern.to_string_lossy())
+                .map(|paths| paths.filter_map(|e| e.ok()).collect())
+                .unwrap_or_default()
+        };
+
+        if roots.len() == 1 {
+            return glob_root(&roots[0]);
+        }
+
+        let mut seen_paths: HashSet<PathBuf> = HashSet::new();
+        let mut seen_copies: HashSet<(PathBuf, u64)> = HashSet::new();
+        let mut files = Vec::new();
+        for root in roots {
+            for path in glob_root(root) {
+                let canonical = path.canonicalize().unwrap_or_else(|_| path.clone());
+                if !seen_paths.insert(canonical) {
+                    continue;
+                }
+                let relative = path.strip_prefix(root).map(Path::to_path_buf);
+                let len = path.metadata().map(|m| m.len());
+                if let (Ok(relative), Ok(len)) = (relative, len) {
+                    if !seen_copies.insert((relative, len)) {
+                        continue;
+                    }
+                }
+                files.push(path);
+            }
+        }
+        files
     }
 
     /// Parse files in parallel and deduplicate
@@ -94,6 +125,25 @@ pub trait CLIParser: Send + Sync {
     }
 }
 
+/// Home directory, falling back to the current directory
+pub(crate) fn home_dir() -> PathBuf {
+    directories::BaseDirs::new()
+        .map(|d| d.home_dir().to_path_buf())
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// Split a comma-separated directory list from an environment variable.
+/// Returns `None` when the variable is unset or contains no paths.
+pub(crate) fn env_dirs(value: Option<&str>) -> Option<Vec<PathBuf>> {
+    let dirs: Vec<PathBuf> = value?
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(PathBuf::from)
+        .collect();
+    (!dirs.is_empty()).then_some(dirs)
+}
+
 /// Registry of available parsers
 pub struct ParserRegistry {
     parsers: Vec<Box<dyn CLIParser>>,
@@ -112,6 +162,28 @@ impl ParserRegistry {
         }
     }
 
+    /// Create a registry using the data directories from the user config,
+    /// falling back to each parser's default (env-derived) directories
+    pub fn from_config(config: &Config) -> Self {
+        let dir = |name: &str| config.data_dirs.get(name).cloned();
+        Self {
+            parsers: vec![
+                Box::new(
+                    dir("claude-code")
+                        .map_or_else(ClaudeCodeParser::new, ClaudeCodeParser::with_data_dirs),
+                ),
+                Box::new(dir("codex").map_or_else(CodexParser::new, CodexParser::with_data_dirs)),
+                Box::new(
+                    dir("gemini").map_or_else(GeminiParser::new, GeminiParser::with_data_dirs),
+                ),
+                Box::new(
+                    dir("opencode")
+                        .map_or_else(OpenCodeParser::new, OpenCodeParser::with_data_dirs),
+                ),
+            ],
+        }
+    }
+
     /// Get all registered parsers
     pub fn parsers(&self) -> &[Box<dyn CLIParser>] {
         &self.parsers
@@ -147,6 +219,64 @@ mod tests {
         assert!(registry.get("opencode").is_some());
     }
 
+    #[test]
+    fn test_registry_from_config_overrides_data_dir() {
+        let mut config = Config::default();
+        config.data_dirs.insert(
+            "codex".into(),
+            vec![
+                PathBuf::from("/custom/codex"),
+                PathBuf::from("/backup/codex"),
+            ],
+        );
+        let registry = ParserRegistry::from_config(&config);
+        assert_eq!(
+            registry.get("codex").unwrap().data_dirs(),
+            [
+                PathBuf::from("/custom/codex"),
+                PathBuf::from("/backup/codex")
+            ]
+        );
+        assert_eq!(
+            registry.get("gemini").unwrap().data_dirs(),
+            ParserRegistry::new().get("gemini").unwrap().data_dirs()
+        );
+    }
+
+    #[test]
+    fn test_env_dirs() {
+        assert_eq!(env_dirs(None), None);
+        assert_eq!(env_dirs(Some(" , ")), None);
+        assert_eq!(
+            env_dirs(Some("/a, /b,")),
+            Some(vec![PathBuf::from("/a"), PathBuf::from("/b")])
+        );
+    }
+
+    #[test]
+    fn test_collect_files_dedups_across_roots() {
+        let fixture = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/codex");
+        let single = CodexParser::with_data_dir(fixture.clone())
+            .parse_file(&fixture.join("sample-session.jsonl"))
+            .unwrap();
+
+        // The same session copied into two roots
+        let tmp = tempfile::tempdir().unwrap();
+        let (primary, backup) = (tmp.path().join("primary"), tmp.path().join("backup"));
+        for root in [&primary, &backup] {
+            let dir = root.join("2026").join("01").join("15");
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::copy(fixture.join("sample-session.jsonl"), dir.join("s.jsonl")).unwrap();
+        }
+        let parser = CodexParser::with_data_dirs(vec![primary.clone(), backup]);
+        assert_eq!(parser.collect_files().len(), 1);
+        assert_eq!(parser.parse_all().unwrap().len(), single.len());
+
+        // Overlapping roots reach the same file twice
+        let parser = CodexParser::with_data_dirs(vec![primary.clone(), primary.join("2026")]);
+        assert_eq!(parser.collect_files().len(), 1);
+    }
+
     #[test]
     fn test_registry_get_unknown() {
         let registry = ParserRegistry::new();
@@ -218,7 +348,8 @@ mod tests {
     fn test_collect_files() {
         let parser = ClaudeCodeParser::with_data_dir(PathBuf::from("tests/fixtures"));
         let files = parser.collect_files();
-        // claude-sample.jsonl, empty.jsonl, multi/file1.jsonl, multi/file2.jsonl, codex/sample-session.jsonl
-        assert_eq!(files.len(), 5);
+        // claude-sample.jsonl, empty.jsonl, multi/file1.jsonl, multi/file2.jsonl,
+        // codex/sample-session.jsonl, codex/turns-session.jsonl
+        assert_eq!(files.len(), 6);
     }
 }
@@ -1,12 +1,12 @@
 //! OpenCode CLI JSON parser
 
-use crate::types::{Result, ToktrackError, UsageEntry};
+use crate::types::{Result, TokenCosts, ToktrackError, UsageEntry};
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use super::CLIParser;
+use super::{home_dir, CLIParser};
 
 /// OpenCode message JSON structure
 #[derive(Deserialize)]
@@ -46,29 +46,41 @@ struct OpenCodeCache {
 
 /// Parser for OpenCode CLI usage data
 pub struct OpenCodeParser {
-    data_dir: PathBuf,
+    data_dirs: Vec<PathBuf>,
 }
 
 impl OpenCodeParser {
-    /// Create a new parser with default data directory (~/.local/share/opencode/storage/message)
-    /// OpenCode uses XDG standard, so we use ~/.local/share on all platforms
+    /// Create a new parser with default data directory ($XDG_DATA_HOME/opencode/storage/message)
+    /// OpenCode uses XDG standard, so we fall back to ~/.local/share on all platforms
     pub fn new() -> Self {
-        let data_dir = directories::BaseDirs::new()
-            .map(|d| d.home_dir().join(".local").join("share"))
-            .unwrap_or_else(|| PathBuf::from("."))
-            .join("opencode")
-            .join("storage")
-            .join("message");
-        Self { data_dir }
+        Self::with_data_dir(default_data_dir(
+            std::env::var("XDG_DATA_HOME").ok().as_deref(),
+            &home_dir(),
+        ))
     }
 
     /// Create a parser with a custom data directory (for testing)
-    #[allow(dead_code)]
     pub fn with_data_dir(data_dir: PathBuf) -> Self {
-        Self { data_dir }
+        Self::with_data_dirs(vec![data_dir])
+    }
+
+    /// Create a parser scanning several roots (config override)
+    pub fn with_data_dirs(data_dirs: Vec<PathBuf>) -> Self {
+        Self { data_dirs }
     }
 }
 
+/// Resolve the default root from `XDG_DATA_HOME`
+fn default_data_dir(xdg_data_home: Option<&str>, home: &Path) -> PathBuf {
+    xdg_data_home
+        .filter(|s| !s.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| home.join(".local").join("share"))
+        .join("opencode")
+        .join("storage")
+        .join("message")
+}
+
 impl Default for OpenCodeParser {
     fn default() -> Self {
         Self::new()
@@ -80,8 +92,8 @@ impl CLIParser for OpenCodeParser {
         "opencode"
     }
 
-    fn data_dir(&self) -> &Path {
-        &self.data_dir
+    fn data_dirs(&self) -> &[PathBuf] {
+        &self.data_dirs
     }
 
     fn file_pattern(&self) -> &str {
@@ -120,16 +132,27 @@ impl CLIParser for OpenCodeParser {
         let entry = UsageEntry {
             timestamp,
             model: message.model_id,
+            // OpenCode reports input excluding cache reads/writes
             input_tokens: tokens.input,
             output_tokens: tokens.output,
             cache_read_tokens: cache_read,
             cache_creation_tokens: cache_write,
+            cache_creation_1h_tokens: 0,
             thinking_tokens: tokens.reasoning,
             cost_usd: message.cost,
             message_id: Some(message.id),
-            request_id: Some(message.session_id),
+            request_id: Some(message.session_id.clone()),
             source: Some("opencode".into()),
             provider: message.provider_id,
+            project: None,
+            session_id: Some(message.session_id),
+            web_search_requests: 0,
+            service_tier: None,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            token_costs: TokenCosts::default(),
+            long_context_tier: None,
+            cost_reported: false,
         };
 
         Ok(vec![entry])
@@ -178,6 +201,7 @@ mod tests {
         assert_eq!(entry.source, Some("opencode".into()));
         assert_eq!(entry.message_id, Some("msg_001".to_string()));
         assert_eq!(entry.request_id, Some("ses_test".to_string()));
+        assert_eq!(entry.session_id, Some("ses_test".to_string()));
     }
 
     #[test]
@@ -216,6 +240,19 @@ mod tests {
         assert_eq!(parser.file_pattern(), "**/msg_*.json");
     }
 
+    #[test]
+    fn test_default_data_dir_from_xdg() {
+        let home = Path::new("/home/u");
+        assert_eq!(
+            default_data_dir(Some("/xdg"), home),
+            PathBuf::from("/xdg/opencode/storage/message")
+        );
+        assert_eq!(
+            default_data_dir(None, home),
+            PathBuf::from("/home/u/.local/share/opencode/storage/message")
+        );
+    }
+
     #[test]
     fn test_parse_nonexistent_file() {
         let parser = OpenCodeParser::new();
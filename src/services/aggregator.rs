@@ -1,8 +1,11 @@
 //! Aggregator service for computing usage statistics
 
 use super::normalize_model_name;
-use crate::types::{DailySummary, ModelUsage, SourceUsage, TotalSummary, UsageEntry};
-use chrono::Datelike;
+use super::provider::provider_of;
+use crate::types::{
+    DailySummary, ModelUsage, SessionSummary, SourceUsage, TokenCosts, TotalSummary, UsageEntry,
+};
+use chrono::{Datelike, Weekday};
 use std::collections::{HashMap, HashSet};
 
 pub struct Aggregator;
@@ -25,15 +28,50 @@ fn accumulate_summary(target: &mut DailySummary, source: &DailySummary) {
         .total_thinking_tokens
         .saturating_add(source.total_thinking_tokens);
     target.total_cost_usd += source.total_cost_usd;
+    target.total_token_costs += source.total_token_costs;
 
     for (model_name, model_usage) in &source.models {
         let t = target.models.entry(model_name.clone()).or_default();
         merge_model_usage(t, model_usage);
     }
+
+    for (project, project_usage) in &source.projects {
+        let t = target.projects.entry(project.clone()).or_default();
+        merge_model_usage(t, project_usage);
+    }
+
+    for (provider, models) in &source.providers {
+        let target_models = target.providers.entry(provider.clone()).or_default();
+        for (model_name, model_usage) in models {
+            let t = target_models.entry(model_name.clone()).or_default();
+            merge_model_usage(t, model_usage);
+        }
+    }
+
+    for (cli, models) in &source.sources {
+        let target_models = target.sources.entry(cli.clone()).or_default();
+        for (model_name, model_usage) in models {
+            let t = target_models.entry(model_name.clone()).or_default();
+            merge_model_usage(t, model_usage);
+        }
+    }
 }
 
 /// Merge model usage fields from `source` into `target`
-fn merge_model_usage(target: &mut ModelUsage, source: &ModelUsage) {
+pub(crate) fn merge_model_usage(target: &mut ModelUsage, source: &ModelUsage) {
+    // The largest prompt stays unknown once usage cached before it was recorded is merged in
+    target.max_prompt_tokens = match (target.count, source.count) {
+        (0, _) => source.max_prompt_tokens,
+        (_, 0) => target.max_prompt_tokens,
+        _ => target
+            .max_prompt_tokens
+            .zip(source.max_prompt_tokens)
+            .map(|(a, b)| a.max(b)),
+    };
+    target.cost_reported |= source.cost_reported;
+    for (threshold, tokens) in &source.tier_tokens {
+        *target.tier_tokens.entry(*threshold).or_default() += *tokens;
+    }
     target.input_tokens = target.input_tokens.saturating_add(source.input_tokens);
     target.output_tokens = target.output_tokens.saturating_add(source.output_tokens);
     target.cache_read_tokens = target
@@ -42,11 +80,23 @@ fn merge_model_usage(target: &mut ModelUsage, source: &ModelUsage) {
     target.cache_creation_tokens = target
         .cache_creation_tokens
         .saturating_add(source.cache_creation_tokens);
+    target.cache_creation_1h_tokens = target
+        .cache_creation_1h_tokens
+        .saturating_add(source.cache_creation_1h_tokens);
     target.thinking_tokens = target
         .thinking_tokens
         .saturating_add(source.thinking_tokens);
     target.cost_usd += source.cost_usd;
     target.count = target.count.saturating_add(source.count);
+    target.web_search_requests = target
+        .web_search_requests
+        .saturating_add(source.web_search_requests);
+    target.web_search_cost_usd += source.web_search_cost_usd;
+    target.service_tier_cost_usd += source.service_tier_cost_usd;
+    target.token_costs += source.token_costs;
+    target.unpriced_tokens = target
+        .unpriced_tokens
+        .saturating_add(source.unpriced_tokens);
 }
 
 impl Aggregator {
@@ -71,7 +121,11 @@ impl Aggregator {
                 total_cache_creation_tokens: 0,
                 total_thinking_tokens: 0,
                 total_cost_usd: 0.0,
+                total_token_costs: TokenCosts::default(),
                 models: HashMap::new(),
+                projects: HashMap::new(),
+                providers: HashMap::new(),
+                sources: HashMap::new(),
             });
 
             summary.total_input_tokens = summary
@@ -90,10 +144,26 @@ impl Aggregator {
                 .total_thinking_tokens
                 .saturating_add(entry.thinking_tokens);
             summary.total_cost_usd += cost;
+            summary.total_token_costs += entry.token_costs;
+
+            // Update provider breakdown
+            let provider_usage = summary
+                .providers
+                .entry(provider_of(entry))
+                .or_default()
+                .entry(model_name.clone())
+                .or_default();
+            provider_usage.add(entry, cost);
 
             // Update model breakdown
             let model_usage = summary.models.entry(model_name).or_default();
             model_usage.add(entry, cost);
+
+            // Update project breakdown (only sources that record a project)
+            if let Some(project) = &entry.project {
+                let project_usage = summary.projects.entry(project.clone()).or_default();
+                project_usage.add(entry, cost);
+            }
         }
 
         // Sort by date ascending
@@ -102,8 +172,8 @@ impl Aggregator {
         result
     }
 
-    /// Aggregate daily summaries into weekly summaries (Sunday-start weeks)
-    pub fn weekly(daily_summaries: &[DailySummary]) -> Vec<DailySummary> {
+    /// Aggregate daily summaries into weekly summaries (weeks start on `week_start`)
+    pub fn weekly(daily_summaries: &[DailySummary], week_start: Weekday) -> Vec<DailySummary> {
         if daily_summaries.is_empty() {
             return Vec::new();
         }
@@ -111,22 +181,26 @@ impl Aggregator {
         let mut week_map: HashMap<chrono::NaiveDate, DailySummary> = HashMap::new();
 
         for summary in daily_summaries {
-            // Calculate the Sunday that starts this week
-            let days_from_sunday = summary.date.weekday().num_days_from_sunday();
-            let week_start = summary
+            // Calculate the day that starts this week
+            let days_into_week = summary.date.weekday().days_since(week_start);
+            let start = summary
                 .date
-                .checked_sub_signed(chrono::Duration::days(days_from_sunday as i64))
+                .checked_sub_signed(chrono::Duration::days(days_into_week as i64))
                 .unwrap_or(summary.date);
 
-            let week_summary = week_map.entry(week_start).or_insert_with(|| DailySummary {
-                date: week_start,
+            let week_summary = week_map.entry(start).or_insert_with(|| DailySummary {
+                date: start,
                 total_input_tokens: 0,
                 total_output_tokens: 0,
                 total_cache_read_tokens: 0,
                 total_cache_creation_tokens: 0,
                 total_thinking_tokens: 0,
                 total_cost_usd: 0.0,
+                total_token_costs: TokenCosts::default(),
                 models: HashMap::new(),
+                projects: HashMap::new(),
+                providers: HashMap::new(),
+                sources: HashMap::new(),
             });
 
             accumulate_summary(week_summary, summary);
@@ -158,7 +232,11 @@ impl Aggregator {
                 total_cache_creation_tokens: 0,
                 total_thinking_tokens: 0,
                 total_cost_usd: 0.0,
+                total_token_costs: TokenCosts::default(),
                 models: HashMap::new(),
+                projects: HashMap::new(),
+                providers: HashMap::new(),
+                sources: HashMap::new(),
             });
 
             accumulate_summary(month_summary, summary);
@@ -184,6 +262,52 @@ impl Aggregator {
         model_map
     }
 
+    /// Aggregate usage by provider (see `services::provider`)
+    #[allow(dead_code)]
+    pub fn by_provider(entries: &[UsageEntry]) -> HashMap<String, ModelUsage> {
+        let mut provider_map: HashMap<String, ModelUsage> = HashMap::new();
+
+        for entry in entries {
+            let usage = provider_map.entry(provider_of(entry)).or_default();
+            usage.add(entry, entry.cost_usd.unwrap_or(0.0));
+        }
+
+        provider_map
+    }
+
+    /// Per-provider model breakdown from DailySummary slice, keyed provider → model
+    pub fn by_provider_from_daily(
+        summaries: &[DailySummary],
+    ) -> HashMap<String, HashMap<String, ModelUsage>> {
+        let mut provider_map: HashMap<String, HashMap<String, ModelUsage>> = HashMap::new();
+
+        for s in summaries {
+            for (provider, models) in &s.providers {
+                let target_models = provider_map.entry(provider.clone()).or_default();
+                for (model_name, usage) in models {
+                    let target = target_models.entry(model_name.clone()).or_default();
+                    merge_model_usage(target, usage);
+                }
+            }
+        }
+
+        provider_map
+    }
+
+    /// Per-project totals from DailySummary slice, keyed by project path
+    pub fn by_project_from_daily(summaries: &[DailySummary]) -> HashMap<String, ModelUsage> {
+        let mut project_map: HashMap<String, ModelUsage> = HashMap::new();
+
+        for s in summaries {
+            for (project, usage) in &s.projects {
+                let target = project_map.entry(project.clone()).or_default();
+                merge_model_usage(target, usage);
+            }
+        }
+
+        project_map
+    }
+
     /// Compute TotalSummary from DailySummary slice (no raw entries needed)
     pub fn total_from_daily(summaries: &[DailySummary]) -> TotalSummary {
         if summaries.is_empty() {
@@ -220,6 +344,7 @@ impl Aggregator {
     }
 
     /// Compute model breakdown from DailySummary slice (no raw entries needed)
+    #[allow(dead_code)]
     pub fn by_model_from_daily(summaries: &[DailySummary]) -> HashMap<String, ModelUsage> {
         let mut model_map: HashMap<String, ModelUsage> = HashMap::new();
 
@@ -297,7 +422,72 @@ impl Aggregator {
             .collect();
 
         // Sort by total_tokens descending
-        result.sort_by(|a, b| b.total_tokens.cmp(&a.total_tokens));
+        result.sort_by_key(|s| std::cmp::Reverse(s.total_tokens));
+        result
+    }
+
+    /// Aggregate usage per session, keyed by (source, session_id).
+    /// Entries without a session id are skipped. Sorted by start time ascending.
+    pub fn by_session(entries: &[UsageEntry]) -> Vec<SessionSummary> {
+        let mut session_map: HashMap<(String, String), SessionSummary> = HashMap::new();
+
+        for entry in entries {
+            let Some(session_id) = &entry.session_id else {
+                continue;
+            };
+            let source = entry.source.as_deref().unwrap_or("unknown").to_string();
+            let cost = entry.cost_usd.unwrap_or(0.0);
+            let model_name = normalize_model_name(entry.model.as_deref().unwrap_or("unknown"));
+
+            let session = session_map
+                .entry((source.clone(), session_id.clone()))
+                .or_insert_with(|| SessionSummary {
+                    session_id: session_id.clone(),
+                    source,
+                    project: entry.project.clone(),
+                    start: entry.timestamp,
+                    end: entry.timestamp,
+                    duration_secs: 0,
+                    total_input_tokens: 0,
+                    total_output_tokens: 0,
+                    total_cache_read_tokens: 0,
+                    total_cache_creation_tokens: 0,
+                    total_thinking_tokens: 0,
+                    total_cost_usd: 0.0,
+                    models: HashMap::new(),
+                });
+
+            session.start = session.start.min(entry.timestamp);
+            session.end = session.end.max(entry.timestamp);
+            session.total_input_tokens = session
+                .total_input_tokens
+                .saturating_add(entry.input_tokens);
+            session.total_output_tokens = session
+                .total_output_tokens
+                .saturating_add(entry.output_tokens);
+            session.total_cache_read_tokens = session
+                .total_cache_read_tokens
+                .saturating_add(entry.cache_read_tokens);
+            session.total_cache_creation_tokens = session
+                .total_cache_creation_tokens
+                .saturating_add(entry.cache_creation_tokens);
+            session.total_thinking_tokens = session
+                .total_thinking_tokens
+                .saturating_add(entry.thinking_tokens);
+            session.total_cost_usd += cost;
+
+            let model_usage = session.models.entry(model_name).or_default();
+            model_usage.add(entry, cost);
+        }
+
+        let mut result: Vec<SessionSummary> = session_map
+            .into_values()
+            .map(|mut s| {
+                s.duration_secs = (s.end - s.start).num_seconds();
+                s
+            })
+            .collect();
+        result.sort_by_key(|s| s.start);
         result
     }
 
@@ -321,7 +511,11 @@ impl Aggregator {
                     total_cache_creation_tokens: 0,
                     total_thinking_tokens: 0,
                     total_cost_usd: 0.0,
+                    total_token_costs: TokenCosts::default(),
                     models: HashMap::new(),
+                    projects: HashMap::new(),
+                    providers: HashMap::new(),
+                    sources: HashMap::new(),
                 });
             accumulate_summary(target, &summary);
         }
@@ -336,6 +530,7 @@ impl Aggregator {
 mod tests {
     use super::*;
     use chrono::{TimeZone, Utc};
+    use std::collections::BTreeMap;
 
     fn make_entry(
         year: i32,
@@ -353,12 +548,22 @@ mod tests {
             output_tokens: output,
             cache_read_tokens: 0,
             cache_creation_tokens: 0,
+            cache_creation_1h_tokens: 0,
             thinking_tokens: 0,
             cost_usd: cost,
             message_id: None,
             request_id: None,
             source: None,
             provider: None,
+            project: None,
+            session_id: None,
+            web_search_requests: 0,
+            service_tier: None,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            token_costs: TokenCosts::default(),
+            long_context_tier: None,
+            cost_reported: false,
         }
     }
 
@@ -381,12 +586,22 @@ mod tests {
             output_tokens: output,
             cache_read_tokens: cache_read,
             cache_creation_tokens: cache_creation,
+            cache_creation_1h_tokens: 0,
             thinking_tokens: 0,
             cost_usd: cost,
             message_id: None,
             request_id: None,
             source: None,
             provider: None,
+            project: None,
+            session_id: None,
+            web_search_requests: 0,
+            service_tier: None,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            token_costs: TokenCosts::default(),
+            long_context_tier: None,
+            cost_reported: false,
         }
     }
 
@@ -453,6 +668,131 @@ mod tests {
         assert_eq!(result[0].models.len(), 2);
     }
 
+    #[test]
+    fn test_daily_sums_cost_by_token_type() {
+        let split = TokenCosts {
+            output_usd: 0.75,
+            cache_read_usd: 0.25,
+            ..TokenCosts::default()
+        };
+        let mut first = make_entry(2024, 1, 15, Some("claude"), 100, 50, Some(1.0));
+        first.token_costs = split;
+        let mut second = first.clone();
+        second.timestamp += chrono::Duration::minutes(1);
+
+        let result = Aggregator::daily(&[first, second]);
+
+        let doubled = split.scaled(2.0);
+        assert_eq!(result[0].models["claude"].token_costs, doubled);
+        assert_eq!(result[0].total_token_costs, doubled);
+
+        // Merging days keeps the split
+        let merged = Aggregator::merge_by_date([result.clone(), result].concat());
+        assert_eq!(merged[0].total_token_costs, split.scaled(4.0));
+    }
+
+    #[test]
+    fn test_daily_project_breakdown() {
+        let mut a = make_entry(2024, 1, 15, Some("claude"), 100, 50, Some(0.01));
+        a.project = Some("/work/monorepo".into());
+        let mut b = make_entry(2024, 1, 15, Some("claude"), 200, 100, Some(0.02));
+        b.project = Some("/work/monorepo".into());
+        let mut c = make_entry(2024, 1, 15, Some("claude"), 10, 5, Some(0.005));
+        c.project = Some("/work/side".into());
+        let d = make_entry(2024, 1, 15, Some("gpt-4"), 10, 5, Some(0.001));
+
+        let result = Aggregator::daily(&[a, b, c, d]);
+
+        let projects = &result[0].projects;
+        assert_eq!(projects.len(), 2);
+        assert_eq!(projects["/work/monorepo"].input_tokens, 300);
+        assert_eq!(projects["/work/monorepo"].count, 2);
+        assert!((projects["/work/monorepo"].cost_usd - 0.03).abs() < 1e-10);
+        assert_eq!(projects["/work/side"].output_tokens, 5);
+    }
+
+    #[test]
+    fn test_daily_provider_breakdown() {
+        let mut a = make_entry(2024, 1, 15, Some("claude-sonnet-4"), 100, 50, Some(0.01));
+        a.source = Some("claude".into());
+        let mut b = make_entry(2024, 1, 15, Some("claude-sonnet-4"), 200, 100, None);
+        b.provider = Some("github-copilot".into());
+        let c = make_entry(2024, 1, 15, Some("gpt-4"), 10, 5, Some(0.001));
+
+        let result = Aggregator::daily(&[a, b, c]);
+
+        let providers = &result[0].providers;
+        assert_eq!(providers.len(), 3);
+        assert_eq!(providers["anthropic"]["claude-sonnet-4"].input_tokens, 100);
+        assert_eq!(
+            providers["github-copilot"]["claude-sonnet-4"].unpriced_tokens,
+            300
+        );
+        assert_eq!(providers["unknown"]["gpt-4"].count, 1);
+        // The model breakdown still sums across providers
+        assert_eq!(result[0].models["claude-sonnet-4"].input_tokens, 300);
+    }
+
+    #[test]
+    fn test_daily_records_what_repricing_needs() {
+        let mut a = make_entry_full(2024, 1, 15, Some("claude"), 150_000, 50, 100_000, 0, None);
+        a.long_context_tier = Some(200_000);
+        let mut b = make_entry(2024, 1, 15, Some("claude"), 1000, 10, Some(0.5));
+        b.cost_reported = true;
+
+        let result = Aggregator::daily(&[a, b]);
+
+        let usage = &result[0].models["claude"];
+        assert_eq!(usage.max_prompt_tokens, Some(250_000));
+        assert!(usage.cost_reported);
+        let tiered = usage.tier_tokens[&200_000];
+        assert_eq!(
+            (tiered.input_tokens, tiered.cache_read_tokens),
+            (150_000, 100_000)
+        );
+
+        // Merged with usage cached before prompt sizes were recorded, the
+        // largest prompt is unknown
+        let mut merged = usage.clone();
+        let old = ModelUsage {
+            count: 1,
+            ..ModelUsage::default()
+        };
+        merge_model_usage(&mut merged, &old);
+        assert_eq!(merged.max_prompt_tokens, None);
+        let mut merged = ModelUsage::default();
+        merge_model_usage(&mut merged, usage);
+        assert_eq!(merged.max_prompt_tokens, Some(250_000));
+    }
+
+    #[test]
+    fn test_by_provider_and_from_daily() {
+        let mut a = make_entry(2024, 1, 15, Some("claude-sonnet-4"), 100, 50, Some(0.01));
+        a.provider = Some("openrouter".into());
+        let mut b = make_entry(2024, 1, 16, Some("gpt-5"), 200, 100, Some(0.02));
+        b.provider = Some("openrouter".into());
+        let mut c = make_entry(2024, 1, 16, Some("claude-sonnet-4"), 10, 5, Some(0.005));
+        c.source = Some("claude".into());
+        let entries = [a, b, c];
+
+        let by_provider = Aggregator::by_provider(&entries);
+        assert_eq!(by_provider.len(), 2);
+        assert_eq!(by_provider["openrouter"].input_tokens, 300);
+        assert_eq!(by_provider["anthropic"].count, 1);
+
+        let from_daily = Aggregator::by_provider_from_daily(&Aggregator::daily(&entries));
+        assert_eq!(from_daily["openrouter"].len(), 2);
+        assert_eq!(from_daily["openrouter"]["gpt-5"].output_tokens, 100);
+        assert!((from_daily["anthropic"]["claude-sonnet-4"].cost_usd - 0.005).abs() < 1e-10);
+
+        // Weekly roll-ups keep the breakdown
+        let weekly = Aggregator::weekly(&Aggregator::daily(&entries), Weekday::Sun);
+        assert_eq!(
+            weekly[0].providers["openrouter"]["claude-sonnet-4"].count,
+            1
+        );
+    }
+
     #[test]
     fn test_by_model_empty() {
         let result = Aggregator::by_model(&[]);
@@ -656,7 +996,11 @@ mod tests {
             total_cache_creation_tokens: 0,
             total_thinking_tokens: 0,
             total_cost_usd: cost,
+            total_token_costs: TokenCosts::default(),
             models: HashMap::new(),
+            projects: HashMap::new(),
+            providers: HashMap::new(),
+            sources: HashMap::new(),
         }
     }
 
@@ -677,13 +1021,17 @@ mod tests {
             total_cache_creation_tokens: 0,
             total_thinking_tokens: 0,
             total_cost_usd: cost,
+            total_token_costs: TokenCosts::default(),
             models,
+            projects: HashMap::new(),
+            providers: HashMap::new(),
+            sources: HashMap::new(),
         }
     }
 
     #[test]
     fn test_weekly_empty() {
-        let result = Aggregator::weekly(&[]);
+        let result = Aggregator::weekly(&[], Weekday::Sun);
         assert!(result.is_empty());
     }
 
@@ -691,7 +1039,7 @@ mod tests {
     fn test_weekly_single_day() {
         // 2025-01-15 is Wednesday → week starts on 2025-01-12 (Sunday)
         let summaries = vec![make_daily_summary(2025, 1, 15, 100, 50, 0.01)];
-        let result = Aggregator::weekly(&summaries);
+        let result = Aggregator::weekly(&summaries, Weekday::Sun);
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].date.to_string(), "2025-01-12");
@@ -706,7 +1054,7 @@ mod tests {
             make_daily_summary(2025, 1, 13, 100, 50, 0.01),
             make_daily_summary(2025, 1, 15, 200, 100, 0.02),
         ];
-        let result = Aggregator::weekly(&summaries);
+        let result = Aggregator::weekly(&summaries, Weekday::Sun);
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].date.to_string(), "2025-01-12");
@@ -723,7 +1071,7 @@ mod tests {
             make_daily_summary(2025, 1, 18, 100, 50, 0.01),
             make_daily_summary(2025, 1, 19, 200, 100, 0.02),
         ];
-        let result = Aggregator::weekly(&summaries);
+        let result = Aggregator::weekly(&summaries, Weekday::Sun);
 
         assert_eq!(result.len(), 2);
         assert_eq!(result[0].date.to_string(), "2025-01-12");
@@ -735,17 +1083,31 @@ mod tests {
         // Sunday itself is the start of its own week
         // 2025-01-12 is a Sunday
         let summaries = vec![make_daily_summary(2025, 1, 12, 100, 50, 0.01)];
-        let result = Aggregator::weekly(&summaries);
+        let result = Aggregator::weekly(&summaries, Weekday::Sun);
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].date.to_string(), "2025-01-12");
     }
 
+    #[test]
+    fn test_weekly_monday_start() {
+        // 2025-01-12 (Sun) → week of Mon 2025-01-06; 2025-01-13 (Mon) → its own week
+        let summaries = vec![
+            make_daily_summary(2025, 1, 12, 100, 50, 0.01),
+            make_daily_summary(2025, 1, 13, 200, 100, 0.02),
+        ];
+        let result = Aggregator::weekly(&summaries, Weekday::Mon);
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].date.to_string(), "2025-01-06");
+        assert_eq!(result[1].date.to_string(), "2025-01-13");
+    }
+
     #[test]
     fn test_weekly_saturday_maps_to_sunday() {
         // 2025-01-18 is Saturday → maps to Sunday 2025-01-12
         let summaries = vec![make_daily_summary(2025, 1, 18, 100, 50, 0.01)];
-        let result = Aggregator::weekly(&summaries);
+        let result = Aggregator::weekly(&summaries, Weekday::Sun);
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].date.to_string(), "2025-01-12");
@@ -793,7 +1155,7 @@ mod tests {
             make_daily_summary_with_models(2025, 1, 15, 250, 125, 0.025, models_b),
         ];
 
-        let result = Aggregator::weekly(&summaries);
+        let result = Aggregator::weekly(&summaries, Weekday::Sun);
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].models.len(), 2);
 
@@ -813,7 +1175,7 @@ mod tests {
             make_daily_summary(2025, 1, 6, 200, 100, 0.02), // week of Jan 5
             make_daily_summary(2025, 1, 13, 150, 75, 0.015), // week of Jan 12
         ];
-        let result = Aggregator::weekly(&summaries);
+        let result = Aggregator::weekly(&summaries, Weekday::Sun);
 
         assert_eq!(result.len(), 3);
         assert_eq!(result[0].date.to_string(), "2025-01-05");
@@ -913,9 +1275,18 @@ mod tests {
                 output_tokens: 50,
                 cache_read_tokens: 10,
                 cache_creation_tokens: 5,
+                cache_creation_1h_tokens: 0,
                 thinking_tokens: 0,
                 cost_usd: 0.01,
                 count: 3,
+                web_search_requests: 0,
+                web_search_cost_usd: 0.0,
+                service_tier_cost_usd: 0.0,
+                unpriced_tokens: 0,
+                token_costs: TokenCosts::default(),
+                cost_reported: false,
+                max_prompt_tokens: None,
+                tier_tokens: BTreeMap::new(),
             },
         );
         let summaries = vec![make_daily_summary_with_models(
@@ -977,6 +1348,98 @@ mod tests {
         assert!(result.is_empty());
     }
 
+    #[test]
+    fn test_merge_and_weekly_keep_source_breakdown() {
+        let tagged = |day, source: &str, input| {
+            let mut s = make_daily_summary(2025, 1, day, input, 0, 0.0);
+            let usage = ModelUsage {
+                input_tokens: input,
+                count: 1,
+                ..ModelUsage::default()
+            };
+            s.sources = HashMap::from([(
+                source.to_string(),
+                HashMap::from([("gpt-5".to_string(), usage)]),
+            )]);
+            s
+        };
+        let merged = Aggregator::merge_by_date(vec![
+            tagged(10, "codex", 100),
+            tagged(10, "opencode", 40),
+            tagged(11, "codex", 200),
+        ]);
+        assert_eq!(merged[0].sources.len(), 2);
+        assert_eq!(merged[0].sources["opencode"]["gpt-5"].input_tokens, 40);
+
+        let weekly = Aggregator::weekly(&merged, Weekday::Sun);
+        assert_eq!(weekly[0].sources["codex"]["gpt-5"].input_tokens, 300);
+        assert_eq!(weekly[0].sources["codex"]["gpt-5"].count, 2);
+    }
+
+    #[test]
+    fn test_weekly_and_monthly_merge_project_breakdown() {
+        let mut day1 = make_daily_summary(2025, 1, 10, 100, 50, 0.01);
+        day1.projects.insert(
+            "/work/app".into(),
+            ModelUsage {
+                input_tokens: 100,
+                output_tokens: 50,
+                cost_usd: 0.01,
+                count: 1,
+                ..ModelUsage::default()
+            },
+        );
+        let mut day2 = make_daily_summary(2025, 1, 11, 200, 100, 0.02);
+        day2.projects.insert(
+            "/work/app".into(),
+            ModelUsage {
+                input_tokens: 200,
+                output_tokens: 100,
+                cost_usd: 0.02,
+                count: 2,
+                ..ModelUsage::default()
+            },
+        );
+
+        let monthly = Aggregator::monthly(&[day1.clone(), day2.clone()]);
+        assert_eq!(monthly[0].projects.len(), 1);
+        assert_eq!(monthly[0].projects["/work/app"].input_tokens, 300);
+        assert_eq!(monthly[0].projects["/work/app"].count, 3);
+
+        let weekly = Aggregator::weekly(&[day1, day2], Weekday::Sun);
+        assert_eq!(weekly[0].projects["/work/app"].output_tokens, 150);
+    }
+
+    #[test]
+    fn test_by_project_from_daily_merges_across_days() {
+        let mut day1 = make_daily_summary(2025, 1, 10, 100, 50, 0.01);
+        day1.projects.insert(
+            "/work/app".to_string(),
+            ModelUsage {
+                input_tokens: 100,
+                cost_usd: 0.01,
+                count: 1,
+                ..ModelUsage::default()
+            },
+        );
+        let mut day2 = make_daily_summary(2025, 1, 11, 200, 0, 0.02);
+        day2.projects.insert(
+            "/work/app".to_string(),
+            ModelUsage {
+                input_tokens: 200,
+                cost_usd: 0.02,
+                count: 2,
+                ..ModelUsage::default()
+            },
+        );
+
+        let projects = Aggregator::by_project_from_daily(&[day1, day2]);
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects["/work/app"].input_tokens, 300);
+        assert_eq!(projects["/work/app"].count, 3);
+        assert!((projects["/work/app"].cost_usd - 0.03).abs() < 1e-10);
+    }
+
     #[test]
     fn test_by_model_from_daily_merges_across_days() {
         let mut models_a = HashMap::new();
@@ -1042,7 +1505,11 @@ mod tests {
             total_cache_creation_tokens: 5,
             total_thinking_tokens: 0,
             total_cost_usd: 0.01,
+            total_token_costs: TokenCosts::default(),
             models: HashMap::new(),
+            projects: HashMap::new(),
+            providers: HashMap::new(),
+            sources: HashMap::new(),
         };
         let source = DailySummary {
             date: chrono::NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
@@ -1052,7 +1519,11 @@ mod tests {
             total_cache_creation_tokens: 15,
             total_thinking_tokens: 0,
             total_cost_usd: 0.02,
+            total_token_costs: TokenCosts::default(),
             models: HashMap::new(),
+            projects: HashMap::new(),
+            providers: HashMap::new(),
+            sources: HashMap::new(),
         };
 
         accumulate_summary(&mut target, &source);
@@ -1071,18 +1542,36 @@ mod tests {
             output_tokens: 50,
             cache_read_tokens: 10,
             cache_creation_tokens: 5,
+            cache_creation_1h_tokens: 0,
             thinking_tokens: 0,
             cost_usd: 0.01,
             count: 2,
+            web_search_requests: 0,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            unpriced_tokens: 0,
+            token_costs: TokenCosts::default(),
+            cost_reported: false,
+            max_prompt_tokens: None,
+            tier_tokens: BTreeMap::new(),
         };
         let source = ModelUsage {
             input_tokens: 200,
             output_tokens: 100,
             cache_read_tokens: 20,
             cache_creation_tokens: 10,
+            cache_creation_1h_tokens: 0,
             thinking_tokens: 0,
             cost_usd: 0.02,
             count: 3,
+            web_search_requests: 0,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            unpriced_tokens: 0,
+            token_costs: TokenCosts::default(),
+            cost_reported: false,
+            max_prompt_tokens: None,
+            tier_tokens: BTreeMap::new(),
         };
 
         merge_model_usage(&mut target, &source);
@@ -1150,7 +1639,11 @@ mod tests {
             total_cache_creation_tokens: 0,
             total_thinking_tokens: 0,
             total_cost_usd: 0.01,
+            total_token_costs: TokenCosts::default(),
             models: models_target,
+            projects: HashMap::new(),
+            providers: HashMap::new(),
+            sources: HashMap::new(),
         };
 
         let mut models_source = HashMap::new();
@@ -1182,7 +1675,11 @@ mod tests {
             total_cache_creation_tokens: 0,
             total_thinking_tokens: 0,
             total_cost_usd: 0.025,
+            total_token_costs: TokenCosts::default(),
             models: models_source,
+            projects: HashMap::new(),
+            providers: HashMap::new(),
+            sources: HashMap::new(),
         };
 
         accumulate_summary(&mut target, &source);
@@ -1217,12 +1714,22 @@ mod tests {
             output_tokens: output,
             cache_read_tokens: 0,
             cache_creation_tokens: 0,
+            cache_creation_1h_tokens: 0,
             thinking_tokens: 0,
             cost_usd: cost,
             message_id: None,
             request_id: None,
             source: source.map(String::from),
             provider: None,
+            project: None,
+            session_id: None,
+            web_search_requests: 0,
+            service_tier: None,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            token_costs: TokenCosts::default(),
+            long_context_tier: None,
+            cost_reported: false,
         }
     }
 
@@ -1243,12 +1750,22 @@ mod tests {
             output_tokens: 50,
             cache_read_tokens: 0,
             cache_creation_tokens: 0,
+            cache_creation_1h_tokens: 0,
             thinking_tokens: 0,
             cost_usd: Some(0.01),
             message_id: None,
             request_id: None,
             source: None,
             provider: None,
+            project: None,
+            session_id: None,
+            web_search_requests: 0,
+            service_tier: None,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            token_costs: TokenCosts::default(),
+            long_context_tier: None,
+            cost_reported: false,
         };
         let entry_early = UsageEntry {
             timestamp: early_utc,
@@ -1257,12 +1774,22 @@ mod tests {
             output_tokens: 100,
             cache_read_tokens: 0,
             cache_creation_tokens: 0,
+            cache_creation_1h_tokens: 0,
             thinking_tokens: 0,
             cost_usd: Some(0.02),
             message_id: None,
             request_id: None,
             source: None,
             provider: None,
+            project: None,
+            session_id: None,
+            web_search_requests: 0,
+            service_tier: None,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            token_costs: TokenCosts::default(),
+            long_context_tier: None,
+            cost_reported: false,
         };
 
         let result = Aggregator::daily(&[entry_late.clone(), entry_early.clone()]);
@@ -1307,12 +1834,22 @@ mod tests {
                 output_tokens: 50,
                 cache_read_tokens: 0,
                 cache_creation_tokens: 0,
+                cache_creation_1h_tokens: 0,
                 thinking_tokens: 0,
                 cost_usd: Some(0.01),
                 message_id: None,
                 request_id: None,
                 source: None,
                 provider: None,
+                project: None,
+                session_id: None,
+                web_search_requests: 0,
+                service_tier: None,
+                web_search_cost_usd: 0.0,
+                service_tier_cost_usd: 0.0,
+                token_costs: TokenCosts::default(),
+                long_context_tier: None,
+                cost_reported: false,
             },
             UsageEntry {
                 timestamp: ts2,
@@ -1321,12 +1858,22 @@ mod tests {
                 output_tokens: 100,
                 cache_read_tokens: 0,
                 cache_creation_tokens: 0,
+                cache_creation_1h_tokens: 0,
                 thinking_tokens: 0,
                 cost_usd: Some(0.02),
                 message_id: None,
                 request_id: None,
                 source: None,
                 provider: None,
+                project: None,
+                session_id: None,
+                web_search_requests: 0,
+                service_tier: None,
+                web_search_cost_usd: 0.0,
+                service_tier_cost_usd: 0.0,
+                token_costs: TokenCosts::default(),
+                long_context_tier: None,
+                cost_reported: false,
             },
         ];
 
@@ -1441,6 +1988,68 @@ mod tests {
         assert_eq!(result[0].source, "unknown");
     }
 
+    // ========== by_session tests ==========
+
+    fn make_session_entry(
+        source: &str,
+        session: Option<&str>,
+        hour: u32,
+        model: &str,
+        input: u64,
+        cost: f64,
+    ) -> UsageEntry {
+        let mut entry = make_entry(2025, 3, 10, Some(model), input, 10, Some(cost));
+        entry.timestamp = Utc.with_ymd_and_hms(2025, 3, 10, hour, 0, 0).unwrap();
+        entry.source = Some(source.into());
+        entry.session_id = session.map(String::from);
+        entry
+    }
+
+    #[test]
+    fn test_by_session_empty() {
+        assert!(Aggregator::by_session(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_by_session_groups_and_spans_time() {
+        let entries = vec![
+            make_session_entry("claude", Some("s1"), 9, "claude-sonnet-4", 100, 0.10),
+            make_session_entry("claude", Some("s1"), 11, "claude-opus-4", 200, 0.50),
+            make_session_entry("claude", Some("s1"), 10, "claude-sonnet-4", 50, 0.05),
+            make_session_entry("codex", Some("s2"), 8, "gpt-4.1", 10, 0.01),
+        ];
+
+        let sessions = Aggregator::by_session(&entries);
+
+        assert_eq!(sessions.len(), 2);
+        // Sorted by start ascending: codex session starts at 08:00
+        assert_eq!(sessions[0].session_id, "s2");
+        let s1 = &sessions[1];
+        assert_eq!(s1.source, "claude");
+        assert_eq!(s1.start.format("%H").to_string(), "09");
+        assert_eq!(s1.end.format("%H").to_string(), "11");
+        assert_eq!(s1.duration_secs, 2 * 3600);
+        assert_eq!(s1.total_input_tokens, 350);
+        assert!((s1.total_cost_usd - 0.65).abs() < 1e-10);
+        assert_eq!(s1.models.len(), 2);
+        assert_eq!(s1.models["claude-sonnet-4"].count, 2);
+    }
+
+    #[test]
+    fn test_by_session_same_id_different_source_kept_apart() {
+        let entries = vec![
+            make_session_entry("claude", Some("same"), 9, "claude-sonnet-4", 100, 0.1),
+            make_session_entry("gemini", Some("same"), 9, "gemini-2.5-pro", 100, 0.1),
+        ];
+        assert_eq!(Aggregator::by_session(&entries).len(), 2);
+    }
+
+    #[test]
+    fn test_by_session_skips_entries_without_session() {
+        let entries = vec![make_session_entry("claude", None, 9, "claude", 100, 0.1)];
+        assert!(Aggregator::by_session(&entries).is_empty());
+    }
+
     // ========== merge_by_date tests ==========
 
     #[test]
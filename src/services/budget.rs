@@ -0,0 +1,310 @@
+//! Spending budgets evaluated against daily summaries
+//!
+//! A budget caps USD spend over the current day, week or month, optionally
+//! scoped to a single source CLI and/or a model glob.
+
+use chrono::{NaiveDate, Weekday};
+use serde::Serialize;
+
+use crate::services::{DataLoaderService, DateRange, DateSpec, UsageFilter};
+use crate::types::DailySummary;
+
+/// Share of the limit at which a budget is reported as a warning
+pub const BUDGET_WARN_RATIO: f64 = 0.8;
+
+/// Budget period; each budget covers the period containing today
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BudgetPeriod {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl BudgetPeriod {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Daily => "daily",
+            Self::Weekly => "weekly",
+            Self::Monthly => "monthly",
+        }
+    }
+
+    /// Inclusive span of the period containing `today`
+    pub fn span(self, today: NaiveDate, week_start: Weekday) -> (NaiveDate, NaiveDate) {
+        let spec = match self {
+            Self::Daily => DateSpec::Today,
+            Self::Weekly => DateSpec::ThisWeek,
+            Self::Monthly => DateSpec::ThisMonth,
+        };
+        spec.span(today, week_start)
+    }
+}
+
+impl std::str::FromStr for BudgetPeriod {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "daily" => Ok(Self::Daily),
+            "weekly" => Ok(Self::Weekly),
+            "monthly" => Ok(Self::Monthly),
+            _ => Err(format!(
+                "invalid period '{}' (expected daily, weekly or monthly)",
+                s
+            )),
+        }
+    }
+}
+
+/// A USD spending limit
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Budget {
+    pub period: BudgetPeriod,
+    pub limit_usd: f64,
+    /// Canonical source CLI name (`None` = all sources)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// Model glob pattern (`None` = all models)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+}
+
+impl Budget {
+    /// Short label such as "monthly" or "daily codex claude-opus*"
+    pub fn label(&self) -> String {
+        [
+            Some(self.period.name()),
+            self.source.as_deref(),
+            self.model.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ")
+    }
+
+    /// Source/model filter for this budget's scope
+    fn scope(&self) -> UsageFilter {
+        UsageFilter {
+            date_range: DateRange::default(),
+            sources: self.source.clone().map(|s| vec![s]),
+            models: self.model.clone().map(|m| vec![m]),
+            providers: None,
+        }
+    }
+}
+
+/// How close spend is to the limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BudgetState {
+    Ok,
+    Warning,
+    Exceeded,
+}
+
+/// A budget evaluated for the current period
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BudgetStatus {
+    #[serde(flatten)]
+    pub budget: Budget,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub spent_usd: f64,
+}
+
+impl BudgetStatus {
+    /// Spend as a fraction of the limit
+    pub fn ratio(&self) -> f64 {
+        if self.budget.limit_usd > 0.0 {
+            self.spent_usd / self.budget.limit_usd
+        } else {
+            0.0
+        }
+    }
+
+    pub fn state(&self) -> BudgetState {
+        let ratio = self.ratio();
+        if ratio > 1.0 {
+            BudgetState::Exceeded
+        } else if ratio >= BUDGET_WARN_RATIO {
+            BudgetState::Warning
+        } else {
+            BudgetState::Ok
+        }
+    }
+
+    pub fn is_exceeded(&self) -> bool {
+        self.state() == BudgetState::Exceeded
+    }
+}
+
+/// Evaluate budgets for the periods containing `today`.
+///
+/// `source_summaries` are per-source daily summaries before source/model
+/// filtering (as in `LoadResult::source_summaries`), so each budget applies
+/// its own scope regardless of any report filters.
+pub fn evaluate(
+    budgets: &[Budget],
+    source_summaries: &[(String, Vec<DailySummary>)],
+    today: NaiveDate,
+    week_start: Weekday,
+) -> Vec<BudgetStatus> {
+    budgets
+        .iter()
+        .map(|budget| {
+            let (start, end) = budget.period.span(today, week_start);
+            let range = DateRange {
+                since: Some(start),
+                until: Some(end),
+            };
+            let (summaries, _) = DataLoaderService::apply_filter(source_summaries, &budget.scope());
+            let spent_usd = summaries
+                .iter()
+                .filter(|s| range.contains(s.date))
+                .fold(0.0, |total, s| total + s.total_cost_usd);
+            BudgetStatus {
+                budget: budget.clone(),
+                start,
+                end,
+                spent_usd,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ModelUsage, TokenCosts};
+    use std::collections::HashMap;
+
+    fn date(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, d).unwrap()
+    }
+
+    fn summary(day: u32, model: &str, cost: f64) -> DailySummary {
+        let usage = ModelUsage {
+            cost_usd: cost,
+            count: 1,
+            ..ModelUsage::default()
+        };
+        DailySummary {
+            date: date(day),
+            total_input_tokens: 0,
+            total_output_tokens: 0,
+            total_cache_read_tokens: 0,
+            total_cache_creation_tokens: 0,
+            total_thinking_tokens: 0,
+            total_cost_usd: cost,
+            total_token_costs: TokenCosts::default(),
+            models: HashMap::from([(model.to_string(), usage)]),
+            projects: HashMap::new(),
+            providers: HashMap::new(),
+            sources: HashMap::new(),
+        }
+    }
+
+    fn budget(period: BudgetPeriod, limit_usd: f64) -> Budget {
+        Budget {
+            period,
+            limit_usd,
+            source: None,
+            model: None,
+        }
+    }
+
+    fn sources() -> Vec<(String, Vec<DailySummary>)> {
+        vec![
+            (
+                "claude-code".to_string(),
+                vec![
+                    summary(1, "claude-opus-4-5", 30.0),
+                    summary(14, "claude-sonnet-4-5", 5.0),
+                    summary(15, "claude-opus-4-5", 10.0),
+                ],
+            ),
+            ("codex".to_string(), vec![summary(15, "gpt-5", 4.0)]),
+        ]
+    }
+
+    #[test]
+    fn test_evaluate_periods() {
+        // 2026-10-15 is a Thursday; the Sunday-start week begins on the 11th
+        let budgets = [
+            budget(BudgetPeriod::Daily, 10.0),
+            budget(BudgetPeriod::Weekly, 100.0),
+            budget(BudgetPeriod::Monthly, 40.0),
+        ];
+        let status = evaluate(&budgets, &sources(), date(15), Weekday::Sun);
+
+        assert_eq!(status[0].spent_usd, 14.0);
+        assert_eq!((status[0].start, status[0].end), (date(15), date(15)));
+        assert_eq!(status[0].state(), BudgetState::Exceeded);
+
+        assert_eq!(status[1].spent_usd, 19.0);
+        assert_eq!((status[1].start, status[1].end), (date(11), date(17)));
+        assert_eq!(status[1].state(), BudgetState::Ok);
+
+        assert_eq!(status[2].spent_usd, 49.0);
+        assert_eq!(status[2].end, date(31));
+        assert!(status[2].is_exceeded());
+    }
+
+    #[test]
+    fn test_evaluate_scoped_budgets() {
+        let budgets = [
+            Budget {
+                source: Some("codex".into()),
+                ..budget(BudgetPeriod::Daily, 5.0)
+            },
+            Budget {
+                model: Some("claude-opus*".into()),
+                ..budget(BudgetPeriod::Monthly, 50.0)
+            },
+        ];
+        let status = evaluate(&budgets, &sources(), date(15), Weekday::Sun);
+
+        assert_eq!(status[0].spent_usd, 4.0);
+        assert_eq!(status[0].state(), BudgetState::Warning);
+        assert_eq!(status[1].spent_usd, 40.0);
+        assert_eq!(status[1].state(), BudgetState::Warning);
+    }
+
+    #[test]
+    fn test_evaluate_no_spend_is_zero() {
+        let budgets = [Budget {
+            source: Some("gemini".into()),
+            ..budget(BudgetPeriod::Daily, 1.0)
+        }];
+        let status = evaluate(&budgets, &sources(), date(15), Weekday::Sun);
+        assert!(status[0].spent_usd.is_sign_positive());
+        assert_eq!(status[0].state(), BudgetState::Ok);
+    }
+
+    #[test]
+    fn test_state_thresholds() {
+        let status = |spent_usd| BudgetStatus {
+            budget: budget(BudgetPeriod::Daily, 10.0),
+            start: date(1),
+            end: date(1),
+            spent_usd,
+        };
+        assert_eq!(status(7.9).state(), BudgetState::Ok);
+        assert_eq!(status(8.0).state(), BudgetState::Warning);
+        assert_eq!(status(10.0).state(), BudgetState::Warning);
+        assert_eq!(status(10.01).state(), BudgetState::Exceeded);
+    }
+
+    #[test]
+    fn test_label() {
+        let b = Budget {
+            source: Some("codex".into()),
+            model: Some("gpt-5*".into()),
+            ..budget(BudgetPeriod::Weekly, 1.0)
+        };
+        assert_eq!(b.label(), "weekly codex gpt-5*");
+        assert_eq!(budget(BudgetPeriod::Monthly, 1.0).label(), "monthly");
+    }
+}
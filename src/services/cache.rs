@@ -3,6 +3,8 @@
 //! Caches daily summaries to preserve historical data even after
 //! original JSONL files are deleted.
 
+use crate::services::aggregator::merge_model_usage;
+use crate::services::provider::{source_provider, UNKNOWN_PROVIDER};
 use crate::services::{normalize_model_name, Aggregator};
 use crate::types::{CacheWarning, DailySummary, ModelUsage, Result, ToktrackError, UsageEntry};
 use chrono::{Local, NaiveDate};
@@ -21,21 +23,7 @@ fn normalize_model_keys(models: HashMap<String, ModelUsage>) -> HashMap<String,
         let key = normalize_model_name(&name);
         normalized
             .entry(key)
-            .and_modify(|existing| {
-                existing.input_tokens = existing.input_tokens.saturating_add(usage.input_tokens);
-                existing.output_tokens = existing.output_tokens.saturating_add(usage.output_tokens);
-                existing.cache_read_tokens = existing
-                    .cache_read_tokens
-                    .saturating_add(usage.cache_read_tokens);
-                existing.cache_creation_tokens = existing
-                    .cache_creation_tokens
-                    .saturating_add(usage.cache_creation_tokens);
-                existing.thinking_tokens = existing
-                    .thinking_tokens
-                    .saturating_add(usage.thinking_tokens);
-                existing.cost_usd += usage.cost_usd;
-                existing.count = existing.count.saturating_add(usage.count);
-            })
+            .and_modify(|existing| merge_model_usage(existing, &usage))
             .or_insert(usage);
     }
     normalized
@@ -43,7 +31,44 @@ fn normalize_model_keys(models: HashMap<String, ModelUsage>) -> HashMap<String,
 
 /// Bump when aggregation logic changes (e.g., timezone fix).
 /// Mismatched version → full cache invalidation.
-const CACHE_VERSION: u32 = 5;
+/// v6: per-project breakdown in DailySummary
+/// v7: per-turn Codex entries
+/// v8: canonical token semantics (uncached input for Codex/Gemini)
+/// v9: thinking tokens billed
+/// v10: long-context tier pricing
+/// v11: 1-hour cache writes tracked and priced separately
+/// v12: web search and service-tier charges
+/// v13: unpriced tokens tracked per model
+/// v14: per-provider breakdown
+/// v15: cost split by token type
+/// v16: prompt sizes, long-context tier tokens and reported costs recorded
+/// v17: Claude Code projects from each record's `cwd`
+const CACHE_VERSION: u32 = 17;
+
+/// Sources that log a cost with every request. Current Claude Code logs carry
+/// none, so its days were priced from tokens and can be repriced.
+const COST_REPORTING_SOURCES: &[&str] = &["opencode"];
+
+/// Sources whose input counted cache reads before v8
+const CACHE_INCLUSIVE_INPUT_SOURCES: &[&str] = &["codex", "gemini"];
+
+/// Take cache reads out of input, for days cached before v8 made them disjoint
+fn exclude_cache_reads(usage: &mut ModelUsage) {
+    usage.input_tokens = usage.input_tokens.saturating_sub(usage.cache_read_tokens);
+}
+
+/// Mark priced usage as possibly reported, for days cached before v16 recorded it
+fn mark_possibly_reported(models: &mut HashMap<String, ModelUsage>) {
+    for usage in models.values_mut() {
+        let tokens = usage
+            .input_tokens
+            .saturating_add(usage.output_tokens)
+            .saturating_add(usage.cache_read_tokens)
+            .saturating_add(usage.cache_creation_tokens)
+            .saturating_add(usage.thinking_tokens);
+        usage.cost_reported |= usage.unpriced_tokens < tokens;
+    }
+}
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DailySummaryCache {
@@ -136,6 +161,21 @@ impl DailySummaryCacheService {
         Ok((result, warning))
     }
 
+    /// Load every cached summary, including today, for rewriting (`toktrack reprice`).
+    /// Returns `None` without a cache; unreadable or corrupted caches are errors
+    /// so they are never overwritten.
+    pub fn load_all(&self, cli: &str) -> Result<Option<Vec<DailySummary>>> {
+        if !self.cache_path(cli).exists() {
+            return Ok(None);
+        }
+        match self.load_past_summaries(cli, NaiveDate::MAX) {
+            (_, Some(CacheWarning::LoadFailed(msg) | CacheWarning::Corrupted(msg))) => {
+                Err(ToktrackError::Cache(format!("{}: {}", cli, msg)))
+            }
+            (summaries, _) => Ok(Some(summaries)),
+        }
+    }
+
     #[allow(dead_code)]
     pub fn clear(&self, cli: &str) -> Result<()> {
         let path = self.cache_path(cli);
@@ -217,6 +257,9 @@ impl DailySummaryCacheService {
         };
 
         let _ = file.unlock();
+        let costs_unrecorded = cache.version < 16 && COST_REPORTING_SOURCES.contains(&cli);
+        let input_includes_cache_reads =
+            cache.version < 8 && CACHE_INCLUSIVE_INPUT_SOURCES.contains(&cli);
 
         // Migrate model names: normalize keys in the models HashMap
         let summaries: Vec<DailySummary> = cache
@@ -224,7 +267,35 @@ impl DailySummaryCacheService {
             .into_iter()
             .filter(|s| s.date < today)
             .map(|mut s| {
+                if input_includes_cache_reads {
+                    s.total_input_tokens = s
+                        .total_input_tokens
+                        .saturating_sub(s.total_cache_read_tokens);
+                    s.models.values_mut().for_each(exclude_cache_reads);
+                    s.projects.values_mut().for_each(exclude_cache_reads);
+                    s.providers
+                        .values_mut()
+                        .flat_map(|models| models.values_mut())
+                        .for_each(exclude_cache_reads);
+                }
                 s.models = normalize_model_keys(s.models);
+                s.providers = s
+                    .providers
+                    .into_iter()
+                    .map(|(provider, models)| (provider, normalize_model_keys(models)))
+                    .collect();
+                // Days cached before v14 have no provider breakdown:
+                // attribute them to the source CLI's own API
+                if s.providers.is_empty() && !s.models.is_empty() {
+                    let provider = source_provider(cli).unwrap_or(UNKNOWN_PROVIDER);
+                    s.providers.insert(provider.to_string(), s.models.clone());
+                }
+                // Whether costs were reported wasn't recorded before v16:
+                // keep repricing from overwriting costs these sources may have reported
+                if costs_unrecorded {
+                    mark_possibly_reported(&mut s.models);
+                    s.providers.values_mut().for_each(mark_possibly_reported);
+                }
                 s
             })
             .collect();
@@ -233,7 +304,7 @@ impl DailySummaryCacheService {
     }
 
     /// Save using atomic write (temp file + rename) with exclusive lock.
-    fn save_cache(&self, cli: &str, summaries: &[DailySummary]) -> Result<()> {
+    pub fn save_cache(&self, cli: &str, summaries: &[DailySummary]) -> Result<()> {
         fs::create_dir_all(&self.cache_dir)?;
 
         let cache = DailySummaryCache {
@@ -279,8 +350,9 @@ impl DailySummaryCacheService {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::TokenCosts;
     use chrono::{Datelike, TimeZone, Utc};
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, HashMap};
     use tempfile::TempDir;
 
     fn make_entry(
@@ -299,12 +371,22 @@ mod tests {
             output_tokens: output,
             cache_read_tokens: 0,
             cache_creation_tokens: 0,
+            cache_creation_1h_tokens: 0,
             thinking_tokens: 0,
             cost_usd: cost,
             message_id: None,
             request_id: None,
             source: None,
             provider: None,
+            project: None,
+            session_id: None,
+            web_search_requests: 0,
+            service_tier: None,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            token_costs: TokenCosts::default(),
+            long_context_tier: None,
+            cost_reported: false,
         }
     }
 
@@ -349,7 +431,11 @@ mod tests {
             total_cache_creation_tokens: 0,
             total_thinking_tokens: 0,
             total_cost_usd: 9.99,
+            total_token_costs: TokenCosts::default(),
             models: HashMap::new(),
+            projects: HashMap::new(),
+            providers: HashMap::new(),
+            sources: HashMap::new(),
         };
         let cache = DailySummaryCache {
             cli: "claude-code".to_string(),
@@ -370,12 +456,22 @@ mod tests {
                 output_tokens: 50,
                 cache_read_tokens: 0,
                 cache_creation_tokens: 0,
+                cache_creation_1h_tokens: 0,
                 thinking_tokens: 0,
                 cost_usd: Some(0.01),
                 message_id: None,
                 request_id: None,
                 source: None,
                 provider: None,
+                project: None,
+                session_id: None,
+                web_search_requests: 0,
+                service_tier: None,
+                web_search_cost_usd: 0.0,
+                service_tier_cost_usd: 0.0,
+                token_costs: TokenCosts::default(),
+                long_context_tier: None,
+                cost_reported: false,
             },
             UsageEntry {
                 timestamp: today.and_hms_opt(12, 0, 0).unwrap().and_utc(),
@@ -384,12 +480,22 @@ mod tests {
                 output_tokens: 100,
                 cache_read_tokens: 0,
                 cache_creation_tokens: 0,
+                cache_creation_1h_tokens: 0,
                 thinking_tokens: 0,
                 cost_usd: Some(0.02),
                 message_id: None,
                 request_id: None,
                 source: None,
                 provider: None,
+                project: None,
+                session_id: None,
+                web_search_requests: 0,
+                service_tier: None,
+                web_search_cost_usd: 0.0,
+                service_tier_cost_usd: 0.0,
+                token_costs: TokenCosts::default(),
+                long_context_tier: None,
+                cost_reported: false,
             },
         ];
 
@@ -426,6 +532,20 @@ mod tests {
         assert_eq!(result[0].total_input_tokens, 100);
     }
 
+    #[test]
+    fn test_cached_project_breakdown_survives_without_entries() {
+        let (service, _temp) = create_test_service();
+        let mut entry = make_entry(2024, 1, 10, Some("claude"), 100, 50, Some(0.01));
+        entry.project = Some("/work/monorepo".into());
+
+        service.load_or_compute("claude-code", &[entry]).unwrap();
+
+        // Session files deleted: project totals still come from cache
+        let (result, _) = service.load_or_compute("claude-code", &[]).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].projects["/work/monorepo"].input_tokens, 100);
+    }
+
     // Test 4: Empty entries returns empty result
     #[test]
     fn test_empty_entries_returns_empty() {
@@ -452,7 +572,11 @@ mod tests {
             total_cache_creation_tokens: 0,
             total_thinking_tokens: 0,
             total_cost_usd: 9.99,
+            total_token_costs: TokenCosts::default(),
             models: HashMap::new(),
+            projects: HashMap::new(),
+            providers: HashMap::new(),
+            sources: HashMap::new(),
         };
         let cache = DailySummaryCache {
             cli: "claude-code".to_string(),
@@ -472,12 +596,22 @@ mod tests {
             output_tokens: 50,
             cache_read_tokens: 0,
             cache_creation_tokens: 0,
+            cache_creation_1h_tokens: 0,
             thinking_tokens: 0,
             cost_usd: Some(0.01),
             message_id: None,
             request_id: None,
             source: None,
             provider: None,
+            project: None,
+            session_id: None,
+            web_search_requests: 0,
+            service_tier: None,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            token_costs: TokenCosts::default(),
+            long_context_tier: None,
+            cost_reported: false,
         }];
 
         let (result, _warning) = service.load_or_compute("claude-code", &entries).unwrap();
@@ -521,7 +655,11 @@ mod tests {
             total_cache_creation_tokens: 0,
             total_thinking_tokens: 0,
             total_cost_usd: 0.005,
+            total_token_costs: TokenCosts::default(),
             models: HashMap::new(),
+            projects: HashMap::new(),
+            providers: HashMap::new(),
+            sources: HashMap::new(),
         };
         let cache = DailySummaryCache {
             cli: "claude-code".to_string(),
@@ -541,12 +679,22 @@ mod tests {
             output_tokens: 100,
             cache_read_tokens: 0,
             cache_creation_tokens: 0,
+            cache_creation_1h_tokens: 0,
             thinking_tokens: 0,
             cost_usd: Some(0.02),
             message_id: None,
             request_id: None,
             source: None,
             provider: None,
+            project: None,
+            session_id: None,
+            web_search_requests: 0,
+            service_tier: None,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            token_costs: TokenCosts::default(),
+            long_context_tier: None,
+            cost_reported: false,
         }];
 
         let (result, _warning) = service.load_or_compute("claude-code", &entries).unwrap();
@@ -584,6 +732,28 @@ mod tests {
         assert!(!cache_path.exists());
     }
 
+    #[test]
+    fn test_load_all_includes_today_and_rejects_corrupted_cache() {
+        let (service, _temp) = create_test_service();
+        assert!(service.load_all("codex").unwrap().is_none());
+
+        let now = Local::now();
+        let mut today = make_entry(2024, 1, 10, Some("gpt-5"), 100, 50, Some(0.01));
+        today.timestamp = now.with_timezone(&Utc);
+        let entries = vec![
+            make_entry(2024, 1, 10, Some("gpt-5"), 100, 50, Some(0.01)),
+            today,
+        ];
+        service.load_or_compute("codex", &entries).unwrap();
+
+        let all = service.load_all("codex").unwrap().unwrap();
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[1].date, now.date_naive());
+
+        fs::write(service.cache_path("codex"), "{not json").unwrap();
+        assert!(service.load_all("codex").is_err());
+    }
+
     // Test 10: CLI isolation - different CLIs have separate caches
     #[test]
     fn test_cli_isolation() {
@@ -631,9 +801,18 @@ mod tests {
                 output_tokens: 50,
                 cache_read_tokens: 0,
                 cache_creation_tokens: 0,
+                cache_creation_1h_tokens: 0,
                 thinking_tokens: 0,
                 cost_usd: 0.10,
                 count: 1,
+                web_search_requests: 0,
+                web_search_cost_usd: 0.0,
+                service_tier_cost_usd: 0.0,
+                unpriced_tokens: 0,
+                token_costs: TokenCosts::default(),
+                cost_reported: false,
+                max_prompt_tokens: None,
+                tier_tokens: BTreeMap::new(),
             },
         );
         models.insert(
@@ -643,9 +822,18 @@ mod tests {
                 output_tokens: 100,
                 cache_read_tokens: 0,
                 cache_creation_tokens: 0,
+                cache_creation_1h_tokens: 0,
                 thinking_tokens: 0,
                 cost_usd: 0.20,
                 count: 2,
+                web_search_requests: 0,
+                web_search_cost_usd: 0.0,
+                service_tier_cost_usd: 0.0,
+                unpriced_tokens: 0,
+                token_costs: TokenCosts::default(),
+                cost_reported: false,
+                max_prompt_tokens: None,
+                tier_tokens: BTreeMap::new(),
             },
         );
 
@@ -657,7 +845,11 @@ mod tests {
             total_cache_creation_tokens: 0,
             total_thinking_tokens: 0,
             total_cost_usd: 0.30,
+            total_token_costs: TokenCosts::default(),
             models,
+            projects: HashMap::new(),
+            providers: HashMap::new(),
+            sources: HashMap::new(),
         };
         let cache = DailySummaryCache {
             cli: "claude-code".to_string(),
@@ -686,6 +878,10 @@ mod tests {
         assert_eq!(model.output_tokens, 150); // 50 + 100
         assert!((model.cost_usd - 0.30).abs() < f64::EPSILON); // 0.10 + 0.20
         assert_eq!(model.count, 3); // 1 + 2
+
+        // No provider breakdown cached: backfilled from the source's own API
+        assert_eq!(summary.providers.len(), 1);
+        assert_eq!(summary.providers["anthropic"]["claude-opus-4-5"].count, 3);
     }
 
     // Test 12: Old cache without version (deserialized as 0) triggers VersionMismatch
@@ -746,7 +942,11 @@ mod tests {
             total_cache_creation_tokens: 0,
             total_thinking_tokens: 0,
             total_cost_usd: 0.50,
+            total_token_costs: TokenCosts::default(),
             models: HashMap::new(),
+            projects: HashMap::new(),
+            providers: HashMap::new(),
+            sources: HashMap::new(),
         };
         let cache = DailySummaryCache {
             cli: "claude-code".to_string(),
@@ -835,4 +1035,96 @@ mod tests {
             serde_json::from_str(&fs::read_to_string(&cache_path).unwrap()).unwrap();
         assert_eq!(saved.version, CACHE_VERSION);
     }
+
+    #[test]
+    fn test_pre_v16_costs_are_kept_by_reprice_only_for_cost_reporting_sources() {
+        let (service, _temp) = create_test_service();
+        let old_date = Local::now().date_naive() - chrono::Duration::days(30);
+        let json = |cli: &str| {
+            serde_json::json!({
+                "cli": cli,
+                "version": 15,
+                "updated_at": 0,
+                "summaries": [{
+                    "date": old_date.to_string(),
+                    "total_input_tokens": 300,
+                    "total_output_tokens": 0,
+                    "total_cache_read_tokens": 0,
+                    "total_cache_creation_tokens": 0,
+                    "total_cost_usd": 0.5,
+                    "models": {
+                        "gpt-5": {
+                            "input_tokens": 100, "output_tokens": 0, "cache_read_tokens": 0,
+                            "cache_creation_tokens": 0, "cost_usd": 0.5, "count": 1
+                        },
+                        "new-model": {
+                            "input_tokens": 200, "output_tokens": 0, "cache_read_tokens": 0,
+                            "cache_creation_tokens": 0, "cost_usd": 0.0, "count": 1,
+                            "unpriced_tokens": 200
+                        }
+                    }
+                }]
+            })
+        };
+        for cli in ["opencode", "claude-code", "codex"] {
+            fs::write(service.cache_path(cli), json(cli).to_string()).unwrap();
+        }
+
+        let opencode = service.load_all("opencode").unwrap().unwrap();
+        assert!(opencode[0].models["gpt-5"].cost_reported);
+        assert!(opencode[0].providers[UNKNOWN_PROVIDER]["gpt-5"].cost_reported);
+        // Fully unpriced usage has no reported cost to keep
+        assert!(!opencode[0].models["new-model"].cost_reported);
+
+        for cli in ["claude-code", "codex"] {
+            let summaries = service.load_all(cli).unwrap().unwrap();
+            assert!(!summaries[0].models["gpt-5"].cost_reported);
+        }
+    }
+
+    #[test]
+    fn test_pre_v8_codex_input_excludes_cache_reads() {
+        let (service, _temp) = create_test_service();
+        let old_date = Local::now().date_naive() - chrono::Duration::days(30);
+        let json = |cli: &str, version: u32| {
+            serde_json::json!({
+                "cli": cli,
+                "version": version,
+                "updated_at": 0,
+                "summaries": [{
+                    "date": old_date.to_string(),
+                    "total_input_tokens": 1000,
+                    "total_output_tokens": 50,
+                    "total_cache_read_tokens": 800,
+                    "total_cache_creation_tokens": 0,
+                    "total_cost_usd": 0.5,
+                    "models": {
+                        "gpt-5": {
+                            "input_tokens": 1000, "output_tokens": 50, "cache_read_tokens": 800,
+                            "cache_creation_tokens": 0, "cost_usd": 0.5, "count": 1
+                        }
+                    }
+                }]
+            })
+        };
+
+        fs::write(service.cache_path("codex"), json("codex", 7).to_string()).unwrap();
+        let codex = service.load_all("codex").unwrap().unwrap();
+        assert_eq!(codex[0].total_input_tokens, 200);
+        assert_eq!(codex[0].models["gpt-5"].input_tokens, 200);
+        assert_eq!(codex[0].providers["openai"]["gpt-5"].input_tokens, 200);
+        assert_eq!(codex[0].models["gpt-5"].cache_read_tokens, 800);
+
+        // From v8 on, and for Claude at any version, input was already disjoint
+        fs::write(service.cache_path("codex"), json("codex", 8).to_string()).unwrap();
+        let codex = service.load_all("codex").unwrap().unwrap();
+        assert_eq!(codex[0].total_input_tokens, 1000);
+        fs::write(
+            service.cache_path("claude-code"),
+            json("claude-code", 7).to_string(),
+        )
+        .unwrap();
+        let claude = service.load_all("claude-code").unwrap().unwrap();
+        assert_eq!(claude[0].models["gpt-5"].input_tokens, 1000);
+    }
 }
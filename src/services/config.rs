@@ -0,0 +1,572 @@
+//! User configuration loaded from `~/.toktrack/config.toml`
+//!
+//! Every key is optional; anything missing falls back to the built-in default.
+//! Unknown keys and out-of-range values are rejected with `ToktrackError::Config`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::Weekday;
+use serde::Deserialize;
+
+use crate::parsers::ParserRegistry;
+use crate::services::budget::Budget;
+use crate::services::canonical_source;
+use crate::services::pricing::{ServiceTierMultipliers, DEFAULT_CACHE_TTL_SECS};
+use crate::services::pricing_overrides::{PricingOverrides, PRICING_OVERRIDES_FILE};
+use crate::services::provider::CostPolicies;
+use crate::types::{Result, ToktrackError};
+
+/// Default window for the warm path: files modified within this many hours are re-parsed
+pub const DEFAULT_WARM_WINDOW_HOURS: u64 = 24;
+
+/// Default cost spike ratios (relative to the daily average)
+pub const DEFAULT_SPIKE_ELEVATED: f64 = 1.5;
+pub const DEFAULT_SPIKE_HIGH: f64 = 2.0;
+
+/// On-disk layout; `None` means "use the default"
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RawConfig {
+    data_dirs: BTreeMap<String, RawPaths>,
+    cache: RawCacheConfig,
+    display: RawDisplayConfig,
+    budgets: Vec<RawBudget>,
+    provider_costs: BTreeMap<String, String>,
+    service_tiers: BTreeMap<String, f64>,
+}
+
+/// A single directory or a list of directories
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum RawPaths {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl RawPaths {
+    fn into_vec(self) -> Vec<String> {
+        match self {
+            Self::One(path) => vec![path],
+            Self::Many(paths) => paths,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RawCacheConfig {
+    pricing_ttl_secs: Option<u64>,
+    warm_window_hours: Option<u64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RawDisplayConfig {
+    week_start: Option<String>,
+    spike_elevated: Option<f64>,
+    spike_high: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawBudget {
+    period: String,
+    limit_usd: f64,
+    source: Option<String>,
+    model: Option<String>,
+}
+
+/// Validated configuration
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    /// Data directory overrides keyed by parser name (e.g. "claude-code").
+    /// Replaces the parser's default roots, including env-derived ones.
+    pub data_dirs: BTreeMap<String, Vec<PathBuf>>,
+    /// How long the LiteLLM pricing cache stays fresh
+    pub pricing_ttl_secs: u64,
+    /// Files modified within this window are re-parsed on the warm path
+    pub warm_window_hours: u64,
+    /// First day of the week for weekly reports and this-week/last-week
+    pub week_start: Weekday,
+    /// Cost at or above `avg * spike_elevated` is highlighted as elevated
+    pub spike_elevated: f64,
+    /// Cost at or above `avg * spike_high` is highlighted as high
+    pub spike_high: f64,
+    /// Spending limits from `[[budgets]]`, in file order
+    pub budgets: Vec<Budget>,
+    /// Local rates, aliases and discounts from `pricing_overrides.toml`
+    pub pricing_overrides: PricingOverrides,
+    /// How each provider's usage is costed, from `[provider_costs]` over the built-in defaults
+    pub provider_costs: CostPolicies,
+    /// Token-cost multiplier per service tier, from `[service_tiers]` over the built-in defaults
+    pub service_tiers: ServiceTierMultipliers,
+    /// Dotted keys set in the config file; everything else is a default
+    pub explicit: BTreeSet<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            data_dirs: BTreeMap::new(),
+            pricing_ttl_secs: DEFAULT_CACHE_TTL_SECS as u64,
+            warm_window_hours: DEFAULT_WARM_WINDOW_HOURS,
+            week_start: Weekday::Sun,
+            spike_elevated: DEFAULT_SPIKE_ELEVATED,
+            spike_high: DEFAULT_SPIKE_HIGH,
+            budgets: Vec::new(),
+            pricing_overrides: PricingOverrides::default(),
+            provider_costs: CostPolicies::default(),
+            service_tiers: ServiceTierMultipliers::default(),
+            explicit: BTreeSet::new(),
+        }
+    }
+}
+
+impl Config {
+    /// Default config path (~/.toktrack/config.toml)
+    pub fn default_path() -> Result<PathBuf> {
+        let home = directories::BaseDirs::new()
+            .ok_or_else(|| ToktrackError::Config("Failed to get home directory".into()))?
+            .home_dir()
+            .to_path_buf();
+        Ok(home.join(".toktrack").join("config.toml"))
+    }
+
+    /// Load from the default path; a missing file yields the defaults
+    pub fn load() -> Result<Self> {
+        Self::load_from(&Self::default_path()?)
+    }
+
+    /// Load from the given path; a missing file yields the defaults.
+    /// `pricing_overrides.toml` is read from the same directory.
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let mut config = match fs::read_to_string(path) {
+            Ok(contents) => Self::from_toml_str(&contents).map_err(|e| {
+                ToktrackError::Config(format!("{}: {}", path.display(), message(e)))
+            })?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Self::default(),
+            Err(e) => return Err(ToktrackError::Io(e)),
+        };
+        config.pricing_overrides =
+            PricingOverrides::load_from(&path.with_file_name(PRICING_OVERRIDES_FILE))?;
+        Ok(config)
+    }
+
+    /// Parse and validate config file contents
+    pub fn from_toml_str(contents: &str) -> Result<Self> {
+        let raw: RawConfig =
+            toml::from_str(contents).map_err(|e| ToktrackError::Config(e.message().into()))?;
+
+        let mut config = Self::default();
+
+        let known: Vec<String> = ParserRegistry::new()
+            .parsers()
+            .iter()
+            .map(|p| p.name().to_string())
+            .collect();
+        for (name, dirs) in raw.data_dirs {
+            let parser = canonical_source(&name);
+            if !known.contains(&parser) {
+                return Err(ToktrackError::Config(format!(
+                    "data_dirs: unknown source '{}' (expected one of: {})",
+                    name,
+                    known.join(", ")
+                )));
+            }
+            let dirs = dirs.into_vec();
+            if dirs.is_empty() || dirs.iter().any(|d| d.trim().is_empty()) {
+                return Err(ToktrackError::Config(format!(
+                    "data_dirs.{}: paths must not be empty",
+                    name
+                )));
+            }
+            config.explicit.insert(format!("data_dirs.{}", parser));
+            config
+                .data_dirs
+                .insert(parser, dirs.iter().map(|d| expand_home(d)).collect());
+        }
+
+        if let Some(ttl) = raw.cache.pricing_ttl_secs {
+            config.pricing_ttl_secs = ttl;
+            config.explicit.insert("cache.pricing_ttl_secs".into());
+        }
+        if let Some(hours) = raw.cache.warm_window_hours {
+            if hours == 0 {
+                return Err(ToktrackError::Config(
+                    "cache.warm_window_hours must be at least 1".into(),
+                ));
+            }
+            config.warm_window_hours = hours;
+            config.explicit.insert("cache.warm_window_hours".into());
+        }
+
+        if let Some(day) = raw.display.week_start {
+            config.week_start = day.trim().parse().map_err(|_| {
+                ToktrackError::Config(format!(
+                    "display.week_start: invalid weekday '{}' (expected e.g. sunday or monday)",
+                    day
+                ))
+            })?;
+            config.explicit.insert("display.week_start".into());
+        }
+        if let Some(ratio) = raw.display.spike_elevated {
+            config.spike_elevated = ratio;
+            config.explicit.insert("display.spike_elevated".into());
+        }
+        if let Some(ratio) = raw.display.spike_high {
+            config.spike_high = ratio;
+            config.explicit.insert("display.spike_high".into());
+        }
+        if !config.spike_elevated.is_finite() || config.spike_elevated <= 0.0 {
+            return Err(ToktrackError::Config(
+                "display.spike_elevated must be a positive number".into(),
+            ));
+        }
+        if !config.spike_high.is_finite() || config.spike_high < config.spike_elevated {
+            return Err(ToktrackError::Config(format!(
+                "display.spike_high ({}) must be >= display.spike_elevated ({})",
+                config.spike_high, config.spike_elevated
+            )));
+        }
+
+        for (i, raw) in raw.budgets.into_iter().enumerate() {
+            let invalid = |msg: String| ToktrackError::Config(format!("budgets[{}]: {}", i, msg));
+            let period = raw.period.parse().map_err(invalid)?;
+            if !raw.limit_usd.is_finite() || raw.limit_usd <= 0.0 {
+                return Err(invalid("limit_usd must be a positive number".into()));
+            }
+            let source = match raw.source {
+                Some(name) => {
+                    let source = canonical_source(&name);
+                    if !known.contains(&source) {
+                        return Err(invalid(format!(
+                            "unknown source '{}' (expected one of: {})",
+                            name,
+                            known.join(", ")
+                        )));
+                    }
+                    Some(source)
+                }
+                None => None,
+            };
+            if let Some(pattern) = &raw.model {
+                glob::Pattern::new(pattern)
+                    .map_err(|e| invalid(format!("invalid model pattern '{}': {}", pattern, e)))?;
+            }
+            config.budgets.push(Budget {
+                period,
+                limit_usd: raw.limit_usd,
+                source,
+                model: raw.model,
+            });
+        }
+
+        for (provider, policy) in raw.provider_costs {
+            let name = provider.trim().to_ascii_lowercase();
+            if name.is_empty() {
+                return Err(ToktrackError::Config(
+                    "provider_costs: provider names must not be empty".into(),
+                ));
+            }
+            let policy = policy
+                .parse()
+                .map_err(|e| ToktrackError::Config(format!("provider_costs.{}: {}", name, e)))?;
+            config.provider_costs.set(&name, policy);
+            config.explicit.insert(format!("provider_costs.{}", name));
+        }
+
+        for (tier, multiplier) in raw.service_tiers {
+            let name = tier.trim().to_ascii_lowercase();
+            if name.is_empty() {
+                return Err(ToktrackError::Config(
+                    "service_tiers: tier names must not be empty".into(),
+                ));
+            }
+            if !multiplier.is_finite() || multiplier < 0.0 {
+                return Err(ToktrackError::Config(format!(
+                    "service_tiers.{}: multiplier must be a non-negative number",
+                    name
+                )));
+            }
+            config.service_tiers.set(&name, multiplier);
+            config.explicit.insert(format!("service_tiers.{}", name));
+        }
+
+        Ok(config)
+    }
+
+    /// Whether the dotted key was left at its default
+    pub fn is_default(&self, key: &str) -> bool {
+        !self.explicit.contains(key)
+    }
+
+    /// Warm path window as a `Duration`
+    pub fn warm_window(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.warm_window_hours.saturating_mul(3600))
+    }
+}
+
+/// Lower-case weekday name as written in the config file
+pub fn weekday_name(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "monday",
+        Weekday::Tue => "tuesday",
+        Weekday::Wed => "wednesday",
+        Weekday::Thu => "thursday",
+        Weekday::Fri => "friday",
+        Weekday::Sat => "saturday",
+        Weekday::Sun => "sunday",
+    }
+}
+
+/// Expand a leading `~` to the home directory
+fn expand_home(path: &str) -> PathBuf {
+    let path = path.trim();
+    let rest = if path == "~" {
+        Some("")
+    } else {
+        path.strip_prefix("~/")
+    };
+    match (rest, directories::BaseDirs::new()) {
+        (Some(rest), Some(dirs)) => dirs.home_dir().join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Strip the "config error: " prefix so path-qualified messages read naturally
+fn message(err: ToktrackError) -> String {
+    match err {
+        ToktrackError::Config(msg) => msg,
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::budget::BudgetPeriod;
+    use crate::services::provider::CostPolicy;
+
+    #[test]
+    fn test_empty_config_is_default() {
+        let config = Config::from_toml_str("").unwrap();
+        assert_eq!(config, Config::default());
+        assert!(config.is_default("cache.pricing_ttl_secs"));
+    }
+
+    #[test]
+    fn test_full_config() {
+        let config = Config::from_toml_str(
+            r#"
+            [data_dirs]
+            claude = "/data/claude"
+            codex = ["/data/codex", "/backup/codex"]
+
+            [cache]
+            pricing_ttl_secs = 600
+            warm_window_hours = 48
+
+            [display]
+            week_start = "Monday"
+            spike_elevated = 1.2
+            spike_high = 3
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.data_dirs.get("claude-code"),
+            Some(&vec![PathBuf::from("/data/claude")])
+        );
+        assert_eq!(
+            config.data_dirs.get("codex"),
+            Some(&vec![
+                PathBuf::from("/data/codex"),
+                PathBuf::from("/backup/codex")
+            ])
+        );
+        assert_eq!(config.pricing_ttl_secs, 600);
+        assert_eq!(config.warm_window_hours, 48);
+        assert_eq!(config.week_start, Weekday::Mon);
+        assert_eq!(config.spike_elevated, 1.2);
+        assert_eq!(config.spike_high, 3.0);
+        assert!(!config.is_default("data_dirs.claude-code"));
+        assert!(config.is_default("data_dirs.gemini"));
+        assert!(!config.is_default("display.week_start"));
+    }
+
+    #[test]
+    fn test_home_expansion() {
+        let config = Config::from_toml_str("[data_dirs]\ngemini = \"~/gemini-logs\"").unwrap();
+        let dir = &config.data_dirs.get("gemini").unwrap()[0];
+        assert!(dir.ends_with("gemini-logs"));
+        assert!(!dir.starts_with("~"));
+    }
+
+    #[test]
+    fn test_rejects_unknown_key() {
+        let err = Config::from_toml_str("[cache]\nttl = 5").unwrap_err();
+        assert!(matches!(err, ToktrackError::Config(_)));
+        assert!(err.to_string().contains("ttl"));
+    }
+
+    #[test]
+    fn test_rejects_unknown_source() {
+        let err = Config::from_toml_str("[data_dirs]\ncursor = \"/x\"").unwrap_err();
+        assert!(err.to_string().contains("unknown source 'cursor'"));
+    }
+
+    #[test]
+    fn test_rejects_invalid_values() {
+        for contents in [
+            "[cache]\nwarm_window_hours = 0",
+            "[cache]\npricing_ttl_secs = -1",
+            "[display]\nweek_start = \"someday\"",
+            "[display]\nspike_elevated = 0",
+            "[display]\nspike_elevated = 2.5",
+            "[data_dirs]\ncodex = \"\"",
+            "[data_dirs]\ncodex = []",
+            "[data_dirs]\ncodex = 5",
+        ] {
+            let err = Config::from_toml_str(contents).unwrap_err();
+            assert!(
+                matches!(err, ToktrackError::Config(_)),
+                "expected config error for {:?}",
+                contents
+            );
+        }
+    }
+
+    #[test]
+    fn test_budgets() {
+        let config = Config::from_toml_str(
+            r#"
+            [[budgets]]
+            period = "monthly"
+            limit_usd = 200
+
+            [[budgets]]
+            period = "Daily"
+            limit_usd = 12.5
+            source = "claude"
+            model = "claude-opus*"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.budgets.len(), 2);
+        assert_eq!(config.budgets[0].period, BudgetPeriod::Monthly);
+        assert_eq!(config.budgets[0].limit_usd, 200.0);
+        assert_eq!(config.budgets[0].source, None);
+        assert_eq!(config.budgets[1].period, BudgetPeriod::Daily);
+        assert_eq!(config.budgets[1].source.as_deref(), Some("claude-code"));
+        assert_eq!(config.budgets[1].model.as_deref(), Some("claude-opus*"));
+    }
+
+    #[test]
+    fn test_rejects_invalid_budgets() {
+        for contents in [
+            "[[budgets]]\nperiod = \"yearly\"\nlimit_usd = 1",
+            "[[budgets]]\nperiod = \"daily\"\nlimit_usd = 0",
+            "[[budgets]]\nperiod = \"daily\"",
+            "[[budgets]]\nperiod = \"daily\"\nlimit_usd = 1\nsource = \"cursor\"",
+            "[[budgets]]\nperiod = \"daily\"\nlimit_usd = 1\nmodel = \"[\"",
+            "[[budgets]]\nperiod = \"daily\"\nlimit_usd = 1\ncap = 2",
+        ] {
+            let err = Config::from_toml_str(contents).unwrap_err();
+            assert!(
+                matches!(err, ToktrackError::Config(_)),
+                "expected config error for {:?}",
+                contents
+            );
+        }
+    }
+
+    #[test]
+    fn test_load_missing_file_is_default() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::load_from(&dir.path().join("config.toml")).unwrap();
+        assert_eq!(config, Config::default());
+    }
+
+    #[test]
+    fn test_load_error_mentions_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, "[cache\n").unwrap();
+        let err = Config::load_from(&path).unwrap_err().to_string();
+        assert!(err.starts_with("config error: "));
+        assert!(err.contains("config.toml"));
+    }
+
+    #[test]
+    fn test_load_reads_pricing_overrides_alongside() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join(PRICING_OVERRIDES_FILE),
+            "[aliases]\n\"corp-sonnet\" = \"claude-sonnet-4\"\n",
+        )
+        .unwrap();
+        let config = Config::load_from(&dir.path().join("config.toml")).unwrap();
+        assert_eq!(
+            config.pricing_overrides.aliases.get("corp-sonnet"),
+            Some(&"claude-sonnet-4".to_string())
+        );
+    }
+
+    #[test]
+    fn test_provider_costs_extend_defaults() {
+        let config = Config::from_toml_str(
+            "[provider_costs]\nopenrouter = \"Reported\"\n\"github-copilot\" = \"calculate\"",
+        )
+        .unwrap();
+        assert_eq!(
+            config.provider_costs.get("openrouter"),
+            CostPolicy::Reported
+        );
+        assert_eq!(
+            config.provider_costs.get("github-copilot"),
+            CostPolicy::Calculate
+        );
+        assert_eq!(
+            config.provider_costs.get("github-copilot-enterprise"),
+            CostPolicy::Free
+        );
+        assert!(!config.is_default("provider_costs.openrouter"));
+        assert!(config.is_default("provider_costs.github-copilot-enterprise"));
+
+        let err = Config::from_toml_str("[provider_costs]\nopenrouter = \"cheap\"").unwrap_err();
+        assert!(err.to_string().contains("provider_costs.openrouter"));
+        assert!(Config::from_toml_str("[provider_costs]\nopenrouter = 1").is_err());
+    }
+
+    #[test]
+    fn test_service_tiers_extend_defaults() {
+        let config = Config::from_toml_str(
+            "[service_tiers]
+Priority = 2
+flex = 0.4",
+        )
+        .unwrap();
+        assert_eq!(config.service_tiers.get(Some("priority")), 2.0);
+        assert_eq!(config.service_tiers.get(Some("flex")), 0.4);
+        assert_eq!(config.service_tiers.get(Some("batch")), 0.5);
+        assert_eq!(config.service_tiers.get(Some("standard")), 1.0);
+        assert!(!config.is_default("service_tiers.priority"));
+        assert!(config.is_default("service_tiers.batch"));
+
+        let err = Config::from_toml_str(
+            "[service_tiers]
+priority = -1",
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("service_tiers.priority"));
+        assert!(Config::from_toml_str(
+            "[service_tiers]
+priority = \"high\""
+        )
+        .is_err());
+    }
+}
@@ -6,8 +6,14 @@
 use std::collections::HashMap;
 
 use crate::parsers::ParserRegistry;
-use crate::services::{Aggregator, DailySummaryCacheService, PricingService};
-use crate::types::{CacheWarning, DailySummary, Result, SourceUsage, ToktrackError, UsageEntry};
+use crate::services::pricing::{Charges, ServiceTierMultipliers};
+use crate::services::pricing_overrides::PricingOverrides;
+use crate::services::provider::{provider_of, CostPolicies, CostPolicy};
+use crate::services::{Aggregator, Config, DailySummaryCacheService, PricingService, UsageFilter};
+use crate::types::{
+    CacheWarning, DailySummary, PricingInfo, Result, SourceUsage, TokenCosts, ToktrackError,
+    UnpricedUsage, UsageEntry,
+};
 
 /// Result of loading data from all parsers
 #[derive(Debug)]
@@ -16,8 +22,15 @@ pub struct LoadResult {
     pub summaries: Vec<DailySummary>,
     /// Usage breakdown by source CLI
     pub source_usage: Vec<SourceUsage>,
+    /// Per-source daily summaries within the date range, before source/model filters.
+    /// Lets callers re-apply a different `UsageFilter` without reloading.
+    pub source_summaries: Vec<(String, Vec<DailySummary>)>,
     /// Cache warning indicator (if any)
     pub cache_warning: Option<CacheWarning>,
+    /// Pricing source used for entries costed in this load (`None` if none was available)
+    pub pricing: Option<PricingInfo>,
+    /// Tokens of filtered usage that no price was found for, by model
+    pub unpriced: UnpricedUsage,
 }
 
 /// Unified data loading service
@@ -29,25 +42,47 @@ pub struct DataLoaderService {
     registry: ParserRegistry,
     cache_service: Option<DailySummaryCacheService>,
     pricing: Option<PricingService>,
+    /// Applied to whichever pricing table ends up being used
+    pricing_overrides: PricingOverrides,
+    service_tiers: ServiceTierMultipliers,
+    cost_policies: CostPolicies,
+    filter: UsageFilter,
+    /// Files modified within this window are re-parsed on the warm path
+    warm_window: std::time::Duration,
 }
 
 impl DataLoaderService {
-    /// Create a new data loader service
+    /// Create a new data loader service with default settings
     pub fn new() -> Self {
+        Self::from_config(&Config::default())
+    }
+
+    /// Create a data loader using the data directories, pricing TTL and
+    /// warm-path window from the user config
+    pub fn from_config(config: &Config) -> Self {
         Self {
-            registry: ParserRegistry::new(),
+            registry: ParserRegistry::from_config(config),
             cache_service: DailySummaryCacheService::new().ok(),
-            pricing: PricingService::from_cache_only(),
+            pricing: PricingService::from_config(config),
+            pricing_overrides: config.pricing_overrides.clone(),
+            cost_policies: config.provider_costs.clone(),
+            service_tiers: config.service_tiers.clone(),
+            filter: UsageFilter::default(),
+            warm_window: config.warm_window(),
         }
     }
 
+    /// Restrict loaded summaries, source stats and entries with the given filter
+    pub fn with_filter(mut self, filter: UsageFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
     /// Load data from all parsers using cache-first strategy
     pub fn load(&self) -> Result<LoadResult> {
         if self.has_valid_cache() {
             if let Ok(result) = self.load_warm_path() {
-                if !result.summaries.is_empty() {
-                    return Ok(result);
-                }
+                return Ok(result);
             }
         }
 
@@ -71,11 +106,12 @@ impl DataLoaderService {
             .as_ref()
             .ok_or_else(|| ToktrackError::Cache("No cache service".into()))?;
 
-        let since = std::time::SystemTime::now() - std::time::Duration::from_secs(24 * 3600);
+        let since = std::time::SystemTime::now() - self.warm_window;
 
-        let mut all_summaries = Vec::new();
-        let mut source_stats: HashMap<String, (u64, f64)> = HashMap::new();
+        let mut source_summaries = Vec::new();
         let mut cache_warning = None;
+        // Tracked before date filtering so an empty range doesn't force a cold parse
+        let mut any_summaries = false;
 
         for parser in self.registry.parsers() {
             let has_parser_cache = cache_service.cache_path(parser.name()).exists();
@@ -105,8 +141,9 @@ impl DataLoaderService {
                     if warning.is_some() && cache_warning.is_none() {
                         cache_warning = warning;
                     }
-                    self.collect_source_stats(&summaries, parser.name(), &mut source_stats);
-                    all_summaries.extend(summaries);
+                    any_summaries |= !summaries.is_empty();
+                    let summaries = self.filter.date_range.filter_summaries(summaries);
+                    source_summaries.push((parser.name().to_string(), summaries));
                 }
                 Err(e) => {
                     eprintln!(
@@ -118,14 +155,12 @@ impl DataLoaderService {
             }
         }
 
-        let all_summaries = Aggregator::merge_by_date(all_summaries);
-        let source_usage = Self::build_source_usage(source_stats);
+        if !any_summaries {
+            return Err(ToktrackError::Cache("No cached summaries".into()));
+        }
 
-        Ok(LoadResult {
-            summaries: all_summaries,
-            source_usage,
-            cache_warning,
-        })
+        let pricing = self.pricing.as_ref().map(PricingService::info);
+        Ok(self.build_result(source_summaries, cache_warning, pricing))
     }
 
     /// Cold path: full parse_all() per parser + build cache
@@ -135,13 +170,15 @@ impl DataLoaderService {
         let pricing_ref = match &self.pricing {
             Some(p) => Some(p),
             None => {
-                fallback_pricing = PricingService::new().ok();
+                fallback_pricing = PricingService::new().ok().map(|p| {
+                    p.with_overrides(&self.pricing_overrides)
+                        .with_service_tiers(&self.service_tiers)
+                });
                 fallback_pricing.as_ref()
             }
         };
 
-        let mut all_summaries = Vec::new();
-        let mut source_stats: HashMap<String, (u64, f64)> = HashMap::new();
+        let mut source_summaries = Vec::new();
         let mut cache_warning = None;
         let mut any_entries = false;
 
@@ -168,8 +205,8 @@ impl DataLoaderService {
                         if warning.is_some() && cache_warning.is_none() {
                             cache_warning = warning;
                         }
-                        self.collect_source_stats(&summaries, parser.name(), &mut source_stats);
-                        all_summaries.extend(summaries);
+                        let summaries = self.filter.date_range.filter_summaries(summaries);
+                        source_summaries.push((parser.name().to_string(), summaries));
                         continue;
                     }
                     Err(e) => {
@@ -183,9 +220,11 @@ impl DataLoaderService {
             }
 
             // Cache unavailable: compute summaries directly
-            let summaries = Aggregator::daily(&entries);
-            self.collect_source_stats(&summaries, parser.name(), &mut source_stats);
-            all_summaries.extend(summaries);
+            let summaries = self
+                .filter
+                .date_range
+                .filter_summaries(Aggregator::daily(&entries));
+            source_summaries.push((parser.name().to_string(), summaries));
         }
 
         if !any_entries {
@@ -194,14 +233,101 @@ impl DataLoaderService {
             ));
         }
 
-        let all_summaries = Aggregator::merge_by_date(all_summaries);
-        let source_usage = Self::build_source_usage(source_stats);
+        let pricing = pricing_ref.map(PricingService::info);
+        Ok(self.build_result(source_summaries, cache_warning, pricing))
+    }
 
-        Ok(LoadResult {
-            summaries: all_summaries,
+    /// Apply source/model filters to per-source summaries and assemble the result
+    fn build_result(
+        &self,
+        source_summaries: Vec<(String, Vec<DailySummary>)>,
+        cache_warning: Option<CacheWarning>,
+        pricing: Option<PricingInfo>,
+    ) -> LoadResult {
+        let (summaries, source_usage) = Self::apply_filter(&source_summaries, &self.filter);
+        LoadResult {
+            unpriced: UnpricedUsage::from_daily_summaries(&summaries),
+            summaries,
             source_usage,
+            source_summaries,
             cache_warning,
-        })
+            pricing,
+        }
+    }
+
+    /// Merge per-source summaries into daily totals and source stats,
+    /// keeping only the sources and models the filter allows. Each merged
+    /// summary keeps a per-source model breakdown in `sources`.
+    /// The date range is not re-applied here; loaders already did that.
+    pub fn apply_filter(
+        source_summaries: &[(String, Vec<DailySummary>)],
+        filter: &UsageFilter,
+    ) -> (Vec<DailySummary>, Vec<SourceUsage>) {
+        let mut all_summaries = Vec::new();
+        let mut source_stats: HashMap<String, (u64, f64)> = HashMap::new();
+
+        for (source, summaries) in source_summaries {
+            if !filter.matches_source(source) {
+                continue;
+            }
+            let summaries: Vec<DailySummary> = summaries
+                .iter()
+                .filter_map(|s| filter.restrict_summary(s))
+                .map(|mut s| {
+                    s.sources = HashMap::from([(source.clone(), s.models.clone())]);
+                    s
+                })
+                .collect();
+            Self::collect_source_stats(&summaries, source, &mut source_stats);
+            all_summaries.extend(summaries);
+        }
+
+        (
+            Aggregator::merge_by_date(all_summaries),
+            Self::build_source_usage(source_stats),
+        )
+    }
+
+    /// Parse raw entries from every parser (bypassing the summary cache), with pricing applied.
+    /// Used by views that cannot be rebuilt from daily summaries, such as sessions.
+    pub fn load_entries(&self) -> Result<Vec<UsageEntry>> {
+        let fallback_pricing;
+        let pricing_ref = match &self.pricing {
+            Some(p) => Some(p),
+            None => {
+                fallback_pricing = PricingService::new().ok().map(|p| {
+                    p.with_overrides(&self.pricing_overrides)
+                        .with_service_tiers(&self.service_tiers)
+                });
+                fallback_pricing.as_ref()
+            }
+        };
+
+        let mut all_entries = Vec::new();
+        let mut any_entries = false;
+        for parser in self.registry.parsers() {
+            if !self.filter.matches_source(parser.name()) {
+                continue;
+            }
+            match parser.parse_all() {
+                Ok(entries) => {
+                    any_entries |= !entries.is_empty();
+                    let entries = self.filter.filter_entries(entries);
+                    all_entries.extend(self.apply_pricing_with_ref(entries, pricing_ref));
+                }
+                Err(e) => {
+                    eprintln!("[toktrack] Warning: {} failed: {}", parser.name(), e);
+                }
+            }
+        }
+
+        if !any_entries {
+            return Err(ToktrackError::Parse(
+                "No usage data found from any CLI".into(),
+            ));
+        }
+
+        Ok(all_entries)
     }
 
     /// Apply pricing to entries using cached pricing service
@@ -209,7 +335,10 @@ impl DataLoaderService {
         self.apply_pricing_with_ref(entries, self.pricing.as_ref())
     }
 
-    /// Apply pricing to entries using the given pricing service reference
+    /// Apply pricing to entries using the given pricing service reference,
+    /// following each entry's provider cost policy.
+    /// Entries without a reported cost or a known price keep `cost_usd: None`,
+    /// which the aggregator counts as unpriced tokens.
     fn apply_pricing_with_ref(
         &self,
         entries: Vec<UsageEntry>,
@@ -218,13 +347,35 @@ impl DataLoaderService {
         entries
             .into_iter()
             .map(|mut entry| {
-                // GitHub Copilot is free, override cost to 0
-                if is_copilot_provider(entry.provider.as_deref()) {
-                    entry.cost_usd = Some(0.0);
-                } else if entry.cost_usd.is_none() || entry.cost_usd == Some(0.0) {
-                    if let Some(p) = pricing {
-                        entry.cost_usd = Some(p.calculate_cost(&entry));
+                let reported = entry.cost_usd.filter(|&c| c != 0.0);
+                let charges = pricing.and_then(|p| p.calculate_charges(&entry));
+                entry.token_costs = TokenCosts::default();
+                entry.long_context_tier = None;
+                entry.cost_reported = false;
+                match self.cost_policies.get(&provider_of(&entry)) {
+                    CostPolicy::Free => {
+                        entry.cost_usd = Some(0.0);
+                        entry.web_search_cost_usd = 0.0;
+                        entry.service_tier_cost_usd = 0.0;
+                    }
+                    CostPolicy::Reported | CostPolicy::Auto if reported.is_some() => {
+                        entry.cost_usd = reported;
+                        entry.cost_reported = reported.is_some();
+                        if let (Some(reported), Some(charges)) = (reported, charges) {
+                            entry.token_costs = split_reported_cost(reported, &charges);
+                        }
                     }
+                    CostPolicy::Reported => entry.cost_usd = None,
+                    CostPolicy::Auto | CostPolicy::Calculate => match charges {
+                        Some(charges) => {
+                            entry.cost_usd = Some(charges.total());
+                            entry.web_search_cost_usd = charges.web_search_usd;
+                            entry.service_tier_cost_usd = charges.service_tier_usd;
+                            entry.token_costs = charges.token_costs;
+                            entry.long_context_tier = charges.long_context_tier;
+                        }
+                        None => entry.cost_usd = None,
+                    },
                 }
                 entry
             })
@@ -233,7 +384,6 @@ impl DataLoaderService {
 
     /// Collect source statistics from summaries
     fn collect_source_stats(
-        &self,
         summaries: &[DailySummary],
         source_name: &str,
         stats: &mut HashMap<String, (u64, f64)>,
@@ -261,7 +411,7 @@ impl DataLoaderService {
             })
             .collect();
         // Sort by total_tokens descending
-        result.sort_by(|a, b| b.total_tokens.cmp(&a.total_tokens));
+        result.sort_by_key(|s| std::cmp::Reverse(s.total_tokens));
         result
     }
 }
@@ -272,48 +422,122 @@ impl Default for DataLoaderService {
     }
 }
 
-/// Check if provider is GitHub Copilot (free service)
-pub fn is_copilot_provider(provider: Option<&str>) -> bool {
-    matches!(
-        provider,
-        Some("github-copilot") | Some("github-copilot-enterprise")
-    )
+/// Spread a reported cost over token types in the proportions list prices give
+fn split_reported_cost(reported: f64, charges: &Charges) -> TokenCosts {
+    let listed = charges.token_costs.total();
+    if listed > 0.0 {
+        charges.token_costs.scaled(reported / listed)
+    } else {
+        TokenCosts::default()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::BTreeMap;
 
-    // ========== is_copilot_provider tests ==========
-
-    #[test]
-    fn test_is_copilot_provider_github_copilot() {
-        assert!(is_copilot_provider(Some("github-copilot")));
-    }
+    // ========== apply_filter tests ==========
 
-    #[test]
-    fn test_is_copilot_provider_github_copilot_enterprise() {
-        assert!(is_copilot_provider(Some("github-copilot-enterprise")));
-    }
-
-    #[test]
-    fn test_is_copilot_provider_anthropic() {
-        assert!(!is_copilot_provider(Some("anthropic")));
-    }
-
-    #[test]
-    fn test_is_copilot_provider_openai() {
-        assert!(!is_copilot_provider(Some("openai")));
+    fn make_source_summary(date: &str, model: &str, input: u64, cost: f64) -> DailySummary {
+        let usage = crate::types::ModelUsage {
+            input_tokens: input,
+            output_tokens: 0,
+            cache_read_tokens: 0,
+            cache_creation_tokens: 0,
+            cache_creation_1h_tokens: 0,
+            thinking_tokens: 0,
+            cost_usd: cost,
+            count: 1,
+            web_search_requests: 0,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            unpriced_tokens: 0,
+            token_costs: TokenCosts::default(),
+            cost_reported: false,
+            max_prompt_tokens: None,
+            tier_tokens: BTreeMap::new(),
+        };
+        DailySummary {
+            date: date.parse().unwrap(),
+            total_input_tokens: input,
+            total_output_tokens: 0,
+            total_cache_read_tokens: 0,
+            total_cache_creation_tokens: 0,
+            total_thinking_tokens: 0,
+            total_cost_usd: cost,
+            total_token_costs: TokenCosts::default(),
+            models: HashMap::from([(model.to_string(), usage)]),
+            projects: HashMap::new(),
+            providers: HashMap::new(),
+            sources: HashMap::new(),
+        }
     }
 
     #[test]
-    fn test_is_copilot_provider_none() {
-        assert!(!is_copilot_provider(None));
+    fn test_apply_filter_unfiltered_merges_sources() {
+        let source_summaries = vec![
+            (
+                "claude".to_string(),
+                vec![make_source_summary("2026-09-01", "claude-opus-4", 100, 1.0)],
+            ),
+            (
+                "gemini".to_string(),
+                vec![make_source_summary("2026-09-01", "gemini-2.5-pro", 50, 0.5)],
+            ),
+        ];
+        let (summaries, source_usage) =
+            DataLoaderService::apply_filter(&source_summaries, &UsageFilter::default());
+
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].total_input_tokens, 150);
+        assert_eq!(source_usage.len(), 2);
+        assert_eq!(source_usage[0].source, "claude");
+
+        // Each source's models are kept apart in the merged day
+        let sources = &summaries[0].sources;
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources["claude"]["claude-opus-4"].input_tokens, 100);
+        assert_eq!(sources["gemini"]["gemini-2.5-pro"].input_tokens, 50);
     }
 
     #[test]
-    fn test_is_copilot_provider_empty_string() {
-        assert!(!is_copilot_provider(Some("")));
+    fn test_apply_filter_by_source_and_model() {
+        let source_summaries = vec![
+            (
+                "claude".to_string(),
+                vec![
+                    make_source_summary("2026-09-01", "claude-opus-4", 100, 1.0),
+                    make_source_summary("2026-09-02", "claude-sonnet-4", 40, 0.2),
+                ],
+            ),
+            (
+                "gemini".to_string(),
+                vec![make_source_summary("2026-09-01", "gemini-2.5-pro", 50, 0.5)],
+            ),
+        ];
+
+        let by_source = UsageFilter {
+            sources: Some(vec!["gemini".into()]),
+            ..UsageFilter::default()
+        };
+        let (summaries, source_usage) =
+            DataLoaderService::apply_filter(&source_summaries, &by_source);
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].total_input_tokens, 50);
+        assert_eq!(source_usage.len(), 1);
+        assert_eq!(source_usage[0].source, "gemini");
+
+        let by_model = UsageFilter {
+            models: Some(vec!["claude-opus*".into()]),
+            ..UsageFilter::default()
+        };
+        let (summaries, source_usage) =
+            DataLoaderService::apply_filter(&source_summaries, &by_model);
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].total_input_tokens, 100);
+        assert_eq!(source_usage.len(), 1);
+        assert!((source_usage[0].total_cost_usd - 1.0).abs() < f64::EPSILON);
     }
 
     // ========== build_source_usage tests ==========
@@ -381,12 +605,22 @@ mod tests {
             output_tokens: 500,
             cache_read_tokens: 0,
             cache_creation_tokens: 0,
+            cache_creation_1h_tokens: 0,
             thinking_tokens: 0,
             cost_usd,
             message_id: None,
             request_id: None,
             source: None,
             provider: provider.map(|s| s.to_string()),
+            project: None,
+            session_id: None,
+            web_search_requests: 0,
+            service_tier: None,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            token_costs: TokenCosts::default(),
+            long_context_tier: None,
+            cost_reported: false,
         }
     }
 
@@ -424,5 +658,79 @@ mod tests {
         let result = service.apply_pricing(entries);
         // Copilot should always be $0 regardless of original cost
         assert_eq!(result[0].cost_usd, Some(0.0));
+
+        let entries = vec![make_entry(None, Some("github-copilot-enterprise"))];
+        assert_eq!(service.apply_pricing(entries)[0].cost_usd, Some(0.0));
+    }
+
+    #[test]
+    fn test_apply_pricing_follows_configured_policies() {
+        let config = Config::from_toml_str(
+            "[provider_costs]\nopenrouter = \"reported\"\nanthropic = \"calculate\"\n\"github-copilot\" = \"auto\"",
+        )
+        .unwrap();
+        let service = DataLoaderService::from_config(&config);
+
+        // Reported: the CLI's cost or nothing
+        let result = service.apply_pricing(vec![
+            make_entry(Some(0.05), Some("openrouter")),
+            make_entry(Some(0.0), Some("openrouter")),
+        ]);
+        assert_eq!(result[0].cost_usd, Some(0.05));
+        assert_eq!(result[1].cost_usd, None);
+
+        // Calculate: the reported cost is replaced (or dropped without pricing)
+        let result = service.apply_pricing(vec![make_entry(Some(123.0), Some("anthropic"))]);
+        assert_ne!(result[0].cost_usd, Some(123.0));
+
+        // Copilot's default can be overridden
+        let result = service.apply_pricing(vec![make_entry(Some(0.10), Some("github-copilot"))]);
+        assert_eq!(result[0].cost_usd, Some(0.10));
+    }
+
+    #[test]
+    fn test_apply_pricing_splits_cost_by_token_type() {
+        use crate::services::pricing::{ModelPricing, PricingCache, PRICING_CACHE_SCHEMA};
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let cache_path = temp_dir.path().join("pricing.json");
+        let cache = PricingCache {
+            fetched_at: 0,
+            schema: PRICING_CACHE_SCHEMA,
+            models: HashMap::from([(
+                "claude-sonnet-4-5".to_string(),
+                ModelPricing {
+                    input_cost_per_token: Some(3e-6),
+                    output_cost_per_token: Some(1.5e-5),
+                    ..ModelPricing::default()
+                },
+            )]),
+        };
+        std::fs::write(&cache_path, serde_json::to_string(&cache).unwrap()).unwrap();
+        let pricing = PricingService::from_cache_only_with_path(&cache_path).unwrap();
+        let service = DataLoaderService::new();
+
+        let result = service.apply_pricing_with_ref(
+            vec![
+                make_entry(None, Some("anthropic")),
+                make_entry(Some(0.021), Some("openrouter")),
+                make_entry(Some(0.021), Some("github-copilot")),
+            ],
+            Some(&pricing),
+        );
+
+        // Calculated: $0.003 input + $0.0075 output
+        let costs = result[0].token_costs;
+        assert!((costs.input_usd - 0.003).abs() < 1e-12);
+        assert!((costs.output_usd - 0.0075).abs() < 1e-12);
+        // Reported $0.021 is split in the same 2:5 proportion
+        let costs = result[1].token_costs;
+        assert!((costs.input_usd - 0.006).abs() < 1e-12);
+        assert!((costs.output_usd - 0.015).abs() < 1e-12);
+        // Free usage has nothing to split
+        assert_eq!(result[2].token_costs, TokenCosts::default());
+        // Only the reported cost is marked as such, so repricing keeps it
+        assert!(!result[0].cost_reported);
+        assert!(result[1].cost_reported);
     }
 }
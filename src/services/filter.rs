@@ -0,0 +1,666 @@
+//! Date-range, source and model filtering for reports
+//!
+//! Date specs accept absolute dates (`2026-09-01`) and relative specs (`7d`, `2w`,
+//! `today`, `yesterday`, `this-week`, `last-week`, `this-month`,
+//! `last-month`, `this-year`). Weeks start on the configured weekday
+//! (Sunday by default), matching `Aggregator::weekly`.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use chrono::{Datelike, Duration, Local, NaiveDate, Weekday};
+use glob::{MatchOptions, Pattern};
+
+use crate::services::aggregator::merge_model_usage;
+use crate::services::normalize_model_name;
+use crate::services::provider::provider_of;
+use crate::types::{DailySummary, ModelUsage, Result, SessionSummary, ToktrackError, UsageEntry};
+
+/// A date expression as given on the command line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateSpec {
+    Date(NaiveDate),
+    Today,
+    Yesterday,
+    /// Last N days, including today
+    Days(u32),
+    /// Last N weeks (7N days), including today
+    Weeks(u32),
+    ThisWeek,
+    LastWeek,
+    ThisMonth,
+    LastMonth,
+    ThisYear,
+}
+
+impl FromStr for DateSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let s = s.trim().to_ascii_lowercase();
+        match s.as_str() {
+            "today" => return Ok(Self::Today),
+            "yesterday" => return Ok(Self::Yesterday),
+            "this-week" => return Ok(Self::ThisWeek),
+            "last-week" => return Ok(Self::LastWeek),
+            "this-month" => return Ok(Self::ThisMonth),
+            "last-month" => return Ok(Self::LastMonth),
+            "this-year" => return Ok(Self::ThisYear),
+            _ => {}
+        }
+
+        if let Ok(date) = NaiveDate::parse_from_str(&s, "%Y-%m-%d") {
+            return Ok(Self::Date(date));
+        }
+
+        let relative = |suffix: char| -> Option<u32> {
+            s.strip_suffix(suffix)
+                .and_then(|n| n.parse::<u32>().ok())
+                .filter(|n| *n > 0)
+        };
+        if let Some(n) = relative('d') {
+            return Ok(Self::Days(n));
+        }
+        if let Some(n) = relative('w') {
+            return Ok(Self::Weeks(n));
+        }
+
+        Err(format!(
+            "invalid date '{}' (expected YYYY-MM-DD, Nd, Nw, today, yesterday, \
+             this-week, last-week, this-month, last-month or this-year)",
+            s
+        ))
+    }
+}
+
+impl DateSpec {
+    /// Resolve to an inclusive (start, end) span relative to `today`, with weeks
+    /// starting on `week_start`. `--since` uses the start of the span, `--until` the end.
+    pub fn span(&self, today: NaiveDate, week_start: Weekday) -> (NaiveDate, NaiveDate) {
+        match *self {
+            Self::Date(d) => (d, d),
+            Self::Today => (today, today),
+            Self::Yesterday => {
+                let d = today - Duration::days(1);
+                (d, d)
+            }
+            Self::Days(n) => (today - Duration::days(i64::from(n) - 1), today),
+            Self::Weeks(n) => (today - Duration::days(7 * i64::from(n) - 1), today),
+            Self::ThisWeek => {
+                let start = start_of_week(today, week_start);
+                (start, start + Duration::days(6))
+            }
+            Self::LastWeek => {
+                let start = start_of_week(today, week_start) - Duration::days(7);
+                (start, start + Duration::days(6))
+            }
+            Self::ThisMonth => month_span(today.year(), today.month()),
+            Self::LastMonth => {
+                if today.month() == 1 {
+                    month_span(today.year() - 1, 12)
+                } else {
+                    month_span(today.year(), today.month() - 1)
+                }
+            }
+            Self::ThisYear => (
+                NaiveDate::from_ymd_opt(today.year(), 1, 1).unwrap_or(today),
+                NaiveDate::from_ymd_opt(today.year(), 12, 31).unwrap_or(today),
+            ),
+        }
+    }
+}
+
+/// `week_start` weekday on or before `date`
+fn start_of_week(date: NaiveDate, week_start: Weekday) -> NaiveDate {
+    date - Duration::days(i64::from(date.weekday().days_since(week_start)))
+}
+
+/// First and last day of the given month
+fn month_span(year: i32, month: u32) -> (NaiveDate, NaiveDate) {
+    let start = NaiveDate::from_ymd_opt(year, month, 1).unwrap_or_default();
+    let next = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)
+    };
+    let end = next.map(|d| d - Duration::days(1)).unwrap_or(start);
+    (start, end)
+}
+
+/// Inclusive date range; `None` bounds are open
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DateRange {
+    pub since: Option<NaiveDate>,
+    pub until: Option<NaiveDate>,
+}
+
+impl DateRange {
+    /// Build a range from optional `--since` / `--until` specs, resolved against `today`
+    pub fn from_specs(
+        since: Option<DateSpec>,
+        until: Option<DateSpec>,
+        today: NaiveDate,
+        week_start: Weekday,
+    ) -> Result<Self> {
+        let range = Self {
+            since: since.map(|s| s.span(today, week_start).0),
+            until: until.map(|u| u.span(today, week_start).1),
+        };
+        if let (Some(s), Some(u)) = (range.since, range.until) {
+            if s > u {
+                return Err(ToktrackError::Parse(format!(
+                    "--since ({}) is after --until ({})",
+                    s, u
+                )));
+            }
+        }
+        Ok(range)
+    }
+
+    /// Same as `from_specs`, resolved against the local current date
+    pub fn from_specs_local(
+        since: Option<DateSpec>,
+        until: Option<DateSpec>,
+        week_start: Weekday,
+    ) -> Result<Self> {
+        Self::from_specs(since, until, Local::now().date_naive(), week_start)
+    }
+
+    /// Whether both bounds are open (no filtering)
+    pub fn is_unbounded(&self) -> bool {
+        self.since.is_none() && self.until.is_none()
+    }
+
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.since.is_none_or(|s| date >= s) && self.until.is_none_or(|u| date <= u)
+    }
+
+    /// Keep only summaries whose date falls within the range
+    pub fn filter_summaries(&self, summaries: Vec<DailySummary>) -> Vec<DailySummary> {
+        if self.is_unbounded() {
+            return summaries;
+        }
+        summaries
+            .into_iter()
+            .filter(|s| self.contains(s.date))
+            .collect()
+    }
+
+    /// Keep only entries whose local date falls within the range
+    pub fn filter_entries(&self, entries: Vec<UsageEntry>) -> Vec<UsageEntry> {
+        if self.is_unbounded() {
+            return entries;
+        }
+        entries
+            .into_iter()
+            .filter(|e| self.contains(e.local_date()))
+            .collect()
+    }
+}
+
+/// Combined report filter: date range plus optional source and model allow-lists
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UsageFilter {
+    pub date_range: DateRange,
+    /// Allowed source CLI names (`None` = all)
+    pub sources: Option<Vec<String>>,
+    /// Allowed model glob patterns, matched case-insensitively against
+    /// normalized model names (`None` = all)
+    pub models: Option<Vec<String>>,
+    /// Allowed provider names, matched case-insensitively (`None` = all)
+    pub providers: Option<Vec<String>>,
+}
+
+impl UsageFilter {
+    pub fn matches_source(&self, source: &str) -> bool {
+        let source = canonical_source(source);
+        self.sources
+            .as_ref()
+            .is_none_or(|allowed| allowed.iter().any(|s| canonical_source(s) == source))
+    }
+
+    /// Match a normalized model name against the model patterns
+    pub fn matches_model(&self, model: &str) -> bool {
+        let options = MatchOptions {
+            case_sensitive: false,
+            ..MatchOptions::new()
+        };
+        self.models.as_ref().is_none_or(|patterns| {
+            patterns.iter().any(|p| match Pattern::new(p) {
+                Ok(pattern) => pattern.matches_with(model, options),
+                // Not a valid glob: fall back to a literal comparison
+                Err(_) => p.eq_ignore_ascii_case(model),
+            })
+        })
+    }
+
+    pub fn matches_provider(&self, provider: &str) -> bool {
+        self.providers.as_ref().is_none_or(|allowed| {
+            allowed
+                .iter()
+                .any(|p| p.trim().eq_ignore_ascii_case(provider))
+        })
+    }
+
+    /// Keep only the models and providers this filter allows, recomputing totals.
+    /// Returns `None` when nothing is left. The per-project breakdown is dropped
+    /// under a model or provider filter since it is not tracked per model, and
+    /// the per-source one under a provider filter.
+    pub fn restrict_summary(&self, summary: &DailySummary) -> Option<DailySummary> {
+        if self.models.is_none() && self.providers.is_none() {
+            return Some(summary.clone());
+        }
+        let providers: HashMap<String, HashMap<String, ModelUsage>> = summary
+            .providers
+            .iter()
+            .filter(|(provider, _)| self.matches_provider(provider))
+            .map(|(provider, models)| (provider.clone(), self.restrict_models(models)))
+            .filter(|(_, models)| !models.is_empty())
+            .collect();
+        let models = if self.providers.is_some() {
+            // Only the provider breakdown knows which provider served each model
+            let mut models: HashMap<String, ModelUsage> = HashMap::new();
+            for (name, usage) in providers.values().flatten() {
+                merge_model_usage(models.entry(name.clone()).or_default(), usage);
+            }
+            models
+        } else {
+            self.restrict_models(&summary.models)
+        };
+        if models.is_empty() {
+            return None;
+        }
+        let totals = sum_models(&models);
+        Some(DailySummary {
+            date: summary.date,
+            total_input_tokens: totals.input_tokens,
+            total_output_tokens: totals.output_tokens,
+            total_cache_read_tokens: totals.cache_read_tokens,
+            total_cache_creation_tokens: totals.cache_creation_tokens,
+            total_thinking_tokens: totals.thinking_tokens,
+            total_cost_usd: totals.cost_usd,
+            total_token_costs: totals.token_costs,
+            models,
+            projects: HashMap::new(),
+            providers,
+            sources: if self.providers.is_some() {
+                HashMap::new()
+            } else {
+                summary
+                    .sources
+                    .iter()
+                    .map(|(source, models)| (source.clone(), self.restrict_models(models)))
+                    .filter(|(_, models)| !models.is_empty())
+                    .collect()
+            },
+        })
+    }
+
+    /// Session counterpart of `restrict_summary`, also applying the source filter.
+    /// Sessions aren't broken down by provider; `filter_entries` applies that part.
+    pub fn restrict_session(&self, session: &SessionSummary) -> Option<SessionSummary> {
+        if !self.matches_source(&session.source) {
+            return None;
+        }
+        if self.models.is_none() {
+            return Some(session.clone());
+        }
+        let models = self.restrict_models(&session.models);
+        if models.is_empty() {
+            return None;
+        }
+        let totals = sum_models(&models);
+        Some(SessionSummary {
+            session_id: session.session_id.clone(),
+            source: session.source.clone(),
+            project: session.project.clone(),
+            start: session.start,
+            end: session.end,
+            duration_secs: session.duration_secs,
+            total_input_tokens: totals.input_tokens,
+            total_output_tokens: totals.output_tokens,
+            total_cache_read_tokens: totals.cache_read_tokens,
+            total_cache_creation_tokens: totals.cache_creation_tokens,
+            total_thinking_tokens: totals.thinking_tokens,
+            total_cost_usd: totals.cost_usd,
+            models,
+        })
+    }
+
+    /// Keep only entries within the date range, from allowed sources, models and providers
+    pub fn filter_entries(&self, entries: Vec<UsageEntry>) -> Vec<UsageEntry> {
+        let entries = self.date_range.filter_entries(entries);
+        if self.sources.is_none() && self.models.is_none() && self.providers.is_none() {
+            return entries;
+        }
+        entries
+            .into_iter()
+            .filter(|e| self.matches_source(e.source.as_deref().unwrap_or("")))
+            .filter(|e| self.matches_provider(&provider_of(e)))
+            .filter(|e| {
+                self.matches_model(&normalize_model_name(
+                    e.model.as_deref().unwrap_or("unknown"),
+                ))
+            })
+            .collect()
+    }
+
+    fn restrict_models(&self, models: &HashMap<String, ModelUsage>) -> HashMap<String, ModelUsage> {
+        models
+            .iter()
+            .filter(|(name, _)| self.matches_model(name))
+            .map(|(name, usage)| (name.clone(), usage.clone()))
+            .collect()
+    }
+}
+
+/// Canonical source name: lowercase, with `claude` accepted for the
+/// `claude-code` parser (entries carry the short name, the parser the long one)
+pub fn canonical_source(name: &str) -> String {
+    let name = name.to_ascii_lowercase();
+    if name == "claude" {
+        "claude-code".to_string()
+    } else {
+        name
+    }
+}
+
+/// Sum per-model usage into a single total
+fn sum_models(models: &HashMap<String, ModelUsage>) -> ModelUsage {
+    let mut total = ModelUsage::default();
+    for usage in models.values() {
+        merge_model_usage(&mut total, usage);
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TokenCosts;
+    use std::collections::{BTreeMap, HashMap};
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    // 2026-09-16 is a Wednesday
+    fn today() -> NaiveDate {
+        date(2026, 9, 16)
+    }
+
+    fn span(s: &str) -> (NaiveDate, NaiveDate) {
+        s.parse::<DateSpec>().unwrap().span(today(), Weekday::Sun)
+    }
+
+    #[test]
+    fn test_parse_absolute_date() {
+        assert_eq!(
+            "2026-09-01".parse::<DateSpec>(),
+            Ok(DateSpec::Date(date(2026, 9, 1)))
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!("2026-13-01".parse::<DateSpec>().is_err());
+        assert!("0d".parse::<DateSpec>().is_err());
+        assert!("soon".parse::<DateSpec>().is_err());
+    }
+
+    #[test]
+    fn test_relative_days_and_weeks_include_today() {
+        assert_eq!(span("7d"), (date(2026, 9, 10), today()));
+        assert_eq!(span("1d"), (today(), today()));
+        assert_eq!(span("2w"), (date(2026, 9, 3), today()));
+    }
+
+    #[test]
+    fn test_today_and_yesterday() {
+        assert_eq!(span("today"), (today(), today()));
+        assert_eq!(span("Yesterday"), (date(2026, 9, 15), date(2026, 9, 15)));
+    }
+
+    #[test]
+    fn test_weeks_start_on_sunday() {
+        assert_eq!(span("this-week"), (date(2026, 9, 13), date(2026, 9, 19)));
+        assert_eq!(span("last-week"), (date(2026, 9, 6), date(2026, 9, 12)));
+    }
+
+    #[test]
+    fn test_weeks_start_on_configured_day() {
+        let span_mon = |s: &str| s.parse::<DateSpec>().unwrap().span(today(), Weekday::Mon);
+        assert_eq!(
+            span_mon("this-week"),
+            (date(2026, 9, 14), date(2026, 9, 20))
+        );
+        assert_eq!(span_mon("last-week"), (date(2026, 9, 7), date(2026, 9, 13)));
+    }
+
+    #[test]
+    fn test_month_and_year_spans() {
+        assert_eq!(span("this-month"), (date(2026, 9, 1), date(2026, 9, 30)));
+        assert_eq!(span("last-month"), (date(2026, 8, 1), date(2026, 8, 31)));
+        assert_eq!(span("this-year"), (date(2026, 1, 1), date(2026, 12, 31)));
+
+        let jan = date(2026, 1, 10);
+        assert_eq!(
+            DateSpec::LastMonth.span(jan, Weekday::Sun),
+            (date(2025, 12, 1), date(2025, 12, 31))
+        );
+    }
+
+    #[test]
+    fn test_range_uses_span_start_and_end() {
+        let range = DateRange::from_specs(
+            Some(DateSpec::LastWeek),
+            Some(DateSpec::LastWeek),
+            today(),
+            Weekday::Sun,
+        )
+        .unwrap();
+        assert_eq!(range.since, Some(date(2026, 9, 6)));
+        assert_eq!(range.until, Some(date(2026, 9, 12)));
+    }
+
+    #[test]
+    fn test_range_rejects_inverted_bounds() {
+        let result = DateRange::from_specs(
+            Some(DateSpec::Date(date(2026, 9, 10))),
+            Some(DateSpec::Date(date(2026, 9, 1))),
+            today(),
+            Weekday::Sun,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_filter_summaries() {
+        let make = |d: NaiveDate| DailySummary {
+            date: d,
+            total_input_tokens: 1,
+            total_output_tokens: 0,
+            total_cache_read_tokens: 0,
+            total_cache_creation_tokens: 0,
+            total_thinking_tokens: 0,
+            total_cost_usd: 0.0,
+            total_token_costs: TokenCosts::default(),
+            models: HashMap::new(),
+            projects: HashMap::new(),
+            providers: HashMap::new(),
+            sources: HashMap::new(),
+        };
+        let summaries = vec![
+            make(date(2026, 8, 31)),
+            make(date(2026, 9, 1)),
+            make(date(2026, 9, 16)),
+        ];
+        let range = DateRange {
+            since: Some(date(2026, 9, 1)),
+            until: None,
+        };
+
+        let filtered = range.filter_summaries(summaries.clone());
+        assert_eq!(filtered.len(), 2);
+        assert_eq!(filtered[0].date, date(2026, 9, 1));
+
+        assert_eq!(DateRange::default().filter_summaries(summaries).len(), 3);
+    }
+
+    fn usage(input: u64, cost: f64) -> ModelUsage {
+        ModelUsage {
+            input_tokens: input,
+            output_tokens: 0,
+            cache_read_tokens: 0,
+            cache_creation_tokens: 0,
+            cache_creation_1h_tokens: 0,
+            thinking_tokens: 0,
+            cost_usd: cost,
+            count: 1,
+            web_search_requests: 0,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            unpriced_tokens: 0,
+            token_costs: TokenCosts::default(),
+            cost_reported: false,
+            max_prompt_tokens: None,
+            tier_tokens: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_matches_source_case_insensitive() {
+        let filter = UsageFilter {
+            sources: Some(vec!["Claude".into(), "codex".into()]),
+            ..UsageFilter::default()
+        };
+        assert!(filter.matches_source("claude"));
+        assert!(filter.matches_source("claude-code"));
+        assert!(filter.matches_source("codex"));
+        assert!(!filter.matches_source("gemini"));
+        assert!(UsageFilter::default().matches_source("gemini"));
+    }
+
+    #[test]
+    fn test_matches_model_glob() {
+        let filter = UsageFilter {
+            models: Some(vec!["claude-opus*".into()]),
+            ..UsageFilter::default()
+        };
+        assert!(filter.matches_model("claude-opus-4-5"));
+        assert!(filter.matches_model("Claude-Opus-4"));
+        assert!(!filter.matches_model("claude-sonnet-4"));
+
+        let empty = UsageFilter {
+            models: Some(vec![]),
+            ..UsageFilter::default()
+        };
+        assert!(!empty.matches_model("claude-opus-4-5"));
+    }
+
+    #[test]
+    fn test_restrict_summary_recomputes_totals() {
+        let mut models = HashMap::new();
+        models.insert("claude-opus-4".to_string(), usage(100, 1.0));
+        models.insert("claude-sonnet-4".to_string(), usage(50, 0.25));
+        let mut projects = HashMap::new();
+        projects.insert("/work/app".to_string(), usage(150, 1.25));
+        let summary = DailySummary {
+            date: date(2026, 9, 1),
+            total_input_tokens: 150,
+            total_output_tokens: 0,
+            total_cache_read_tokens: 0,
+            total_cache_creation_tokens: 0,
+            total_thinking_tokens: 0,
+            total_cost_usd: 1.25,
+            total_token_costs: TokenCosts::default(),
+            models,
+            projects,
+            providers: HashMap::new(),
+            sources: HashMap::new(),
+        };
+
+        let filter = UsageFilter {
+            models: Some(vec!["*sonnet*".into()]),
+            ..UsageFilter::default()
+        };
+        let restricted = filter.restrict_summary(&summary).unwrap();
+        assert_eq!(restricted.total_input_tokens, 50);
+        assert!((restricted.total_cost_usd - 0.25).abs() < f64::EPSILON);
+        assert_eq!(restricted.models.len(), 1);
+        assert!(restricted.projects.is_empty());
+
+        let none = UsageFilter {
+            models: Some(vec!["gpt-*".into()]),
+            ..UsageFilter::default()
+        };
+        assert!(none.restrict_summary(&summary).is_none());
+
+        // No model filter: unchanged, projects kept
+        assert_eq!(
+            UsageFilter::default().restrict_summary(&summary),
+            Some(summary)
+        );
+    }
+
+    #[test]
+    fn test_restrict_summary_by_provider() {
+        let mut models = HashMap::new();
+        models.insert("claude-sonnet-4".to_string(), usage(150, 0.5));
+        models.insert("gpt-5".to_string(), usage(20, 0.2));
+        let providers = HashMap::from([
+            (
+                "anthropic".to_string(),
+                HashMap::from([("claude-sonnet-4".to_string(), usage(100, 0.5))]),
+            ),
+            (
+                "github-copilot".to_string(),
+                HashMap::from([
+                    ("claude-sonnet-4".to_string(), usage(50, 0.0)),
+                    ("gpt-5".to_string(), usage(20, 0.2)),
+                ]),
+            ),
+        ]);
+        let summary = DailySummary {
+            date: date(2026, 9, 1),
+            total_input_tokens: 170,
+            total_output_tokens: 0,
+            total_cache_read_tokens: 0,
+            total_cache_creation_tokens: 0,
+            total_thinking_tokens: 0,
+            total_cost_usd: 0.7,
+            total_token_costs: TokenCosts::default(),
+            models,
+            projects: HashMap::new(),
+            providers,
+            sources: HashMap::new(),
+        };
+
+        let copilot = UsageFilter {
+            providers: Some(vec!["GitHub-Copilot".into()]),
+            ..UsageFilter::default()
+        };
+        assert!(copilot.matches_provider("github-copilot"));
+        assert!(!copilot.matches_provider("anthropic"));
+        let restricted = copilot.restrict_summary(&summary).unwrap();
+        assert_eq!(restricted.total_input_tokens, 70);
+        assert_eq!(restricted.models["claude-sonnet-4"].input_tokens, 50);
+        assert_eq!(restricted.providers.len(), 1);
+
+        // Provider and model filters combine
+        let copilot_claude = UsageFilter {
+            models: Some(vec!["claude-*".into()]),
+            ..copilot
+        };
+        let restricted = copilot_claude.restrict_summary(&summary).unwrap();
+        assert_eq!(restricted.total_input_tokens, 50);
+        assert_eq!(restricted.models.len(), 1);
+        assert_eq!(restricted.providers["github-copilot"].len(), 1);
+
+        let openai = UsageFilter {
+            providers: Some(vec!["openai".into()]),
+            ..UsageFilter::default()
+        };
+        assert!(openai.restrict_summary(&summary).is_none());
+    }
+}
@@ -0,0 +1,234 @@
+//! Cost forecasts for the current week and month
+//!
+//! Two projections are made from the daily cost series:
+//! - linear: spend so far extrapolated at the period's average daily rate
+//! - trend: spend so far plus the remaining days at a weighted average of the
+//!   last `TREND_DAYS` completed days, with recent days weighted most
+//!
+//! The confidence band widens both projections by the day-to-day variation
+//! over the trend window, scaled by the square root of the remaining days.
+
+use std::collections::HashMap;
+
+use chrono::{Duration, NaiveDate, Weekday};
+
+use crate::services::{DateRange, DateSpec};
+use crate::types::{DailySummary, Forecast, StatsData};
+
+/// Completed days used for the weighted recent-trend rate
+const TREND_DAYS: i64 = 7;
+
+/// z-score of the confidence band (~80% two-sided)
+const BAND_Z: f64 = 1.28;
+
+/// Project spend for the inclusive period `start..=end` as of `today`
+pub fn project(
+    summaries: &[DailySummary],
+    start: NaiveDate,
+    end: NaiveDate,
+    today: NaiveDate,
+) -> Forecast {
+    let today = today.clamp(start, end);
+    let costs: HashMap<NaiveDate, f64> = summaries
+        .iter()
+        .map(|s| (s.date, s.total_cost_usd))
+        .collect();
+    let cost_on = |date: NaiveDate| costs.get(&date).copied().unwrap_or(0.0);
+
+    let elapsed_days = (today - start).num_days() + 1;
+    let total_days = (end - start).num_days() + 1;
+    let remaining = (total_days - elapsed_days) as f64;
+
+    let spent_usd = start
+        .iter_days()
+        .take(elapsed_days as usize)
+        .fold(0.0, |total, date| total + cost_on(date));
+    let linear_usd = spent_usd + spent_usd / elapsed_days as f64 * remaining;
+
+    // Completed days before today, oldest first; weights 1..=TREND_DAYS
+    let window: Vec<f64> = (1..=TREND_DAYS)
+        .rev()
+        .map(|back| cost_on(today - Duration::days(back)))
+        .collect();
+    let weight_sum: f64 = (1..=window.len()).map(|w| w as f64).sum();
+    let trend_rate = window
+        .iter()
+        .enumerate()
+        .map(|(i, cost)| (i + 1) as f64 * cost)
+        .sum::<f64>()
+        / weight_sum;
+    let trend_usd = spent_usd + trend_rate * remaining;
+
+    let band = BAND_Z * std_dev(&window) * remaining.sqrt();
+    let low_usd = (linear_usd.min(trend_usd) - band).max(spent_usd);
+    let high_usd = linear_usd.max(trend_usd) + band;
+
+    Forecast {
+        start,
+        end,
+        elapsed_days: elapsed_days as u32,
+        total_days: total_days as u32,
+        spent_usd,
+        linear_usd,
+        trend_usd,
+        low_usd,
+        high_usd,
+    }
+}
+
+/// Forecast for the week or month containing `today`, or `None` when `range`
+/// doesn't cover the period from its start through today
+pub fn current(
+    summaries: &[DailySummary],
+    period: DateSpec,
+    range: &DateRange,
+    today: NaiveDate,
+    week_start: Weekday,
+) -> Option<Forecast> {
+    let (start, end) = period.span(today, week_start);
+    (range.contains(start) && range.contains(today)).then(|| project(summaries, start, end, today))
+}
+
+/// Add week and month forecasts for the periods containing `today` to `stats`
+pub fn with_current(
+    stats: StatsData,
+    summaries: &[DailySummary],
+    range: &DateRange,
+    today: NaiveDate,
+    week_start: Weekday,
+) -> StatsData {
+    let forecast = |period| current(summaries, period, range, today, week_start);
+    stats.with_forecasts(forecast(DateSpec::ThisWeek), forecast(DateSpec::ThisMonth))
+}
+
+/// Sample standard deviation (0 for fewer than two values)
+fn std_dev(values: &[f64]) -> f64 {
+    if values.len() < 2 {
+        return 0.0;
+    }
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    variance.sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TokenCosts;
+
+    fn date(m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, m, d).unwrap()
+    }
+
+    fn summary(date: NaiveDate, cost: f64) -> DailySummary {
+        DailySummary {
+            date,
+            total_input_tokens: 0,
+            total_output_tokens: 0,
+            total_cache_read_tokens: 0,
+            total_cache_creation_tokens: 0,
+            total_thinking_tokens: 0,
+            total_cost_usd: cost,
+            total_token_costs: TokenCosts::default(),
+            models: HashMap::new(),
+            projects: HashMap::new(),
+            providers: HashMap::new(),
+            sources: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_flat_spend_projects_exactly() {
+        // $10/day every day of September up to the 10th
+        let summaries: Vec<_> = (1..=10).map(|d| summary(date(9, d), 10.0)).collect();
+        let f = project(&summaries, date(9, 1), date(9, 30), date(9, 10));
+
+        assert_eq!((f.elapsed_days, f.total_days), (10, 30));
+        assert_eq!(f.spent_usd, 100.0);
+        assert_eq!(f.linear_usd, 300.0);
+        assert_eq!(f.trend_usd, 300.0);
+
+        // Early in the month the trend window reaches back into August (no data)
+        let f = project(&summaries, date(9, 1), date(9, 30), date(9, 5));
+        assert_eq!(f.linear_usd, 300.0);
+        assert!(f.trend_usd < f.linear_usd);
+    }
+
+    #[test]
+    fn test_trend_follows_recent_days() {
+        // Quiet start, heavy last week
+        let summaries: Vec<_> = (1..=20)
+            .map(|d| summary(date(9, d), if d > 12 { 50.0 } else { 5.0 }))
+            .collect();
+        let f = project(&summaries, date(9, 1), date(9, 30), date(9, 20));
+
+        assert_eq!(f.spent_usd, 12.0 * 5.0 + 8.0 * 50.0);
+        assert_eq!(f.trend_usd, f.spent_usd + 50.0 * 10.0);
+        assert!(f.trend_usd > f.linear_usd);
+        assert!(f.low_usd <= f.linear_usd);
+        assert!(f.high_usd >= f.trend_usd);
+    }
+
+    #[test]
+    fn test_band_is_zero_for_steady_spend_and_last_day() {
+        let summaries: Vec<_> = (1..=30).map(|d| summary(date(9, d), 4.0)).collect();
+        let f = project(&summaries, date(9, 1), date(9, 30), date(9, 20));
+        assert_eq!((f.low_usd, f.high_usd), (f.linear_usd, f.linear_usd));
+
+        let f = project(&summaries, date(9, 1), date(9, 30), date(9, 30));
+        assert_eq!(f.spent_usd, 120.0);
+        assert_eq!((f.linear_usd, f.trend_usd), (120.0, 120.0));
+        assert_eq!((f.low_usd, f.high_usd), (120.0, 120.0));
+    }
+
+    #[test]
+    fn test_low_never_below_spent() {
+        let summaries = vec![summary(date(9, 2), 100.0)];
+        let f = project(&summaries, date(9, 1), date(9, 30), date(9, 9));
+        assert!(f.low_usd >= f.spent_usd);
+    }
+
+    #[test]
+    fn test_current_requires_range_to_cover_period() {
+        let summaries = vec![summary(date(10, 14), 3.0)];
+        let today = date(10, 15);
+
+        let week = current(
+            &summaries,
+            DateSpec::ThisWeek,
+            &DateRange::default(),
+            today,
+            Weekday::Mon,
+        )
+        .unwrap();
+        assert_eq!((week.start, week.end), (date(10, 12), date(10, 18)));
+        assert_eq!(week.spent_usd, 3.0);
+
+        let last_month = DateRange {
+            since: None,
+            until: Some(date(9, 30)),
+        };
+        assert!(current(
+            &summaries,
+            DateSpec::ThisMonth,
+            &last_month,
+            today,
+            Weekday::Mon
+        )
+        .is_none());
+
+        let mid_month = DateRange {
+            since: Some(date(10, 10)),
+            until: None,
+        };
+        assert!(current(
+            &summaries,
+            DateSpec::ThisMonth,
+            &mid_month,
+            today,
+            Weekday::Mon
+        )
+        .is_none());
+    }
+}
@@ -1,14 +1,25 @@
 //! Services for data aggregation and processing
 
 pub mod aggregator;
+pub mod budget;
 pub mod cache;
+pub mod config;
 pub mod data_loader;
+pub mod filter;
+pub mod forecast;
 pub mod normalizer;
 pub mod pricing;
+pub mod pricing_history;
+pub mod pricing_overrides;
+pub mod provider;
+pub mod reprice;
 pub mod update_checker;
 
 pub use aggregator::Aggregator;
+pub use budget::BudgetStatus;
 pub use cache::DailySummaryCacheService;
+pub use config::Config;
 pub use data_loader::DataLoaderService;
+pub use filter::{canonical_source, DateRange, DateSpec, UsageFilter};
 pub use normalizer::{display_name, normalize_model_name};
 pub use pricing::PricingService;
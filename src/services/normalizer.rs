@@ -2,6 +2,28 @@
 //!
 //! Normalizes model names to a canonical form for consistent pricing lookup
 //! and aggregation across different data sources.
+//!
+//! Cloud providers and routers wrap the same model in their own IDs:
+//! `anthropic.claude-sonnet-4-20250514-v1:0` (Bedrock), `claude-sonnet-4@20250514`
+//! (Vertex AI) and `anthropic/claude-sonnet-4` (OpenRouter) all aggregate as
+//! `claude-sonnet-4`. Pricing still tries the provider's own LiteLLM entry first.
+
+/// Bedrock model vendors, as in `anthropic.claude-sonnet-4-20250514-v1:0`
+const BEDROCK_VENDORS: &[&str] = &[
+    "anthropic",
+    "amazon",
+    "meta",
+    "mistral",
+    "cohere",
+    "ai21",
+    "deepseek",
+    "openai",
+    "qwen",
+    "writer",
+];
+
+/// Bedrock cross-region inference profiles, as in `us.anthropic.claude-…`
+const BEDROCK_REGIONS: &[&str] = &["us", "us-gov", "eu", "apac", "jp", "au", "ca", "global"];
 
 /// Convert normalized model name to human-readable display name.
 /// Uses dynamic pattern parsing for automatic support of new models.
@@ -172,6 +194,9 @@ fn format_version(version: &str) -> String {
 /// Normalize a model name to canonical form.
 ///
 /// Transformations:
+/// - Remove provider wrappers: "anthropic/claude-sonnet-4" → "claude-sonnet-4",
+///   "claude-sonnet-4@20250514" → "claude-sonnet-4",
+///   "us.anthropic.claude-sonnet-4-20250514-v1:0" → "claude-sonnet-4"
 /// - Dots to hyphens: "claude-opus-4.5" → "claude-opus-4-5"
 /// - Remove date suffix: "claude-opus-4-5-20251101" → "claude-opus-4-5"
 ///
@@ -181,12 +206,16 @@ fn format_version(version: &str) -> String {
 ///
 /// assert_eq!(normalize_model_name("claude-opus-4-5-20251101"), "claude-opus-4-5");
 /// assert_eq!(normalize_model_name("claude-opus-4.5"), "claude-opus-4-5");
+/// assert_eq!(normalize_model_name("anthropic.claude-opus-4-5-20251101-v1:0"), "claude-opus-4-5");
 /// ```
 pub fn normalize_model_name(model: &str) -> String {
-    // Step 1: Replace dots with hyphens
+    // Step 1: Remove router prefixes, Vertex versions and Bedrock wrapping
+    let model = strip_provider_wrappers(model);
+
+    // Step 2: Replace dots with hyphens
     let normalized = model.replace('.', "-");
 
-    // Step 2: Remove 8-digit date suffix at end (e.g., -20251101)
+    // Step 3: Remove 8-digit date suffix at end (e.g., -20251101)
     // Pattern: ends with -YYYYMMDD where YYYYMMDD is 8 digits starting with 20
     if let Some(suffix_start) = normalized.rfind('-') {
         let suffix = &normalized[suffix_start + 1..];
@@ -201,6 +230,81 @@ pub fn normalize_model_name(model: &str) -> String {
     normalized
 }
 
+/// LiteLLM keys to look up a model's price under, most specific first:
+/// the provider's own entry (`openrouter/…`, `bedrock/…`, `vertex_ai/…`),
+/// the ID as logged, its Vertex version as a date suffix, then the canonical name.
+///
+/// `provider` is the provider ID recorded by the CLI (e.g. OpenCode's `providerID`).
+pub fn pricing_keys(model: &str, provider: Option<&str>) -> Vec<String> {
+    let route = provider
+        .and_then(litellm_route)
+        .or_else(|| model.contains('@').then_some("vertex_ai"));
+
+    let mut keys = Vec::new();
+    if let Some(route) = route {
+        if !model.starts_with(&format!("{}/", route)) {
+            keys.push(format!("{}/{}", route, model));
+        }
+    }
+    keys.push(model.to_string());
+    if let Some((name, version)) = model.split_once('@') {
+        keys.push(format!("{}-{}", name, version));
+    }
+    let normalized = normalize_model_name(model);
+    if !keys.contains(&normalized) {
+        keys.push(normalized);
+    }
+    keys
+}
+
+/// LiteLLM key prefix for a CLI provider ID
+fn litellm_route(provider: &str) -> Option<&'static str> {
+    match provider {
+        "openrouter" => Some("openrouter"),
+        "amazon-bedrock" | "bedrock" => Some("bedrock"),
+        "vertex" | "vertex_ai" => Some("vertex_ai"),
+        p if p.starts_with("google-vertex") => Some("vertex_ai"),
+        _ => None,
+    }
+}
+
+/// Strip what providers add around a model name:
+/// - Router paths: "openrouter/anthropic/claude-sonnet-4" → "claude-sonnet-4"
+/// - Vertex versions: "claude-sonnet-4@20250514" → "claude-sonnet-4"
+/// - Bedrock IDs: "us.anthropic.claude-sonnet-4-20250514-v1:0" → "claude-sonnet-4-20250514"
+fn strip_provider_wrappers(model: &str) -> &str {
+    let model = model.rsplit('/').next().unwrap_or(model);
+    let model = model.split('@').next().unwrap_or(model);
+    strip_bedrock_id(model).unwrap_or(model)
+}
+
+/// Whether `model` is an AWS Bedrock model ID such as `us.anthropic.claude-…-v1:0`
+pub fn is_bedrock_id(model: &str) -> bool {
+    strip_bedrock_id(model.rsplit('/').next().unwrap_or(model)).is_some()
+}
+
+/// `[region.]vendor.name[-vN][:N]` → `name`, or `None` if `model` isn't a Bedrock ID
+fn strip_bedrock_id(model: &str) -> Option<&str> {
+    let rest = match model.split_once('.') {
+        Some((region, rest)) if BEDROCK_REGIONS.contains(&region) => rest,
+        _ => model,
+    };
+    let (vendor, name) = rest.split_once('.')?;
+    if !BEDROCK_VENDORS.contains(&vendor) {
+        return None;
+    }
+    let is_number = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+    let name = match name.rsplit_once(':') {
+        Some((base, n)) if is_number(n) => base,
+        _ => name,
+    };
+    let name = match name.rsplit_once("-v") {
+        Some((base, n)) if is_number(n) => base,
+        _ => name,
+    };
+    Some(name)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -389,4 +493,82 @@ mod tests {
         // Date must be at end
         assert_eq!(normalize_model_name("20251101-claude"), "20251101-claude");
     }
+
+    // ========== provider ID tests ==========
+
+    #[test]
+    fn test_normalize_bedrock_ids() {
+        assert_eq!(
+            normalize_model_name("anthropic.claude-sonnet-4-20250514-v1:0"),
+            "claude-sonnet-4"
+        );
+        assert_eq!(
+            normalize_model_name("us.anthropic.claude-opus-4-1-20250805-v1:0"),
+            "claude-opus-4-1"
+        );
+        assert_eq!(
+            normalize_model_name("bedrock/global.anthropic.claude-haiku-4-5-20251001-v1:0"),
+            "claude-haiku-4-5"
+        );
+        assert_eq!(normalize_model_name("amazon.nova-pro-v1:0"), "nova-pro");
+        // Not a Bedrock vendor: only dots are converted
+        assert_eq!(normalize_model_name("gpt-4.1"), "gpt-4-1");
+    }
+
+    #[test]
+    fn test_normalize_vertex_and_router_ids() {
+        assert_eq!(
+            normalize_model_name("claude-sonnet-4@20250514"),
+            "claude-sonnet-4"
+        );
+        assert_eq!(
+            normalize_model_name("vertex_ai/claude-opus-4-5@20251101"),
+            "claude-opus-4-5"
+        );
+        assert_eq!(
+            normalize_model_name("anthropic/claude-sonnet-4.5"),
+            "claude-sonnet-4-5"
+        );
+        assert_eq!(
+            normalize_model_name("openrouter/google/gemini-2.5-pro"),
+            "gemini-2-5-pro"
+        );
+        assert_eq!(display_name(&normalize_model_name("openai/gpt-5")), "GPT-5");
+    }
+
+    #[test]
+    fn test_pricing_keys_prefer_provider_entries() {
+        assert_eq!(
+            pricing_keys("anthropic/claude-sonnet-4", Some("openrouter")),
+            vec![
+                "openrouter/anthropic/claude-sonnet-4",
+                "anthropic/claude-sonnet-4",
+                "claude-sonnet-4",
+            ]
+        );
+        assert_eq!(
+            pricing_keys("claude-sonnet-4@20250514", None),
+            vec![
+                "vertex_ai/claude-sonnet-4@20250514",
+                "claude-sonnet-4@20250514",
+                "claude-sonnet-4-20250514",
+                "claude-sonnet-4",
+            ]
+        );
+        assert_eq!(
+            pricing_keys(
+                "anthropic.claude-sonnet-4-20250514-v1:0",
+                Some("amazon-bedrock")
+            ),
+            vec![
+                "bedrock/anthropic.claude-sonnet-4-20250514-v1:0",
+                "anthropic.claude-sonnet-4-20250514-v1:0",
+                "claude-sonnet-4",
+            ]
+        );
+        assert_eq!(
+            pricing_keys("claude-sonnet-4", Some("anthropic")),
+            vec!["claude-sonnet-4"]
+        );
+    }
 }
@@ -4,61 +4,353 @@
 //! Supports auto mode: uses pre-calculated cost_usd when available,
 //! falls back to token-based calculation otherwise.
 
-use crate::types::{Result, ToktrackError, UsageEntry};
+use crate::services::normalizer::pricing_keys;
+use crate::services::pricing_history::{PricingHistory, PRICING_HISTORY_FILE};
+use crate::services::pricing_overrides::PricingOverrides;
+use crate::services::Config;
+use crate::types::{
+    ModelUsage, PricingInfo, PricingSource, Result, TierTokens, TokenCosts, ToktrackError,
+    UsageEntry,
+};
+use chrono::{DateTime, Local, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// LiteLLM pricing URL
 const LITELLM_PRICING_URL: &str =
     "https://raw.githubusercontent.com/BerriAI/litellm/main/model_prices_and_context_window.json";
 
-/// Cache TTL in seconds (1 hour)
-const CACHE_TTL_SECS: i64 = 3600;
+/// Default cache TTL in seconds (1 hour); configurable via `cache.pricing_ttl_secs`
+pub const DEFAULT_CACHE_TTL_SECS: i64 = 3600;
+
+/// LiteLLM rates for the models the parsers know, embedded at build time
+const PRICING_SNAPSHOT: &str = include_str!("../../assets/pricing_snapshot.json");
 
 /// HTTP request timeout in seconds
 const REQUEST_TIMEOUT_SECS: u64 = 10;
 
+/// Web search price when the model publishes none ($10 per 1,000, Anthropic list price)
+const DEFAULT_WEB_SEARCH_COST_PER_REQUEST: f64 = 0.01;
+
+/// Built-in token-cost multipliers by service tier; configurable via `[service_tiers]`
+const DEFAULT_SERVICE_TIERS: &[(&str, f64)] = &[("batch", 0.5), ("flex", 0.5)];
+
+/// Token-cost multiplier per provider service tier (e.g. "batch", "priority").
+/// Unlisted tiers, including "standard", use list rates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceTierMultipliers(BTreeMap<String, f64>);
+
+impl Default for ServiceTierMultipliers {
+    fn default() -> Self {
+        Self(
+            DEFAULT_SERVICE_TIERS
+                .iter()
+                .map(|(tier, multiplier)| (tier.to_string(), *multiplier))
+                .collect(),
+        )
+    }
+}
+
+impl ServiceTierMultipliers {
+    pub fn get(&self, tier: Option<&str>) -> f64 {
+        tier.and_then(|t| self.0.get(&t.trim().to_ascii_lowercase()))
+            .copied()
+            .unwrap_or(1.0)
+    }
+
+    pub fn set(&mut self, tier: &str, multiplier: f64) {
+        self.0.insert(tier.to_ascii_lowercase(), multiplier);
+    }
+
+    /// Tiers with a multiplier, by name
+    pub fn iter(&self) -> impl Iterator<Item = (&str, f64)> {
+        self.0
+            .iter()
+            .map(|(tier, multiplier)| (tier.as_str(), *multiplier))
+    }
+}
+
+/// Cost of an entry split into line items
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Charges {
+    /// Tokens at list rates
+    pub tokens_usd: f64,
+    /// Server-side web search requests
+    pub web_search_usd: f64,
+    /// Service-tier adjustment to the token cost (negative for discounts)
+    pub service_tier_usd: f64,
+    /// Token cost by type, service-tier adjustment included
+    pub token_costs: TokenCosts,
+    /// Threshold of the long-context tier the tokens were billed at
+    pub long_context_tier: Option<u64>,
+}
+
+impl Charges {
+    pub fn total(&self) -> f64 {
+        self.tokens_usd + self.web_search_usd + self.service_tier_usd
+    }
+}
+
 /// Pricing information for a model
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(from = "LiteLlmPricing", into = "LiteLlmPricing")]
 pub struct ModelPricing {
-    #[serde(default)]
     pub input_cost_per_token: Option<f64>,
-    #[serde(default)]
     pub output_cost_per_token: Option<f64>,
-    #[serde(default)]
     pub cache_read_input_token_cost: Option<f64>,
-    #[serde(default)]
     pub cache_creation_input_token_cost: Option<f64>,
+    /// Rate for 1-hour cache writes; falls back to `cache_creation_input_token_cost`
+    pub cache_creation_input_token_cost_above_1hr: Option<f64>,
+    /// Rate for reasoning/thinking output; falls back to `output_cost_per_token`
+    pub output_cost_per_reasoning_token: Option<f64>,
+    /// Per web search request (LiteLLM `search_context_cost_per_query`)
+    pub web_search_cost_per_request: Option<f64>,
+    /// LiteLLM provider, e.g. "anthropic" or "vertex_ai-language-models"
+    pub provider: Option<String>,
+    /// Long-context tiers, ascending by threshold
+    pub tiers: Vec<PricingTier>,
 }
 
+/// Rates for requests whose prompt exceeds `threshold` tokens.
+/// Missing rates fall back to the model's base rates.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PricingTier {
+    pub threshold: u64,
+    pub input_cost_per_token: Option<f64>,
+    pub output_cost_per_token: Option<f64>,
+    pub cache_read_input_token_cost: Option<f64>,
+    pub cache_creation_input_token_cost: Option<f64>,
+    pub cache_creation_input_token_cost_above_1hr: Option<f64>,
+}
+
+impl ModelPricing {
+    /// Whether both bill tokens and web searches identically; the provider
+    /// label is ignored
+    pub fn same_rates(&self, other: &Self) -> bool {
+        let rates = |p: &Self| {
+            (
+                p.input_cost_per_token,
+                p.output_cost_per_token,
+                p.cache_read_input_token_cost,
+                p.cache_creation_input_token_cost,
+                p.cache_creation_input_token_cost_above_1hr,
+                p.output_cost_per_reasoning_token,
+                p.web_search_cost_per_request,
+            )
+        };
+        rates(self) == rates(other) && self.tiers == other.tiers
+    }
+
+    /// Highest tier whose threshold `prompt_tokens` exceeds
+    pub fn tier_for(&self, prompt_tokens: u64) -> Option<&PricingTier> {
+        self.tiers
+            .iter()
+            .rev()
+            .find(|t| prompt_tokens > t.threshold)
+    }
+}
+
+/// LiteLLM's model entry; tiered rates are published as flat keys such as
+/// `input_cost_per_token_above_200k_tokens`, and web search prices as
+/// `search_context_cost_per_query` keyed by context size
+#[derive(Serialize, Deserialize)]
+struct LiteLlmPricing {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    input_cost_per_token: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    output_cost_per_token: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cache_read_input_token_cost: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cache_creation_input_token_cost: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cache_creation_input_token_cost_above_1hr: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    output_cost_per_reasoning_token: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    litellm_provider: Option<String>,
+    #[serde(flatten)]
+    extra: BTreeMap<String, serde_json::Value>,
+}
+
+/// Base rates that LiteLLM publishes per context tier
+const TIERED_RATES: [&str; 5] = [
+    "input_cost_per_token",
+    "output_cost_per_token",
+    "cache_read_input_token_cost",
+    "cache_creation_input_token_cost",
+    "cache_creation_input_token_cost_above_1hr",
+];
+
+/// Parse a tier key into its base rate name and threshold,
+/// e.g. `output_cost_per_token_above_200k_tokens` → (`output_cost_per_token`, 200000)
+fn parse_tier_key(key: &str) -> Option<(&str, u64)> {
+    let (base, threshold) = key.strip_suffix("_tokens")?.rsplit_once("_above_")?;
+    let threshold = match threshold.strip_suffix('k') {
+        Some(thousands) => thousands.parse::<u64>().ok()?.checked_mul(1000)?,
+        None => threshold.parse().ok()?,
+    };
+    Some((base, threshold))
+}
+
+/// LiteLLM key holding per-query web search prices
+const SEARCH_COST_KEY: &str = "search_context_cost_per_query";
+
+/// Context size used for the web search price (Claude Code doesn't report one)
+const SEARCH_CONTEXT_SIZES: [&str; 3] = [
+    "search_context_size_medium",
+    "search_context_size_low",
+    "search_context_size_high",
+];
+
+impl From<LiteLlmPricing> for ModelPricing {
+    fn from(raw: LiteLlmPricing) -> Self {
+        let web_search_cost_per_request = raw.extra.get(SEARCH_COST_KEY).and_then(|costs| {
+            SEARCH_CONTEXT_SIZES
+                .iter()
+                .find_map(|size| costs.get(size).and_then(|v| v.as_f64()))
+        });
+
+        let mut tiers: BTreeMap<u64, PricingTier> = BTreeMap::new();
+        for (key, value) in &raw.extra {
+            let (Some((base, threshold)), Some(rate)) = (parse_tier_key(key), value.as_f64())
+            else {
+                continue;
+            };
+            // Skip other tiered units, e.g. `output_cost_per_character_above_128k_tokens`
+            if !TIERED_RATES.contains(&base) {
+                continue;
+            }
+            let tier = tiers.entry(threshold).or_insert_with(|| PricingTier {
+                threshold,
+                ..PricingTier::default()
+            });
+            let slot = match base {
+                "input_cost_per_token" => &mut tier.input_cost_per_token,
+                "output_cost_per_token" => &mut tier.output_cost_per_token,
+                "cache_read_input_token_cost" => &mut tier.cache_read_input_token_cost,
+                "cache_creation_input_token_cost" => &mut tier.cache_creation_input_token_cost,
+                _ => &mut tier.cache_creation_input_token_cost_above_1hr,
+            };
+            *slot = Some(rate);
+        }
+
+        Self {
+            input_cost_per_token: raw.input_cost_per_token,
+            output_cost_per_token: raw.output_cost_per_token,
+            cache_read_input_token_cost: raw.cache_read_input_token_cost,
+            cache_creation_input_token_cost: raw.cache_creation_input_token_cost,
+            cache_creation_input_token_cost_above_1hr: raw
+                .cache_creation_input_token_cost_above_1hr,
+            output_cost_per_reasoning_token: raw.output_cost_per_reasoning_token,
+            web_search_cost_per_request,
+            provider: raw.litellm_provider,
+            tiers: tiers.into_values().collect(),
+        }
+    }
+}
+
+impl From<ModelPricing> for LiteLlmPricing {
+    fn from(pricing: ModelPricing) -> Self {
+        let mut extra = BTreeMap::new();
+        if let Some(cost) = pricing.web_search_cost_per_request {
+            extra.insert(
+                SEARCH_COST_KEY.to_string(),
+                serde_json::json!({ SEARCH_CONTEXT_SIZES[0]: cost }),
+            );
+        }
+        for tier in &pricing.tiers {
+            let threshold = if tier.threshold % 1000 == 0 {
+                format!("{}k", tier.threshold / 1000)
+            } else {
+                tier.threshold.to_string()
+            };
+            let rates = [
+                ("input_cost_per_token", tier.input_cost_per_token),
+                ("output_cost_per_token", tier.output_cost_per_token),
+                (
+                    "cache_read_input_token_cost",
+                    tier.cache_read_input_token_cost,
+                ),
+                (
+                    "cache_creation_input_token_cost",
+                    tier.cache_creation_input_token_cost,
+                ),
+                (
+                    "cache_creation_input_token_cost_above_1hr",
+                    tier.cache_creation_input_token_cost_above_1hr,
+                ),
+            ];
+            for (base, rate) in rates {
+                if let Some(rate) = rate {
+                    let key = format!("{}_above_{}_tokens", base, threshold);
+                    extra.insert(key, rate.into());
+                }
+            }
+        }
+
+        Self {
+            input_cost_per_token: pricing.input_cost_per_token,
+            output_cost_per_token: pricing.output_cost_per_token,
+            cache_read_input_token_cost: pricing.cache_read_input_token_cost,
+            cache_creation_input_token_cost: pricing.cache_creation_input_token_cost,
+            cache_creation_input_token_cost_above_1hr: pricing
+                .cache_creation_input_token_cost_above_1hr,
+            output_cost_per_reasoning_token: pricing.output_cost_per_reasoning_token,
+            litellm_provider: pricing.provider,
+            extra,
+        }
+    }
+}
+
+/// Bump when `ModelPricing` starts keeping rates an older `pricing.json` lacks
+pub const PRICING_CACHE_SCHEMA: u32 = 1;
+
 /// Cached pricing data
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PricingCache {
     /// Unix timestamp when the cache was fetched
     pub fetched_at: i64,
+    /// `PRICING_CACHE_SCHEMA` the cache was written with; 0 before tiers,
+    /// 1-hour cache writes, reasoning, web search and provider were kept
+    #[serde(default)]
+    pub schema: u32,
     /// Model pricing data
     pub models: HashMap<String, ModelPricing>,
 }
 
 impl PricingCache {
-    /// Check if the cache has expired
+    /// Check if the cache has expired (default TTL)
+    #[allow(dead_code)]
     pub fn is_expired(&self) -> bool {
+        self.is_expired_after(DEFAULT_CACHE_TTL_SECS)
+    }
+
+    /// Check if the cache is older than `ttl_secs`
+    pub fn is_expired_after(&self, ttl_secs: i64) -> bool {
         let now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .map(|d| d.as_secs() as i64)
             .unwrap_or(0);
-        now - self.fetched_at > CACHE_TTL_SECS
+        now - self.fetched_at > ttl_secs
     }
 }
 
 /// Pricing service for calculating token costs
 pub struct PricingService {
     cache: PricingCache,
+    source: PricingSource,
     #[allow(dead_code)]
     cache_path: PathBuf,
+    /// Local overlay, re-applied after a refresh
+    overrides: PricingOverrides,
+    /// Superseded rates, for entries dated before a price change
+    history: PricingHistory,
+    service_tiers: ServiceTierMultipliers,
 }
 
 impl PricingService {
@@ -70,52 +362,88 @@ impl PricingService {
 
     /// Create a new PricingService with a custom cache path
     pub fn with_cache_path(cache_path: PathBuf) -> Result<Self> {
-        let cache = Self::load_or_fetch_cache(&cache_path)?;
-        Ok(Self { cache, cache_path })
+        let (cache, source) = Self::load_or_fetch_cache(&cache_path, DEFAULT_CACHE_TTL_SECS);
+        Ok(Self::from_parts(cache, source, cache_path))
     }
 
-    /// Create a PricingService, preferring cache but refreshing if expired or corrupt.
-    /// Returns None only if no cache exists AND network fetch fails.
-    pub fn from_cache_only() -> Option<Self> {
+    /// Create a PricingService, preferring cache but refreshing if older than `ttl_secs`
+    /// or corrupt. Falls back to the bundled snapshot when there is no usable cache and
+    /// the network is down; returns None only if the home directory is unknown.
+    pub fn from_cache_only(ttl_secs: i64) -> Option<Self> {
         let cache_path = Self::default_cache_path().ok()?;
+        let (cache, source) = Self::load_or_fetch_cache(&cache_path, ttl_secs);
+        Some(Self::from_parts(cache, source, cache_path))
+    }
 
-        match Self::load_cache(&cache_path) {
-            Ok(cache) if !cache.is_expired() => Some(Self { cache, cache_path }),
-            Ok(cache) => {
-                // Expired → try refresh, fallback to expired cache
-                if let Ok(fresh) = Self::fetch_pricing() {
-                    let _ = Self::save_cache(&cache_path, &fresh);
-                    Some(Self {
-                        cache: fresh,
-                        cache_path,
-                    })
-                } else {
-                    Some(Self { cache, cache_path })
-                }
-            }
-            Err(_) => {
-                // Corrupt or unreadable → try fresh fetch to recover
-                if let Ok(fresh) = Self::fetch_pricing() {
-                    let _ = Self::save_cache(&cache_path, &fresh);
-                    Some(Self {
-                        cache: fresh,
-                        cache_path,
-                    })
-                } else {
-                    None
-                }
-            }
-        }
+    /// Pricing with the TTL, local overrides and service-tier multipliers from the user config
+    pub fn from_config(config: &Config) -> Option<Self> {
+        let ttl_secs = i64::try_from(config.pricing_ttl_secs).unwrap_or(i64::MAX);
+        Self::from_cache_only(ttl_secs).map(|p| {
+            p.with_overrides(&config.pricing_overrides)
+                .with_service_tiers(&config.service_tiers)
+        })
     }
 
     /// Cache-only constructor with custom path (for testing)
     #[allow(dead_code)]
     pub fn from_cache_only_with_path(cache_path: &PathBuf) -> Option<Self> {
         let cache = Self::load_cache(cache_path).ok()?;
-        Some(Self {
+        Some(Self::from_parts(
             cache,
-            cache_path: cache_path.clone(),
-        })
+            PricingSource::Cache,
+            cache_path.clone(),
+        ))
+    }
+
+    fn from_parts(cache: PricingCache, source: PricingSource, cache_path: PathBuf) -> Self {
+        let history = PricingHistory::load(&Self::history_path(&cache_path));
+        Self {
+            cache,
+            source,
+            cache_path,
+            overrides: PricingOverrides::default(),
+            history,
+            service_tiers: ServiceTierMultipliers::default(),
+        }
+    }
+
+    /// Price history file, next to the cache
+    fn history_path(cache_path: &Path) -> PathBuf {
+        cache_path.with_file_name(PRICING_HISTORY_FILE)
+    }
+
+    /// Record rate changes between the previous cache and a fresh fetch
+    fn record_history(cache_path: &Path, previous: &PricingCache, fresh: &PricingCache) {
+        let path = Self::history_path(cache_path);
+        let mut history = PricingHistory::load(&path);
+        if history.record_changes(previous, fresh) {
+            let _ = history.save(&path);
+        }
+    }
+
+    /// Pricing source and the date the rates were fetched
+    pub fn info(&self) -> PricingInfo {
+        let as_of = DateTime::from_timestamp(self.cache.fetched_at, 0)
+            .unwrap_or_default()
+            .date_naive();
+        PricingInfo {
+            source: self.source,
+            as_of,
+        }
+    }
+
+    /// Merge a local pricing overlay on top of the LiteLLM table.
+    /// The overlay is kept in memory only; `pricing.json` stays untouched.
+    pub fn with_overrides(mut self, overrides: &PricingOverrides) -> Self {
+        overrides.apply(&mut self.cache.models);
+        self.overrides = overrides.clone();
+        self
+    }
+
+    /// Use these multipliers for service-tier adjustments instead of the built-in ones
+    pub fn with_service_tiers(mut self, service_tiers: &ServiceTierMultipliers) -> Self {
+        self.service_tiers = service_tiers.clone();
+        self
     }
 
     /// Get the default cache path (~/.toktrack/pricing.json)
@@ -127,27 +455,32 @@ impl PricingService {
         Ok(home.join(".toktrack").join("pricing.json"))
     }
 
-    /// Load cache from disk or fetch fresh data
-    fn load_or_fetch_cache(cache_path: &PathBuf) -> Result<PricingCache> {
-        // Try loading existing cache
-        if let Ok(cache) = Self::load_cache(cache_path) {
-            if !cache.is_expired() {
-                return Ok(cache);
-            }
-            // Cache expired, try to refresh
-            if let Ok(fresh_cache) = Self::fetch_pricing() {
-                let _ = Self::save_cache(cache_path, &fresh_cache);
-                return Ok(fresh_cache);
+    /// Load the disk cache, refreshing it once older than `ttl_secs`.
+    /// Without a usable cache or network, falls back to an expired cache,
+    /// then to the bundled snapshot (which is never written to disk).
+    fn load_or_fetch_cache(cache_path: &PathBuf, ttl_secs: i64) -> (PricingCache, PricingSource) {
+        match Self::load_cache(cache_path) {
+            Ok(cache) if !cache.is_expired_after(ttl_secs) => (cache, PricingSource::Cache),
+            cached => {
+                // Expired, missing or corrupt → try a fresh fetch
+                if let Ok(fresh) = Self::fetch_pricing() {
+                    if let Ok(previous) = &cached {
+                        Self::record_history(cache_path, previous, &fresh);
+                    }
+                    let _ = Self::save_cache(cache_path, &fresh);
+                    return (fresh, PricingSource::Live);
+                }
+                match cached {
+                    Ok(cache) => (cache, PricingSource::Cache),
+                    Err(_) => (Self::snapshot(), PricingSource::Snapshot),
+                }
             }
-            // Fetch failed, use expired cache
-            return Ok(cache);
         }
+    }
 
-        // No cache exists, must fetch
-        let cache = Self::fetch_pricing()
-            .map_err(|e| ToktrackError::Pricing(format!("Failed to fetch pricing data: {}", e)))?;
-        let _ = Self::save_cache(cache_path, &cache);
-        Ok(cache)
+    /// Pricing snapshot bundled at build time (see `scripts/update-pricing-snapshot.sh`)
+    fn snapshot() -> PricingCache {
+        serde_json::from_str(PRICING_SNAPSHOT).expect("bundled pricing snapshot is valid JSON")
     }
 
     /// Load cache from disk
@@ -190,7 +523,11 @@ impl PricingService {
             .map(|d| d.as_secs() as i64)
             .unwrap_or(0);
 
-        Ok(PricingCache { fetched_at, models })
+        Ok(PricingCache {
+            fetched_at,
+            schema: PRICING_CACHE_SCHEMA,
+            models,
+        })
     }
 
     /// Get cost, using pre-calculated cost_usd if available (auto mode)
@@ -202,44 +539,235 @@ impl PricingService {
         self.calculate_cost(entry)
     }
 
-    /// Calculate cost from tokens (always calculates, ignores cost_usd)
+    /// Calculate cost from tokens (always calculates, ignores cost_usd).
+    /// Unpriced models cost 0.
     pub fn calculate_cost(&self, entry: &UsageEntry) -> f64 {
-        let model = match &entry.model {
-            Some(m) => m,
-            None => return 0.0,
+        self.calculate_charges(entry)
+            .map_or(0.0, |charges| charges.total())
+    }
+
+    /// Calculate cost as line items: tokens, web searches and service-tier adjustment.
+    /// Returns `None` if no price is known for the entry's model.
+    pub fn calculate_charges(&self, entry: &UsageEntry) -> Option<Charges> {
+        let pricing = self.pricing_for(entry)?;
+
+        // The tier is decided per request by its whole prompt, cached or not
+        let tier = pricing.tier_for(entry.prompt_tokens());
+        let token_costs = Self::token_costs(&pricing, tier, entry);
+        let tokens_usd = token_costs.total();
+        let web_search_rate = pricing
+            .web_search_cost_per_request
+            .unwrap_or(DEFAULT_WEB_SEARCH_COST_PER_REQUEST);
+        let multiplier = self.service_tiers.get(entry.service_tier.as_deref());
+
+        Some(Charges {
+            tokens_usd,
+            web_search_usd: entry.web_search_requests as f64 * web_search_rate,
+            service_tier_usd: tokens_usd * (multiplier - 1.0),
+            token_costs: token_costs.scaled(multiplier),
+            long_context_tier: tier.map(|t| t.threshold),
+        })
+    }
+
+    /// Charges for one day's aggregated usage of `model` through `provider`,
+    /// or `None` if it is unpriced or its cost can't be reproduced: costs
+    /// reported by the source, and requests that may have crossed a threshold
+    /// which wasn't a tier when the day was priced (for days cached before
+    /// prompt sizes were recorded, any tier).
+    ///
+    /// Tokens recorded under a long-context tier are billed at that tier's
+    /// current rates and the rest at base rates. The recorded service-tier
+    /// adjustment is kept as the same share of the token cost.
+    pub fn calculate_usage_charges(
+        &self,
+        model: &str,
+        provider: Option<&str>,
+        date: NaiveDate,
+        usage: &ModelUsage,
+    ) -> Option<Charges> {
+        if usage.cost_reported {
+            return None;
+        }
+        let noon = date
+            .and_hms_opt(12, 0, 0)?
+            .and_local_timezone(Local)
+            .earliest()?;
+        let entry = |tokens: TierTokens| UsageEntry {
+            timestamp: noon.with_timezone(&Utc),
+            model: Some(model.to_string()),
+            input_tokens: tokens.input_tokens,
+            output_tokens: tokens.output_tokens,
+            cache_read_tokens: tokens.cache_read_tokens,
+            cache_creation_tokens: tokens.cache_creation_tokens,
+            cache_creation_1h_tokens: tokens.cache_creation_1h_tokens,
+            thinking_tokens: tokens.thinking_tokens,
+            cost_usd: None,
+            message_id: None,
+            request_id: None,
+            source: None,
+            provider: provider.map(String::from),
+            project: None,
+            session_id: None,
+            web_search_requests: 0,
+            service_tier: None,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            token_costs: TokenCosts::default(),
+            long_context_tier: None,
+            cost_reported: false,
         };
 
-        let pricing = match self.get_pricing(model) {
-            Some(p) => p,
-            None => return 0.0,
+        // Tokens not billed at any tier
+        let mut base = TierTokens {
+            input_tokens: usage.input_tokens,
+            output_tokens: usage.output_tokens,
+            cache_read_tokens: usage.cache_read_tokens,
+            cache_creation_tokens: usage.cache_creation_tokens,
+            cache_creation_1h_tokens: usage.cache_creation_1h_tokens,
+            thinking_tokens: usage.thinking_tokens,
         };
+        for tokens in usage.tier_tokens.values() {
+            base.input_tokens = base.input_tokens.saturating_sub(tokens.input_tokens);
+            base.output_tokens = base.output_tokens.saturating_sub(tokens.output_tokens);
+            base.cache_read_tokens = base
+                .cache_read_tokens
+                .saturating_sub(tokens.cache_read_tokens);
+            base.cache_creation_tokens = base
+                .cache_creation_tokens
+                .saturating_sub(tokens.cache_creation_tokens);
+            base.cache_creation_1h_tokens = base
+                .cache_creation_1h_tokens
+                .saturating_sub(tokens.cache_creation_1h_tokens);
+            base.thinking_tokens = base.thinking_tokens.saturating_sub(tokens.thinking_tokens);
+        }
 
-        // Non-cached input = input_tokens - cache_read_tokens
-        let non_cached_input = entry.input_tokens.saturating_sub(entry.cache_read_tokens);
+        let base_entry = entry(base);
+        let pricing = self.pricing_for(&base_entry)?;
+        let tier_at = |threshold: u64| pricing.tiers.iter().find(|t| t.threshold == threshold);
+        let tiers_reproducible = usage.tier_tokens.keys().all(|&t| tier_at(t).is_some())
+            && pricing.tiers.iter().all(|tier| {
+                usage.tier_tokens.contains_key(&tier.threshold)
+                    || usage
+                        .max_prompt_tokens
+                        .is_some_and(|max| max <= tier.threshold)
+            });
+        if !tiers_reproducible {
+            return None;
+        }
 
-        let input_cost = pricing.input_cost_per_token.unwrap_or(0.0);
-        let output_cost = pricing.output_cost_per_token.unwrap_or(0.0);
-        let cache_read_cost = pricing.cache_read_input_token_cost.unwrap_or(0.0);
-        let cache_creation_cost = pricing.cache_creation_input_token_cost.unwrap_or(0.0);
+        let mut token_costs = Self::token_costs(&pricing, None, &base_entry);
+        for (&threshold, &tokens) in &usage.tier_tokens {
+            token_costs += Self::token_costs(&pricing, tier_at(threshold), &entry(tokens));
+        }
+        let tokens_usd = token_costs.total();
+        let web_search_rate = pricing
+            .web_search_cost_per_request
+            .unwrap_or(DEFAULT_WEB_SEARCH_COST_PER_REQUEST);
+        let old_tokens_usd =
+            usage.cost_usd - usage.web_search_cost_usd - usage.service_tier_cost_usd;
+        let service_tier_usd = if old_tokens_usd > 0.0 {
+            tokens_usd * usage.service_tier_cost_usd / old_tokens_usd
+        } else {
+            0.0
+        };
+        let multiplier = if tokens_usd > 0.0 {
+            (tokens_usd + service_tier_usd) / tokens_usd
+        } else {
+            1.0
+        };
 
-        (non_cached_input as f64 * input_cost)
-            + (entry.cache_read_tokens as f64 * cache_read_cost)
-            + (entry.cache_creation_tokens as f64 * cache_creation_cost)
-            + (entry.output_tokens as f64 * output_cost)
+        Some(Charges {
+            tokens_usd,
+            web_search_usd: usage.web_search_requests as f64 * web_search_rate,
+            service_tier_usd,
+            token_costs: token_costs.scaled(multiplier),
+            long_context_tier: None,
+        })
     }
 
-    /// Get pricing for a model (tries exact match first, then normalized)
-    pub fn get_pricing(&self, model: &str) -> Option<&ModelPricing> {
-        // Try exact match first
-        if let Some(pricing) = self.cache.models.get(model) {
-            return Some(pricing);
+    /// Token cost by type at list rates, or at `tier`'s rates where it sets them
+    fn token_costs(
+        pricing: &ModelPricing,
+        tier: Option<&PricingTier>,
+        entry: &UsageEntry,
+    ) -> TokenCosts {
+        let rate = |base: Option<f64>, tiered: fn(&PricingTier) -> Option<f64>| {
+            tier.and_then(tiered).or(base).unwrap_or(0.0)
+        };
+
+        let input_cost = rate(pricing.input_cost_per_token, |t| t.input_cost_per_token);
+        let output_cost = rate(pricing.output_cost_per_token, |t| t.output_cost_per_token);
+        let cache_read_cost = rate(pricing.cache_read_input_token_cost, |t| {
+            t.cache_read_input_token_cost
+        });
+        let cache_creation_cost = rate(pricing.cache_creation_input_token_cost, |t| {
+            t.cache_creation_input_token_cost
+        });
+        let cache_creation_1h_cost = tier
+            .and_then(|t| t.cache_creation_input_token_cost_above_1hr)
+            .or(pricing.cache_creation_input_token_cost_above_1hr)
+            .unwrap_or(cache_creation_cost);
+        let reasoning_cost = pricing
+            .output_cost_per_reasoning_token
+            .unwrap_or(output_cost);
+
+        let cache_creation_1h = entry
+            .cache_creation_1h_tokens
+            .min(entry.cache_creation_tokens);
+        let cache_creation_5m = entry.cache_creation_tokens - cache_creation_1h;
+
+        // Token fields are disjoint (see UsageEntry), so each is billed as-is
+        TokenCosts {
+            input_usd: entry.input_tokens as f64 * input_cost,
+            output_usd: entry.output_tokens as f64 * output_cost,
+            cache_write_usd: (cache_creation_5m as f64 * cache_creation_cost)
+                + (cache_creation_1h as f64 * cache_creation_1h_cost),
+            cache_read_usd: entry.cache_read_tokens as f64 * cache_read_cost,
+            thinking_usd: entry.thinking_tokens as f64 * reasoning_cost,
         }
-        // Try normalized name
-        let normalized = super::normalize_model_name(model);
-        if normalized != model {
-            return self.cache.models.get(&normalized);
+    }
+
+    /// Rates for the entry's model in effect on the entry's local date
+    fn pricing_for(&self, entry: &UsageEntry) -> Option<Cow<'_, ModelPricing>> {
+        let model = entry.model.as_deref()?;
+        let provider = entry.provider.as_deref();
+        match self.superseded_rates(model, provider, entry.local_date()) {
+            Some(rates) => {
+                let mut rates = rates.clone();
+                self.overrides.adjust(model, &mut rates);
+                Some(Cow::Owned(rates))
+            }
+            None => self.get_pricing_for(model, provider).map(Cow::Borrowed),
         }
-        None
+    }
+
+    /// LiteLLM rates from before a price change, if `date` predates it
+    /// (history is keyed by LiteLLM names, so aliases use their target)
+    fn superseded_rates(
+        &self,
+        model: &str,
+        provider: Option<&str>,
+        date: NaiveDate,
+    ) -> Option<&ModelPricing> {
+        let key = self.pricing_key(self.overrides.base_model(model), provider)?;
+        self.history.superseded_rates(key, date)
+    }
+
+    /// Get pricing for a model served by `provider`, preferring the provider's
+    /// own LiteLLM entry (e.g. `bedrock/…`) over the canonical model's
+    pub fn get_pricing_for(&self, model: &str, provider: Option<&str>) -> Option<&ModelPricing> {
+        let key = self.pricing_key(model, provider)?;
+        self.cache.models.get(key)
+    }
+
+    /// First of the model's lookup keys that the price table has
+    fn pricing_key(&self, model: &str, provider: Option<&str>) -> Option<&str> {
+        pricing_keys(model, provider).into_iter().find_map(|key| {
+            self.cache
+                .models
+                .get_key_value(&key)
+                .map(|(k, _)| k.as_str())
+        })
     }
 
     /// Force refresh pricing data
@@ -247,8 +775,14 @@ impl PricingService {
     pub fn refresh(&mut self) -> Result<()> {
         let cache = Self::fetch_pricing()
             .map_err(|e| ToktrackError::Pricing(format!("Refresh failed: {}", e)))?;
+        if let Ok(previous) = Self::load_cache(&self.cache_path) {
+            Self::record_history(&self.cache_path, &previous, &cache);
+            self.history = PricingHistory::load(&Self::history_path(&self.cache_path));
+        }
         let _ = Self::save_cache(&self.cache_path, &cache);
         self.cache = cache;
+        self.source = PricingSource::Live;
+        self.overrides.apply(&mut self.cache.models);
         Ok(())
     }
 
@@ -262,6 +796,7 @@ impl PricingService {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parsers::{CLIParser, ClaudeCodeParser, CodexParser, GeminiParser, OpenCodeParser};
     use chrono::Utc;
     use std::time::{SystemTime, UNIX_EPOCH};
     use tempfile::TempDir;
@@ -281,12 +816,22 @@ mod tests {
             output_tokens: output,
             cache_read_tokens: cache_read,
             cache_creation_tokens: cache_creation,
+            cache_creation_1h_tokens: 0,
             thinking_tokens: 0,
             cost_usd,
             message_id: None,
             request_id: None,
             source: None,
             provider: None,
+            project: None,
+            session_id: None,
+            web_search_requests: 0,
+            service_tier: None,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            token_costs: TokenCosts::default(),
+            long_context_tier: None,
+            cost_reported: false,
         }
     }
 
@@ -303,6 +848,11 @@ mod tests {
                 output_cost_per_token: Some(0.000015),        // $15 per 1M tokens
                 cache_read_input_token_cost: Some(0.0000003), // $0.30 per 1M tokens
                 cache_creation_input_token_cost: Some(0.00000375), // $3.75 per 1M tokens
+                cache_creation_input_token_cost_above_1hr: None,
+                output_cost_per_reasoning_token: None,
+                web_search_cost_per_request: None,
+                provider: None,
+                tiers: vec![],
             },
         );
         models.insert(
@@ -312,6 +862,11 @@ mod tests {
                 output_cost_per_token: Some(0.000075), // $75 per 1M tokens
                 cache_read_input_token_cost: Some(0.0000015), // $1.50 per 1M tokens
                 cache_creation_input_token_cost: Some(0.00001875), // $18.75 per 1M tokens
+                cache_creation_input_token_cost_above_1hr: None,
+                output_cost_per_reasoning_token: None,
+                web_search_cost_per_request: None,
+                provider: None,
+                tiers: vec![],
             },
         );
 
@@ -322,6 +877,7 @@ mod tests {
 
         let cache = PricingCache {
             fetched_at: now,
+            schema: PRICING_CACHE_SCHEMA,
             models,
         };
 
@@ -389,19 +945,18 @@ mod tests {
         // - input=$3/1M, output=$15/1M
         // - cache_read=$0.30/1M, cache_creation=$3.75/1M
         //
-        // Entry: input=1000, output=500, cache_read=200, cache_creation=100
-        // non_cached_input = 1000 - 200 = 800
+        // Entry: input=1000 (uncached), output=500, cache_read=200, cache_creation=100
         //
-        // Cost = (800 * 0.000003) + (200 * 0.0000003) + (100 * 0.00000375) + (500 * 0.000015)
-        //      = 0.0024 + 0.00006 + 0.000375 + 0.0075
-        //      = 0.010335
+        // Cost = (1000 * 0.000003) + (200 * 0.0000003) + (100 * 0.00000375) + (500 * 0.000015)
+        //      = 0.003 + 0.00006 + 0.000375 + 0.0075
+        //      = 0.010935
         let entry = make_entry(Some("claude-sonnet-4"), 1000, 500, 200, 100, None);
 
         let cost = service.calculate_cost(&entry);
 
         assert!(
-            (cost - 0.010335).abs() < 1e-10,
-            "Expected 0.010335, got {}",
+            (cost - 0.010935).abs() < 1e-10,
+            "Expected 0.010935, got {}",
             cost
         );
     }
@@ -414,6 +969,7 @@ mod tests {
         let cost = service.calculate_cost(&entry);
 
         assert!((cost - 0.0).abs() < f64::EPSILON);
+        assert!(service.calculate_charges(&entry).is_none());
     }
 
     #[test]
@@ -427,33 +983,425 @@ mod tests {
     }
 
     #[test]
-    fn test_non_cached_input_calculation() {
+    fn test_input_is_not_reduced_by_cache_reads() {
         let (service, _temp) = create_test_service();
-        // If cache_read > input_tokens, saturating_sub should give 0
-        // This tests edge case: input=100, cache_read=150
+        // input_tokens is already uncached, even when cache reads exceed it
         let entry = make_entry(Some("claude-sonnet-4"), 100, 500, 150, 0, None);
 
-        // non_cached_input = 100.saturating_sub(150) = 0
-        // cost = (0 * input) + (150 * cache_read) + (0 * cache_create) + (500 * output)
-        //      = 0 + (150 * 0.0000003) + 0 + (500 * 0.000015)
-        //      = 0.000045 + 0.0075
-        //      = 0.007545
+        // cost = (100 * input) + (150 * cache_read) + (0 * cache_create) + (500 * output)
+        //      = 0.0003 + 0.000045 + 0 + 0.0075
+        //      = 0.007845
         let cost = service.calculate_cost(&entry);
 
         assert!(
-            (cost - 0.007545).abs() < 1e-10,
-            "Expected 0.007545, got {}",
+            (cost - 0.007845).abs() < 1e-10,
+            "Expected 0.007845, got {}",
             cost
         );
     }
 
+    #[test]
+    fn test_thinking_billed_at_output_rate_by_default() {
+        let (service, _temp) = create_test_service();
+        let mut entry = make_entry(Some("claude-sonnet-4"), 0, 100, 0, 0, None);
+        entry.thinking_tokens = 1000;
+
+        // (100 + 1000) output-rate tokens * $15/1M = $0.0165
+        assert_cost(service.calculate_cost(&entry), 0.0165);
+    }
+
+    #[test]
+    fn test_thinking_uses_reasoning_rate_when_published() {
+        let (mut service, _temp) = create_test_service();
+        service.cache.models.insert(
+            "reasoner".to_string(),
+            ModelPricing {
+                output_cost_per_token: Some(0.00001), // $10 per 1M tokens
+                output_cost_per_reasoning_token: Some(0.000002), // $2 per 1M tokens
+                ..ModelPricing::default()
+            },
+        );
+        let mut entry = make_entry(Some("reasoner"), 0, 100, 0, 0, None);
+        entry.thinking_tokens = 1000;
+
+        // 100 * $10/1M + 1000 * $2/1M = $0.003
+        assert_cost(service.calculate_cost(&entry), 0.003);
+    }
+
+    #[test]
+    fn test_reasoning_rate_deserializes_from_litellm() {
+        let pricing: ModelPricing = serde_json::from_str(
+            r#"{"input_cost_per_token": 1e-6, "output_cost_per_token": 4e-6,
+                "output_cost_per_reasoning_token": 3e-6, "max_tokens": 8192}"#,
+        )
+        .unwrap();
+        assert_eq!(pricing.output_cost_per_reasoning_token, Some(3e-6));
+    }
+
+    #[test]
+    fn test_one_hour_cache_writes_use_1hr_rate() {
+        let (mut service, _temp) = create_test_service();
+        service.cache.models.insert(
+            "claude-sonnet-4".to_string(),
+            serde_json::from_str(
+                r#"{"input_cost_per_token": 3e-6, "output_cost_per_token": 1.5e-5,
+                    "cache_creation_input_token_cost": 3.75e-6,
+                    "cache_creation_input_token_cost_above_1hr": 6e-6}"#,
+            )
+            .unwrap(),
+        );
+        let mut entry = make_entry(Some("claude-sonnet-4"), 0, 0, 0, 1000, None);
+        entry.cache_creation_1h_tokens = 400;
+
+        // 600 * $3.75/1M + 400 * $6/1M = $0.00465
+        assert_cost(service.calculate_cost(&entry), 0.00465);
+    }
+
+    #[test]
+    fn test_one_hour_cache_writes_fall_back_to_5m_rate() {
+        let (service, _temp) = create_test_service();
+        let mut entry = make_entry(Some("claude-sonnet-4"), 0, 0, 0, 1000, None);
+        entry.cache_creation_1h_tokens = 400;
+
+        // No 1-hour rate published: all 1000 writes at $3.75/1M
+        assert_cost(service.calculate_cost(&entry), 0.00375);
+    }
+
+    #[test]
+    fn test_web_searches_are_a_separate_line_item() {
+        let (mut service, _temp) = create_test_service();
+        let mut entry = make_entry(Some("claude-sonnet-4"), 1000, 0, 0, 0, None);
+        entry.web_search_requests = 2;
+
+        // No published rate: $10 per 1,000 searches
+        let charges = service.calculate_charges(&entry).unwrap();
+        assert_cost(charges.tokens_usd, 0.003);
+        assert_cost(charges.web_search_usd, 0.02);
+        assert_cost(service.calculate_cost(&entry), 0.023);
+
+        service.cache.models.insert(
+            "claude-sonnet-4".to_string(),
+            serde_json::from_str(
+                r#"{"input_cost_per_token": 3e-6,
+                    "search_context_cost_per_query": {
+                        "search_context_size_low": 0.02,
+                        "search_context_size_medium": 0.03}}"#,
+            )
+            .unwrap(),
+        );
+        assert_cost(
+            service.calculate_charges(&entry).unwrap().web_search_usd,
+            0.06,
+        );
+    }
+
+    #[test]
+    fn test_batch_tier_discount_is_a_separate_line_item() {
+        let (service, _temp) = create_test_service();
+        let mut entry = make_entry(Some("claude-sonnet-4"), 1000, 500, 0, 0, None);
+        entry.service_tier = Some("batch".to_string());
+
+        let charges = service.calculate_charges(&entry).unwrap();
+        assert_cost(charges.tokens_usd, 0.0105);
+        assert_cost(charges.service_tier_usd, -0.00525);
+        assert_cost(charges.total(), 0.00525);
+
+        entry.service_tier = Some("standard".to_string());
+        assert_eq!(
+            service.calculate_charges(&entry).unwrap().service_tier_usd,
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_charges_split_token_cost_by_type() {
+        let (service, _temp) = create_test_service();
+        let mut entry = make_entry(Some("claude-sonnet-4"), 1000, 500, 200, 100, None);
+        entry.thinking_tokens = 100;
+
+        let costs = service.calculate_charges(&entry).unwrap().token_costs;
+        assert_cost(costs.input_usd, 0.003);
+        assert_cost(costs.output_usd, 0.0075);
+        assert_cost(costs.cache_read_usd, 0.00006);
+        assert_cost(costs.cache_write_usd, 0.000375);
+        // Thinking at the output rate
+        assert_cost(costs.thinking_usd, 0.0015);
+
+        // Batch halves every part, so the parts still add up to the token cost
+        entry.service_tier = Some("batch".to_string());
+        let charges = service.calculate_charges(&entry).unwrap();
+        assert_cost(charges.token_costs.output_usd, 0.00375);
+        assert_cost(
+            charges.token_costs.total(),
+            charges.tokens_usd + charges.service_tier_usd,
+        );
+    }
+
+    #[test]
+    fn test_service_tier_multiplier() {
+        let tiers = ServiceTierMultipliers::default();
+        assert_eq!(tiers.get(None), 1.0);
+        assert_eq!(tiers.get(Some("standard")), 1.0);
+        assert_eq!(tiers.get(Some("priority")), 1.0);
+        assert_eq!(tiers.get(Some("batch")), 0.5);
+        assert_eq!(tiers.get(Some("Flex")), 0.5);
+    }
+
+    #[test]
+    fn test_configured_service_tier_adds_surcharge() {
+        let (service, _temp) = create_test_service();
+        let mut tiers = ServiceTierMultipliers::default();
+        tiers.set("priority", 1.75);
+        let service = service.with_service_tiers(&tiers);
+        let mut entry = make_entry(Some("claude-sonnet-4"), 1000, 500, 0, 0, None);
+        entry.service_tier = Some("priority".to_string());
+
+        let charges = service.calculate_charges(&entry).unwrap();
+        assert_cost(charges.tokens_usd, 0.0105);
+        assert_cost(charges.service_tier_usd, 0.007875);
+        assert_cost(charges.total(), 0.018375);
+        assert_cost(charges.token_costs.total(), charges.total());
+
+        // Batch keeps its built-in discount
+        entry.service_tier = Some("batch".to_string());
+        assert_cost(
+            service.calculate_charges(&entry).unwrap().service_tier_usd,
+            -0.00525,
+        );
+    }
+
+    #[test]
+    fn test_web_search_rate_survives_cache_round_trip() {
+        let pricing = ModelPricing {
+            web_search_cost_per_request: Some(0.01),
+            ..ModelPricing::default()
+        };
+        let json = serde_json::to_string(&pricing).unwrap();
+        let pricing: ModelPricing = serde_json::from_str(&json).unwrap();
+        assert_eq!(pricing.web_search_cost_per_request, Some(0.01));
+    }
+
+    // ========== tiered pricing tests ==========
+
+    /// LiteLLM-style entry with a 200k long-context tier
+    fn tiered_pricing() -> ModelPricing {
+        serde_json::from_str(
+            r#"{
+                "input_cost_per_token": 3e-6,
+                "output_cost_per_token": 1.5e-5,
+                "cache_read_input_token_cost": 3e-7,
+                "cache_creation_input_token_cost": 3.75e-6,
+                "input_cost_per_token_above_200k_tokens": 6e-6,
+                "output_cost_per_token_above_200k_tokens": 2.25e-5,
+                "cache_read_input_token_cost_above_200k_tokens": 6e-7,
+                "output_cost_per_character_above_128k_tokens": 1e-6,
+                "max_input_tokens": 1000000,
+                "litellm_provider": "anthropic"
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_tier_key() {
+        assert_eq!(
+            parse_tier_key("input_cost_per_token_above_200k_tokens"),
+            Some(("input_cost_per_token", 200_000))
+        );
+        assert_eq!(
+            parse_tier_key("output_cost_per_token_above_128000_tokens"),
+            Some(("output_cost_per_token", 128_000))
+        );
+        assert_eq!(parse_tier_key("max_input_tokens"), None);
+        assert_eq!(parse_tier_key("input_cost_per_token"), None);
+    }
+
+    #[test]
+    fn test_tiers_deserialize_from_litellm_keys() {
+        let pricing = tiered_pricing();
+        assert_eq!(
+            pricing.tiers,
+            vec![PricingTier {
+                threshold: 200_000,
+                input_cost_per_token: Some(6e-6),
+                output_cost_per_token: Some(2.25e-5),
+                cache_read_input_token_cost: Some(6e-7),
+                cache_creation_input_token_cost: None,
+                cache_creation_input_token_cost_above_1hr: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_tiers_survive_cache_round_trip() {
+        let json = serde_json::to_string(&tiered_pricing()).unwrap();
+        assert!(json.contains("\"input_cost_per_token_above_200k_tokens\""));
+        let pricing: ModelPricing = serde_json::from_str(&json).unwrap();
+        assert_eq!(pricing.tiers, tiered_pricing().tiers);
+    }
+
+    #[test]
+    fn test_calculate_cost_uses_tier_above_threshold() {
+        let (mut service, _temp) = create_test_service();
+        service
+            .cache
+            .models
+            .insert("claude-sonnet-4".to_string(), tiered_pricing());
+
+        // Exactly 200k prompt tokens stays on the base tier:
+        // 200000*3 + 1000*15 per 1M = $0.615
+        let entry = make_entry(Some("claude-sonnet-4"), 200_000, 1000, 0, 0, None);
+        assert_cost(service.calculate_cost(&entry), 0.615);
+        let charges = service.calculate_charges(&entry).unwrap();
+        assert_eq!(charges.long_context_tier, None);
+
+        // Cached tokens count toward the prompt size; cache writes have
+        // no tiered rate and fall back to the base rate:
+        // 100000*6 + 1000*22.5 + 100000*0.6 + 1000*3.75 per 1M = $0.68625
+        let entry = make_entry(Some("claude-sonnet-4"), 100_000, 1000, 100_000, 1000, None);
+        assert_cost(service.calculate_cost(&entry), 0.68625);
+        let charges = service.calculate_charges(&entry).unwrap();
+        assert_eq!(charges.long_context_tier, Some(200_000));
+    }
+
+    #[test]
+    fn test_tier_for_picks_highest_threshold_crossed() {
+        let pricing: ModelPricing = serde_json::from_str(
+            r#"{
+                "input_cost_per_token": 1e-6,
+                "input_cost_per_token_above_128k_tokens": 2e-6,
+                "input_cost_per_token_above_200k_tokens": 4e-6
+            }"#,
+        )
+        .unwrap();
+        assert!(pricing.tier_for(128_000).is_none());
+        assert_eq!(pricing.tier_for(150_000).unwrap().threshold, 128_000);
+        assert_eq!(pricing.tier_for(900_000).unwrap().threshold, 200_000);
+    }
+
+    // ========== cross-parser cost tests ==========
+
+    /// Test service extended with the models used by the parser fixtures
+    fn create_cross_parser_service() -> (PricingService, TempDir) {
+        let (mut service, temp) = create_test_service();
+        let gpt5 = ModelPricing {
+            input_cost_per_token: Some(0.00000125), // $1.25 per 1M tokens
+            output_cost_per_token: Some(0.00001),   // $10 per 1M tokens
+            cache_read_input_token_cost: Some(0.000000125), // $0.125 per 1M tokens
+            cache_creation_input_token_cost: None,
+            cache_creation_input_token_cost_above_1hr: None,
+            output_cost_per_reasoning_token: None,
+            web_search_cost_per_request: None,
+            provider: None,
+            tiers: vec![],
+        };
+        service
+            .cache
+            .models
+            .insert("gpt-5-codex".to_string(), gpt5.clone());
+        service.cache.models.insert("gpt-5".to_string(), gpt5);
+        service.cache.models.insert(
+            "gemini-2.5-pro".to_string(),
+            ModelPricing {
+                input_cost_per_token: Some(0.00000125), // $1.25 per 1M tokens
+                output_cost_per_token: Some(0.00001),   // $10 per 1M tokens
+                cache_read_input_token_cost: Some(0.00000031), // $0.31 per 1M tokens
+                cache_creation_input_token_cost: None,
+                cache_creation_input_token_cost_above_1hr: None,
+                output_cost_per_reasoning_token: None,
+                web_search_cost_per_request: None,
+                provider: None,
+                tiers: vec![],
+            },
+        );
+        (service, temp)
+    }
+
+    fn fixture(path: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(path)
+    }
+
+    fn assert_cost(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-10,
+            "Expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn test_claude_cost_bills_all_input_plus_cache() {
+        let (service, _temp) = create_cross_parser_service();
+        let entries = ClaudeCodeParser::new()
+            .parse_file(&fixture("claude-sample.jsonl"))
+            .unwrap();
+
+        // input=100, output=50, cache_creation=10, cache_read=20 (sonnet)
+        // = 100*3 + 50*15 + 10*3.75 + 20*0.30 per 1M = $0.0010935
+        assert_cost(service.calculate_cost(&entries[0]), 0.0010935);
+    }
+
+    #[test]
+    fn test_codex_cost_excludes_cached_input() {
+        let (service, _temp) = create_cross_parser_service();
+        let entries = CodexParser::new()
+            .parse_file(&fixture("codex/turns-session.jsonl"))
+            .unwrap();
+
+        // Reported input=1000 incl. 200 cached, output=300 incl. 100 reasoning
+        // = 800*1.25 + 200*0.125 + 200*10 + 100*10 per 1M = $0.004025
+        assert_cost(service.calculate_cost(&entries[0]), 0.004025);
+        // input=600 incl. 300 cached, output=150 incl. 50 reasoning
+        // = 300*1.25 + 300*0.125 + 100*10 + 50*10 per 1M = $0.0019125
+        assert_cost(service.calculate_cost(&entries[1]), 0.0019125);
+    }
+
+    #[test]
+    fn test_gemini_cost_excludes_cached_input() {
+        let (service, _temp) = create_cross_parser_service();
+        let entries = GeminiParser::new()
+            .parse_file(&fixture("gemini/tmp123/chats/session-abc123.json"))
+            .unwrap();
+
+        // Reported input=100 incl. 20 cached, output=50, thoughts=30
+        // = 80*1.25 + 20*0.31 + 50*10 + 30*10 per 1M = $0.0009062
+        assert_cost(service.calculate_cost(&entries[0]), 0.0009062);
+        // input=250 incl. 50 cached, output=150, thoughts=100
+        // = 200*1.25 + 50*0.31 + 150*10 + 100*10 per 1M = $0.0027655
+        assert_cost(service.calculate_cost(&entries[1]), 0.0027655);
+    }
+
+    #[test]
+    fn test_opencode_cost_bills_all_input_plus_cache() {
+        let (service, _temp) = create_cross_parser_service();
+        let entries = OpenCodeParser::new()
+            .parse_file(&fixture("opencode/storage/message/ses_test/msg_001.json"))
+            .unwrap();
+
+        // input=1000, output=500, cache read=100, write=50 (sonnet)
+        // = 1000*3 + 500*15 + 100*0.30 + 50*3.75 per 1M = $0.0107175
+        assert_cost(service.calculate_cost(&entries[0]), 0.0107175);
+        // The recorded cost still takes precedence
+        assert_cost(service.get_or_calculate_cost(&entries[0]), 0.05);
+
+        let entries = OpenCodeParser::new()
+            .parse_file(&fixture("opencode/storage/message/ses_test/msg_002.json"))
+            .unwrap();
+        // input=2000, output=800, reasoning=150, cache read=200, write=100 (sonnet)
+        // = 2000*3 + 800*15 + 150*15 + 200*0.30 + 100*3.75 per 1M = $0.020685
+        assert_cost(service.calculate_cost(&entries[0]), 0.020685);
+    }
+
     // ========== get_pricing tests ==========
 
     #[test]
     fn test_get_pricing_exact_match() {
         let (service, _temp) = create_test_service();
 
-        let pricing = service.get_pricing("claude-sonnet-4");
+        let pricing = service.get_pricing_for("claude-sonnet-4", None);
 
         assert!(pricing.is_some());
         let p = pricing.unwrap();
@@ -464,7 +1412,7 @@ mod tests {
     fn test_get_pricing_not_found() {
         let (service, _temp) = create_test_service();
 
-        let pricing = service.get_pricing("nonexistent-model");
+        let pricing = service.get_pricing_for("nonexistent-model", None);
 
         assert!(pricing.is_none());
     }
@@ -473,7 +1421,7 @@ mod tests {
     fn test_get_pricing_normalized_date_suffix() {
         let (service, _temp) = create_test_service();
         // claude-sonnet-4 is in cache, try with date suffix
-        let pricing = service.get_pricing("claude-sonnet-4-20250514");
+        let pricing = service.get_pricing_for("claude-sonnet-4-20250514", None);
 
         assert!(pricing.is_some());
         let p = pricing.unwrap();
@@ -484,11 +1432,46 @@ mod tests {
     fn test_get_pricing_normalized_dot_to_hyphen() {
         let (service, _temp) = create_test_service();
         // claude-opus-4 is in cache, try with dot version
-        let pricing = service.get_pricing("claude-opus-4");
+        let pricing = service.get_pricing_for("claude-opus-4", None);
 
         assert!(pricing.is_some());
     }
 
+    #[test]
+    fn test_provider_ids_prefer_provider_rates() {
+        let (mut service, _temp) = create_test_service();
+        let input_only = |rate: f64| ModelPricing {
+            input_cost_per_token: Some(rate),
+            ..ModelPricing::default()
+        };
+        service.cache.models.insert(
+            "openrouter/anthropic/claude-sonnet-4".to_string(),
+            input_only(0.000004),
+        );
+        service.cache.models.insert(
+            "vertex_ai/claude-sonnet-4@20250514".to_string(),
+            input_only(0.000005),
+        );
+
+        let rate = |model: &str, provider: Option<&str>| {
+            let mut entry = make_entry(Some(model), 1_000_000, 0, 0, 0, None);
+            entry.provider = provider.map(String::from);
+            service.calculate_cost(&entry)
+        };
+        assert_cost(rate("anthropic/claude-sonnet-4", Some("openrouter")), 4.0);
+        assert_cost(rate("claude-sonnet-4@20250514", None), 5.0);
+        assert_cost(rate("claude-sonnet-4@20250514", Some("google-vertex")), 5.0);
+        // No provider entry: canonical model's rates
+        assert_cost(rate("anthropic/claude-sonnet-4", None), 3.0);
+        assert_cost(
+            rate(
+                "us.anthropic.claude-opus-4-20250514-v1:0",
+                Some("amazon-bedrock"),
+            ),
+            15.0,
+        );
+    }
+
     // ========== PricingCache tests ==========
 
     #[test]
@@ -501,6 +1484,7 @@ mod tests {
 
         let cache = PricingCache {
             fetched_at: old_timestamp,
+            schema: PRICING_CACHE_SCHEMA,
             models: HashMap::new(),
         };
 
@@ -517,12 +1501,31 @@ mod tests {
 
         let cache = PricingCache {
             fetched_at: recent_timestamp,
+            schema: PRICING_CACHE_SCHEMA,
             models: HashMap::new(),
         };
 
         assert!(!cache.is_expired());
     }
 
+    #[test]
+    fn test_cache_is_expired_after_custom_ttl() {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64
+            - 600; // 10 minutes ago
+
+        let cache = PricingCache {
+            fetched_at: timestamp,
+            schema: PRICING_CACHE_SCHEMA,
+            models: HashMap::new(),
+        };
+
+        assert!(cache.is_expired_after(300));
+        assert!(!cache.is_expired_after(900));
+    }
+
     #[test]
     fn test_cache_load_and_save() {
         let temp_dir = TempDir::new().unwrap();
@@ -536,11 +1539,17 @@ mod tests {
                 output_cost_per_token: Some(0.002),
                 cache_read_input_token_cost: None,
                 cache_creation_input_token_cost: None,
+                cache_creation_input_token_cost_above_1hr: None,
+                output_cost_per_reasoning_token: None,
+                web_search_cost_per_request: None,
+                provider: None,
+                tiers: vec![],
             },
         );
 
         let cache = PricingCache {
             fetched_at: 12345,
+            schema: PRICING_CACHE_SCHEMA,
             models,
         };
 
@@ -562,6 +1571,280 @@ mod tests {
         assert_eq!(service.model_count(), 2);
     }
 
+    // ========== aggregated usage tests ==========
+
+    #[test]
+    fn test_usage_charges_bill_recorded_tiers_and_keep_service_tier_share() {
+        let (service, _temp_dir) = create_test_service();
+        let mut pricing = service
+            .get_pricing_for("claude-sonnet-4", None)
+            .unwrap()
+            .clone();
+        pricing.tiers = vec![PricingTier {
+            threshold: 200_000,
+            input_cost_per_token: Some(0.000006),
+            ..PricingTier::default()
+        }];
+        let mut service = service;
+        service
+            .cache
+            .models
+            .insert("claude-sonnet-4".to_string(), pricing);
+
+        // A day's total of 1M input, no request above 200K: all at base $3/1M
+        let usage = ModelUsage {
+            input_tokens: 1_000_000,
+            cost_usd: 1.5,
+            service_tier_cost_usd: -1.5,
+            web_search_requests: 2,
+            max_prompt_tokens: Some(150_000),
+            ..ModelUsage::default()
+        };
+        let date = NaiveDate::from_ymd_opt(2026, 9, 1).unwrap();
+        let charges = service
+            .calculate_usage_charges("claude-sonnet-4", None, date, &usage)
+            .unwrap();
+
+        assert_cost(charges.tokens_usd, 3.0);
+        // Recorded batch discount was half the token cost
+        assert_cost(charges.service_tier_usd, -1.5);
+        assert_cost(charges.web_search_usd, 0.02);
+        // The discount carries into the split by type
+        assert_cost(charges.token_costs.input_usd, 1.5);
+        assert!(service
+            .calculate_usage_charges("unknown-model", None, date, &usage)
+            .is_none());
+
+        // 400K of it was billed at the tier: $1.80 base + $2.40 tiered
+        let tiered = ModelUsage {
+            max_prompt_tokens: Some(400_000),
+            tier_tokens: BTreeMap::from([(
+                200_000,
+                TierTokens {
+                    input_tokens: 400_000,
+                    ..TierTokens::default()
+                },
+            )]),
+            ..usage.clone()
+        };
+        let charges = service
+            .calculate_usage_charges("claude-sonnet-4", None, date, &tiered)
+            .unwrap();
+        assert_cost(charges.tokens_usd, 4.2);
+
+        // Not reproducible: a request crossed 200K with no tier recorded,
+        // prompt sizes weren't recorded, or the cost was reported
+        for usage in [
+            ModelUsage {
+                tier_tokens: BTreeMap::new(),
+                ..tiered.clone()
+            },
+            ModelUsage {
+                max_prompt_tokens: None,
+                ..usage.clone()
+            },
+            ModelUsage {
+                cost_reported: true,
+                ..usage
+            },
+        ] {
+            assert!(service
+                .calculate_usage_charges("claude-sonnet-4", None, date, &usage)
+                .is_none());
+        }
+    }
+
+    // ========== price history tests ==========
+
+    #[test]
+    fn test_entries_priced_at_rates_in_effect_on_their_date() {
+        let (service, temp_dir) = create_test_service();
+        let cache_path = temp_dir.path().join("pricing.json");
+
+        // Sonnet input was $6/1M until today, $3/1M (the current table) from today
+        let today = Local::now().date_naive();
+        let noon = |date: NaiveDate| {
+            date.and_hms_opt(12, 0, 0)
+                .unwrap()
+                .and_local_timezone(Local)
+                .unwrap()
+        };
+        let mut old = service
+            .get_pricing_for("claude-sonnet-4", None)
+            .unwrap()
+            .clone();
+        old.input_cost_per_token = Some(0.000006);
+        let mut history = PricingHistory::default();
+        history.record_changes(
+            &PricingCache {
+                fetched_at: noon(today - chrono::Duration::days(30)).timestamp(),
+                schema: PRICING_CACHE_SCHEMA,
+                models: HashMap::from([("claude-sonnet-4".to_string(), old)]),
+            },
+            &PricingCache {
+                fetched_at: noon(today).timestamp(),
+                schema: PRICING_CACHE_SCHEMA,
+                models: HashMap::from([(
+                    "claude-sonnet-4".to_string(),
+                    service
+                        .get_pricing_for("claude-sonnet-4", None)
+                        .unwrap()
+                        .clone(),
+                )]),
+            },
+        );
+        history
+            .save(&PricingService::history_path(&cache_path))
+            .unwrap();
+
+        let overrides = PricingOverrides::from_toml_str(
+            "[aliases]\n\"corp-sonnet\" = \"claude-sonnet-4\"\n[[discounts]]\nmodel = \"corp-*\"\npercent = 50",
+        )
+        .unwrap();
+        let service = PricingService::from_cache_only_with_path(&cache_path)
+            .unwrap()
+            .with_overrides(&overrides);
+
+        let entry_on = |model: &str, date: NaiveDate| UsageEntry {
+            timestamp: noon(date).with_timezone(&Utc),
+            ..make_entry(Some(model), 1_000_000, 0, 0, 0, None)
+        };
+        let last_week = today - chrono::Duration::days(7);
+        let last_year = today - chrono::Duration::days(365);
+
+        assert_cost(
+            service.calculate_cost(&entry_on("claude-sonnet-4", today)),
+            3.0,
+        );
+        assert_cost(
+            service.calculate_cost(&entry_on("claude-sonnet-4", last_week)),
+            6.0,
+        );
+        // Earliest known rates also cover earlier dates
+        assert_cost(
+            service.calculate_cost(&entry_on("claude-sonnet-4", last_year)),
+            6.0,
+        );
+        // Aliases follow their target's history; the overlay still applies
+        assert_cost(
+            service.calculate_cost(&entry_on("corp-sonnet", last_week)),
+            3.0,
+        );
+        assert_cost(service.calculate_cost(&entry_on("corp-sonnet", today)), 1.5);
+        // Models without history use the current table
+        assert_cost(
+            service.calculate_cost(&entry_on("claude-opus-4", last_year)),
+            15.0,
+        );
+    }
+
+    // ========== bundled snapshot tests ==========
+
+    #[test]
+    fn test_snapshot_prices_models_seen_by_parsers() {
+        let temp_dir = TempDir::new().unwrap();
+        let service = PricingService::from_parts(
+            PricingService::snapshot(),
+            PricingSource::Snapshot,
+            temp_dir.path().join("pricing.json"),
+        );
+
+        for model in [
+            "claude-opus-4-20250514",
+            "claude-opus-4-5-20251101",
+            "claude-sonnet-4-20250514",
+            "claude-sonnet-4-5-20250929",
+            "claude-haiku-4-5-20251001",
+            "gpt-5",
+            "gpt-5-codex",
+            "gpt-4.1",
+            "o4-mini",
+            "gemini-2.5-pro",
+            "gemini-2.5-flash",
+        ] {
+            let pricing = service
+                .get_pricing_for(model, None)
+                .unwrap_or_else(|| panic!("{} missing from snapshot", model));
+            assert!(
+                pricing.input_cost_per_token.unwrap_or(0.0) > 0.0,
+                "{}",
+                model
+            );
+            assert!(
+                pricing.output_cost_per_token.unwrap_or(0.0) > 0.0,
+                "{}",
+                model
+            );
+        }
+        assert!(!service
+            .get_pricing_for("claude-sonnet-4-5", None)
+            .unwrap()
+            .tiers
+            .is_empty());
+    }
+
+    #[test]
+    fn test_snapshot_prices_every_model_in_parser_fixtures() {
+        let temp_dir = TempDir::new().unwrap();
+        let service = PricingService::from_parts(
+            PricingService::snapshot(),
+            PricingSource::Snapshot,
+            temp_dir.path().join("pricing.json"),
+        );
+        let parsers: [Box<dyn CLIParser>; 4] = [
+            Box::new(ClaudeCodeParser::with_data_dir(fixture(""))),
+            Box::new(CodexParser::with_data_dir(fixture("codex"))),
+            Box::new(GeminiParser::with_data_dir(fixture("gemini"))),
+            Box::new(OpenCodeParser::with_data_dir(fixture(
+                "opencode/storage/message",
+            ))),
+        ];
+
+        let mut models = std::collections::BTreeSet::new();
+        for parser in &parsers {
+            let entries = parser.parse_all().unwrap();
+            assert!(!entries.is_empty(), "{} parsed nothing", parser.name());
+            models.extend(entries.into_iter().filter_map(|e| e.model));
+        }
+
+        let missing: Vec<_> = models
+            .iter()
+            .filter(|m| service.get_pricing_for(m, None).is_none())
+            .collect();
+        assert!(missing.is_empty(), "missing from snapshot: {:?}", missing);
+    }
+
+    #[test]
+    fn test_info_reports_source_and_fetch_date() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = PricingCache {
+            fetched_at: 1_792_195_200, // 2026-10-17T00:00:00Z
+            schema: PRICING_CACHE_SCHEMA,
+            models: HashMap::new(),
+        };
+        let service = PricingService::from_parts(
+            cache,
+            PricingSource::Snapshot,
+            temp_dir.path().join("pricing.json"),
+        );
+
+        let info = service.info();
+        assert_eq!(info.source, PricingSource::Snapshot);
+        assert_eq!(
+            info.as_of,
+            chrono::NaiveDate::from_ymd_opt(2026, 10, 17).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_from_cache_only_with_path_reports_cache_source() {
+        let (_, temp_dir) = create_test_service();
+        let cache_path = temp_dir.path().join("pricing.json");
+
+        let service = PricingService::from_cache_only_with_path(&cache_path).unwrap();
+        assert_eq!(service.info().source, PricingSource::Cache);
+    }
+
     // ========== from_cache_only tests ==========
 
     #[test]
@@ -593,6 +1876,7 @@ mod tests {
         models.insert("test-model".to_string(), ModelPricing::default());
         let cache = PricingCache {
             fetched_at: 0,
+            schema: PRICING_CACHE_SCHEMA,
             models,
         };
         let content = serde_json::to_string_pretty(&cache).unwrap();
@@ -0,0 +1,255 @@
+//! Price history: superseded LiteLLM rates with the dates they took effect
+//!
+//! Each refresh compares the fetched table with the previous cache. When a
+//! model's rates changed, the previous and new rates are recorded in
+//! `~/.toktrack/pricing_history.json`, keyed by the local date of each fetch.
+//! Entries dated before the latest change are priced at the rates in effect on
+//! their date; the earliest recorded rates also cover any earlier dates.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use chrono::{DateTime, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::services::pricing::{ModelPricing, PricingCache, PRICING_CACHE_SCHEMA};
+use crate::types::{Result, ToktrackError};
+
+/// History file name, next to `pricing.json`
+pub const PRICING_HISTORY_FILE: &str = "pricing_history.json";
+
+/// Rates in effect from `effective_from` until the next version
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PriceVersion {
+    pub effective_from: NaiveDate,
+    pub rates: ModelPricing,
+}
+
+/// Rate versions per LiteLLM model name, oldest first.
+/// Only models whose rates changed while toktrack was watching appear here.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PricingHistory {
+    pub models: BTreeMap<String, Vec<PriceVersion>>,
+}
+
+impl PricingHistory {
+    /// Load from disk; a missing or unreadable file yields an empty history
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| ToktrackError::Pricing(format!("Serialization failed: {}", e)))?;
+        fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// Record models whose rates differ between the previous and a fresh fetch.
+    /// A previous cache from an older schema lacks rates rather than having
+    /// different ones, so it records nothing. Returns whether anything was recorded.
+    pub fn record_changes(&mut self, previous: &PricingCache, fresh: &PricingCache) -> bool {
+        if previous.schema < PRICING_CACHE_SCHEMA {
+            return false;
+        }
+        let fresh_date = local_date(fresh.fetched_at);
+        // The previous rates must cover at least the day before the change
+        let previous_date =
+            local_date(previous.fetched_at).min(fresh_date.pred_opt().unwrap_or(fresh_date));
+
+        let mut changed = false;
+        for (name, rates) in &fresh.models {
+            let Some(old) = previous.models.get(name) else {
+                continue;
+            };
+            if old.same_rates(rates) {
+                continue;
+            }
+            let versions = self.models.entry(name.clone()).or_default();
+            if versions.is_empty() {
+                versions.push(PriceVersion {
+                    effective_from: previous_date,
+                    rates: old.clone(),
+                });
+            }
+            match versions.last_mut() {
+                // Several changes on one day: the last one wins for that day
+                Some(last) if last.effective_from >= fresh_date => last.rates = rates.clone(),
+                _ => versions.push(PriceVersion {
+                    effective_from: fresh_date,
+                    rates: rates.clone(),
+                }),
+            }
+            changed = true;
+        }
+        changed
+    }
+
+    /// Rates for `model` on `date`, or `None` when the latest (current) rates apply
+    pub fn superseded_rates(&self, model: &str, date: NaiveDate) -> Option<&ModelPricing> {
+        let versions = self.models.get(model)?;
+        if date >= versions.last()?.effective_from {
+            return None;
+        }
+        versions
+            .iter()
+            .rev()
+            .find(|v| v.effective_from <= date)
+            .or(versions.first())
+            .map(|v| &v.rates)
+    }
+}
+
+/// Local calendar date of a Unix timestamp
+fn local_date(timestamp: i64) -> NaiveDate {
+    DateTime::from_timestamp(timestamp, 0)
+        .unwrap_or_default()
+        .with_timezone(&Local)
+        .date_naive()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+    use std::collections::HashMap;
+
+    fn date(m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, m, d).unwrap()
+    }
+
+    /// Noon local time, so the local date is stable across time zones
+    fn fetched_on(day: NaiveDate) -> i64 {
+        Local
+            .from_local_datetime(&day.and_hms_opt(12, 0, 0).unwrap())
+            .unwrap()
+            .timestamp()
+    }
+
+    fn rates(input: f64) -> ModelPricing {
+        ModelPricing {
+            input_cost_per_token: Some(input),
+            output_cost_per_token: Some(input * 4.0),
+            ..ModelPricing::default()
+        }
+    }
+
+    fn cache(day: NaiveDate, models: &[(&str, f64)]) -> PricingCache {
+        PricingCache {
+            fetched_at: fetched_on(day),
+            schema: PRICING_CACHE_SCHEMA,
+            models: models
+                .iter()
+                .map(|(name, input)| (name.to_string(), rates(*input)))
+                .collect::<HashMap<_, _>>(),
+        }
+    }
+
+    #[test]
+    fn test_record_changes_keeps_previous_and_new_rates() {
+        let mut history = PricingHistory::default();
+        let previous = cache(date(9, 1), &[("gpt-5", 2e-6), ("o3", 2e-6)]);
+        let fresh = cache(date(9, 10), &[("gpt-5", 1e-6), ("o3", 2e-6), ("new", 1e-6)]);
+
+        assert!(history.record_changes(&previous, &fresh));
+        assert_eq!(history.models.len(), 1);
+        assert_eq!(
+            history.models["gpt-5"],
+            vec![
+                PriceVersion {
+                    effective_from: date(9, 1),
+                    rates: rates(2e-6)
+                },
+                PriceVersion {
+                    effective_from: date(9, 10),
+                    rates: rates(1e-6)
+                },
+            ]
+        );
+
+        // Unchanged refresh records nothing
+        let again = cache(date(9, 11), &[("gpt-5", 1e-6)]);
+        assert!(!history.record_changes(&fresh, &again));
+        assert_eq!(history.models["gpt-5"].len(), 2);
+    }
+
+    #[test]
+    fn test_same_day_change_covers_earlier_dates() {
+        let mut history = PricingHistory::default();
+        let previous = cache(date(9, 10), &[("gpt-5", 2e-6)]);
+        let fresh = cache(date(9, 10), &[("gpt-5", 1e-6)]);
+        history.record_changes(&previous, &fresh);
+
+        let versions = &history.models["gpt-5"];
+        assert_eq!(versions[0].effective_from, date(9, 9));
+        assert_eq!(versions[1].effective_from, date(9, 10));
+
+        // A second change the same day replaces that day's rates
+        let later = cache(date(9, 10), &[("gpt-5", 5e-7)]);
+        history.record_changes(&fresh, &later);
+        assert_eq!(history.models["gpt-5"].len(), 2);
+        assert_eq!(history.models["gpt-5"][1].rates, rates(5e-7));
+    }
+
+    #[test]
+    fn test_old_schema_and_provider_changes_record_nothing() {
+        let mut history = PricingHistory::default();
+        let fresh = cache(date(9, 10), &[("gpt-5", 1e-6)]);
+
+        // pricing.json from before tiers and providers were kept
+        let legacy: PricingCache = serde_json::from_str(
+            r#"{"fetched_at": 0, "models": {"gpt-5": {"input_cost_per_token": 2e-6}}}"#,
+        )
+        .unwrap();
+        assert!(!history.record_changes(&legacy, &fresh));
+
+        let mut relabeled = cache(date(9, 11), &[("gpt-5", 1e-6)]);
+        relabeled.models.get_mut("gpt-5").unwrap().provider = Some("openai".into());
+        assert!(!history.record_changes(&fresh, &relabeled));
+        assert!(history.models.is_empty());
+    }
+
+    #[test]
+    fn test_superseded_rates_by_date() {
+        let mut history = PricingHistory::default();
+        history.record_changes(
+            &cache(date(8, 1), &[("gpt-5", 3e-6)]),
+            &cache(date(9, 1), &[("gpt-5", 2e-6)]),
+        );
+        history.record_changes(
+            &cache(date(9, 1), &[("gpt-5", 2e-6)]),
+            &cache(date(10, 1), &[("gpt-5", 1e-6)]),
+        );
+
+        let at = |d| history.superseded_rates("gpt-5", d).cloned();
+        assert_eq!(at(date(7, 1)), Some(rates(3e-6)));
+        assert_eq!(at(date(8, 31)), Some(rates(3e-6)));
+        assert_eq!(at(date(9, 1)), Some(rates(2e-6)));
+        assert_eq!(at(date(9, 30)), Some(rates(2e-6)));
+        assert_eq!(at(date(10, 1)), None);
+        assert_eq!(at(date(10, 1) + Duration::days(30)), None);
+        assert_eq!(history.superseded_rates("o3", date(7, 1)), None);
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(PRICING_HISTORY_FILE);
+        assert_eq!(PricingHistory::load(&path), PricingHistory::default());
+
+        let mut history = PricingHistory::default();
+        history.record_changes(
+            &cache(date(9, 1), &[("gpt-5", 2e-6)]),
+            &cache(date(9, 10), &[("gpt-5", 1e-6)]),
+        );
+        history.save(&path).unwrap();
+        assert_eq!(PricingHistory::load(&path), history);
+    }
+}
@@ -0,0 +1,645 @@
+//! Local pricing overlay loaded from `~/.toktrack/pricing_overrides.toml`
+//!
+//! The overlay is merged on top of the LiteLLM price table before any lookup:
+//! 1. `[models."<name>"]` defines a new model or overrides individual rates
+//! 2. `[aliases]` maps a model name (e.g. a gateway alias) to a known model
+//! 3. `[[discounts]]` scales every rate of matching models by a percentage;
+//!    the first matching discount (in file order) applies
+//!
+//! Token rates are written in USD per million tokens, web search in USD per request.
+//! Overriding a token rate also replaces its long-context tier rates, so long
+//! prompts are billed at the overridden rate too.
+//!
+//! Discount providers are provider IDs as used by `--provider` and
+//! `[provider_costs]` (e.g. "amazon-bedrock", "google-vertex"). They match
+//! price table entries by their LiteLLM provider, so a Bedrock discount applies
+//! to Bedrock's own rates; a model priced at its first-party rates gets the
+//! first-party provider's discount.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use glob::{MatchOptions, Pattern};
+use serde::Deserialize;
+
+use crate::services::normalize_model_name;
+use crate::services::pricing::ModelPricing;
+use crate::services::provider::litellm_provider_id;
+use crate::types::{Result, ToktrackError};
+
+/// Overlay file name, next to `config.toml`
+pub const PRICING_OVERRIDES_FILE: &str = "pricing_overrides.toml";
+
+/// On-disk layout
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RawOverrides {
+    models: BTreeMap<String, RawRates>,
+    aliases: BTreeMap<String, String>,
+    discounts: Vec<RawDiscount>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RawRates {
+    input: Option<f64>,
+    output: Option<f64>,
+    cache_read: Option<f64>,
+    cache_write: Option<f64>,
+    cache_write_1h: Option<f64>,
+    reasoning: Option<f64>,
+    web_search: Option<f64>,
+    provider: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawDiscount {
+    provider: Option<String>,
+    model: Option<String>,
+    percent: f64,
+}
+
+/// Rates set for one model, in USD per token (web search: per request).
+/// `None` keeps the LiteLLM rate.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RateOverride {
+    pub input_cost_per_token: Option<f64>,
+    pub output_cost_per_token: Option<f64>,
+    pub cache_read_input_token_cost: Option<f64>,
+    pub cache_creation_input_token_cost: Option<f64>,
+    pub cache_creation_input_token_cost_above_1hr: Option<f64>,
+    pub output_cost_per_reasoning_token: Option<f64>,
+    pub web_search_cost_per_request: Option<f64>,
+    pub provider: Option<String>,
+}
+
+/// Percentage discount for models matching a provider ID and/or model glob
+/// (neither = every model)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Discount {
+    pub provider: Option<String>,
+    pub model: Option<String>,
+    pub percent: f64,
+}
+
+impl Discount {
+    fn matches(&self, name: &str, pricing: &ModelPricing) -> bool {
+        let provider_ok = self.provider.as_deref().is_none_or(|provider| {
+            pricing
+                .provider
+                .as_deref()
+                .is_some_and(|p| litellm_provider_id(p).eq_ignore_ascii_case(provider.trim()))
+        });
+        let options = MatchOptions {
+            case_sensitive: false,
+            ..MatchOptions::new()
+        };
+        let model_ok = self.model.as_deref().is_none_or(|pattern| {
+            Pattern::new(pattern).is_ok_and(|p| p.matches_with(name, options))
+        });
+        provider_ok && model_ok
+    }
+}
+
+/// Validated pricing overlay
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PricingOverrides {
+    pub models: BTreeMap<String, RateOverride>,
+    /// Alias → target model
+    pub aliases: BTreeMap<String, String>,
+    /// Discounts in file order
+    pub discounts: Vec<Discount>,
+}
+
+impl PricingOverrides {
+    /// Default overlay path (~/.toktrack/pricing_overrides.toml)
+    pub fn default_path() -> Result<PathBuf> {
+        let home = directories::BaseDirs::new()
+            .ok_or_else(|| ToktrackError::Config("Failed to get home directory".into()))?
+            .home_dir()
+            .to_path_buf();
+        Ok(home.join(".toktrack").join(PRICING_OVERRIDES_FILE))
+    }
+
+    /// Load from the given path; a missing file yields an empty overlay
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(c) => c,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(ToktrackError::Io(e)),
+        };
+        Self::from_toml_str(&contents).map_err(|e| match e {
+            ToktrackError::Config(msg) => {
+                ToktrackError::Config(format!("{}: {}", path.display(), msg))
+            }
+            other => other,
+        })
+    }
+
+    /// Parse and validate overlay file contents
+    pub fn from_toml_str(contents: &str) -> Result<Self> {
+        let raw: RawOverrides =
+            toml::from_str(contents).map_err(|e| ToktrackError::Config(e.message().into()))?;
+
+        let mut overrides = Self::default();
+
+        for (name, rates) in raw.models {
+            let invalid = |key: &str| {
+                ToktrackError::Config(format!(
+                    "models.\"{}\".{} must be a non-negative number",
+                    name, key
+                ))
+            };
+            // Token rates are given per million tokens
+            let per_token = |value: Option<f64>, key: &str| match value {
+                Some(v) if !v.is_finite() || v < 0.0 => Err(invalid(key)),
+                Some(v) => Ok(Some(v / 1_000_000.0)),
+                None => Ok(None),
+            };
+            let web_search = match rates.web_search {
+                Some(v) if !v.is_finite() || v < 0.0 => return Err(invalid("web_search")),
+                other => other,
+            };
+            overrides.models.insert(
+                name.clone(),
+                RateOverride {
+                    input_cost_per_token: per_token(rates.input, "input")?,
+                    output_cost_per_token: per_token(rates.output, "output")?,
+                    cache_read_input_token_cost: per_token(rates.cache_read, "cache_read")?,
+                    cache_creation_input_token_cost: per_token(rates.cache_write, "cache_write")?,
+                    cache_creation_input_token_cost_above_1hr: per_token(
+                        rates.cache_write_1h,
+                        "cache_write_1h",
+                    )?,
+                    output_cost_per_reasoning_token: per_token(rates.reasoning, "reasoning")?,
+                    web_search_cost_per_request: web_search,
+                    provider: rates.provider,
+                },
+            );
+        }
+
+        for (alias, target) in raw.aliases {
+            if target.trim().is_empty() || target == alias {
+                return Err(ToktrackError::Config(format!(
+                    "aliases.\"{}\": target must be another model name",
+                    alias
+                )));
+            }
+            overrides.aliases.insert(alias, target);
+        }
+
+        for (i, raw) in raw.discounts.into_iter().enumerate() {
+            let invalid = |msg: String| ToktrackError::Config(format!("discounts[{}]: {}", i, msg));
+            if !raw.percent.is_finite() || raw.percent <= 0.0 || raw.percent > 100.0 {
+                return Err(invalid(
+                    "percent must be greater than 0 and at most 100".into(),
+                ));
+            }
+            if let Some(pattern) = &raw.model {
+                Pattern::new(pattern)
+                    .map_err(|e| invalid(format!("invalid model pattern '{}': {}", pattern, e)))?;
+            }
+            overrides.discounts.push(Discount {
+                provider: raw.provider,
+                model: raw.model,
+                percent: raw.percent,
+            });
+        }
+
+        Ok(overrides)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.models.is_empty() && self.aliases.is_empty() && self.discounts.is_empty()
+    }
+
+    /// Merge the overlay into a price table: model rates, then aliases, then discounts.
+    /// A model's rates also replace those of every LiteLLM ID that normalizes to
+    /// its name (dated or provider-prefixed), since lookups try those first.
+    pub fn apply(&self, models: &mut HashMap<String, ModelPricing>) {
+        for (name, rates) in &self.models {
+            let mut pricing = lookup(models, name).cloned().unwrap_or_default();
+            rates.apply_to(&mut pricing);
+            models.insert(name.clone(), pricing);
+            for (key, pricing) in models.iter_mut() {
+                if key != name && normalize_model_name(key) == *name {
+                    rates.apply_to(pricing);
+                }
+            }
+        }
+
+        for (alias, target) in &self.aliases {
+            match lookup(models, target).cloned() {
+                Some(pricing) => {
+                    models.insert(alias.clone(), pricing);
+                }
+                None => eprintln!(
+                    "[toktrack] Warning: pricing alias '{}' points to unknown model '{}'",
+                    alias, target
+                ),
+            }
+        }
+
+        if self.discounts.is_empty() {
+            return;
+        }
+        for (name, pricing) in models.iter_mut() {
+            self.apply_discount(name, pricing);
+        }
+    }
+
+    /// Model whose LiteLLM rates `name` is priced at (its alias target, or itself)
+    pub fn base_model<'a>(&'a self, name: &'a str) -> &'a str {
+        self.aliases.get(name).map_or(name, String::as_str)
+    }
+
+    /// Apply the overlay to one model's LiteLLM rates, as `apply` would for `name`.
+    /// Used for superseded rates from the price history.
+    pub fn adjust(&self, name: &str, pricing: &mut ModelPricing) {
+        let base = self.base_model(name);
+        let rates = self
+            .models
+            .get(base)
+            .or_else(|| self.models.get(&normalize_model_name(base)));
+        if let Some(rates) = rates {
+            rates.apply_to(pricing);
+        }
+        self.apply_discount(name, pricing);
+    }
+
+    /// Scale by the first discount matching `name`, if any
+    fn apply_discount(&self, name: &str, pricing: &mut ModelPricing) {
+        if let Some(discount) = self.discounts.iter().find(|d| d.matches(name, pricing)) {
+            scale(pricing, 1.0 - discount.percent / 100.0);
+        }
+    }
+}
+
+impl RateOverride {
+    /// Replace the rates this override sets, keeping the rest.
+    /// Tier rates of overridden token rates are cleared so tiers fall back to them.
+    fn apply_to(&self, pricing: &mut ModelPricing) {
+        for tier in &mut pricing.tiers {
+            let fields = [
+                (&mut tier.input_cost_per_token, self.input_cost_per_token),
+                (&mut tier.output_cost_per_token, self.output_cost_per_token),
+                (
+                    &mut tier.cache_read_input_token_cost,
+                    self.cache_read_input_token_cost,
+                ),
+                (
+                    &mut tier.cache_creation_input_token_cost,
+                    self.cache_creation_input_token_cost,
+                ),
+                (
+                    &mut tier.cache_creation_input_token_cost_above_1hr,
+                    self.cache_creation_input_token_cost_above_1hr,
+                ),
+            ];
+            for (field, rate) in fields {
+                if rate.is_some() {
+                    *field = None;
+                }
+            }
+        }
+
+        let fields = [
+            (&mut pricing.input_cost_per_token, self.input_cost_per_token),
+            (
+                &mut pricing.output_cost_per_token,
+                self.output_cost_per_token,
+            ),
+            (
+                &mut pricing.cache_read_input_token_cost,
+                self.cache_read_input_token_cost,
+            ),
+            (
+                &mut pricing.cache_creation_input_token_cost,
+                self.cache_creation_input_token_cost,
+            ),
+            (
+                &mut pricing.cache_creation_input_token_cost_above_1hr,
+                self.cache_creation_input_token_cost_above_1hr,
+            ),
+            (
+                &mut pricing.output_cost_per_reasoning_token,
+                self.output_cost_per_reasoning_token,
+            ),
+            (
+                &mut pricing.web_search_cost_per_request,
+                self.web_search_cost_per_request,
+            ),
+        ];
+        for (field, rate) in fields {
+            if rate.is_some() {
+                *field = rate;
+            }
+        }
+        if self.provider.is_some() {
+            pricing.provider = self.provider.clone();
+        }
+    }
+}
+
+/// Exact match first, then the normalized name (as `PricingService::get_pricing`)
+fn lookup<'a>(models: &'a HashMap<String, ModelPricing>, name: &str) -> Option<&'a ModelPricing> {
+    models
+        .get(name)
+        .or_else(|| models.get(&normalize_model_name(name)))
+}
+
+/// Multiply every rate, including long-context tiers, by `factor`
+fn scale(pricing: &mut ModelPricing, factor: f64) {
+    let rates = [
+        &mut pricing.input_cost_per_token,
+        &mut pricing.output_cost_per_token,
+        &mut pricing.cache_read_input_token_cost,
+        &mut pricing.cache_creation_input_token_cost,
+        &mut pricing.cache_creation_input_token_cost_above_1hr,
+        &mut pricing.output_cost_per_reasoning_token,
+        &mut pricing.web_search_cost_per_request,
+    ];
+    for rate in rates.into_iter().flatten() {
+        *rate *= factor;
+    }
+    for tier in &mut pricing.tiers {
+        let rates = [
+            &mut tier.input_cost_per_token,
+            &mut tier.output_cost_per_token,
+            &mut tier.cache_read_input_token_cost,
+            &mut tier.cache_creation_input_token_cost,
+            &mut tier.cache_creation_input_token_cost_above_1hr,
+        ];
+        for rate in rates.into_iter().flatten() {
+            *rate *= factor;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> HashMap<String, ModelPricing> {
+        let sonnet: ModelPricing = serde_json::from_str(
+            r#"{"input_cost_per_token": 3e-6, "output_cost_per_token": 1.5e-5,
+                "cache_read_input_token_cost": 3e-7, "litellm_provider": "anthropic",
+                "input_cost_per_token_above_200k_tokens": 6e-6}"#,
+        )
+        .unwrap();
+        let gpt: ModelPricing = serde_json::from_str(
+            r#"{"input_cost_per_token": 1.25e-6, "output_cost_per_token": 1e-5,
+                "litellm_provider": "openai"}"#,
+        )
+        .unwrap();
+        HashMap::from([
+            ("claude-sonnet-4".to_string(), sonnet),
+            ("gpt-5".to_string(), gpt),
+        ])
+    }
+
+    fn assert_rate(actual: Option<f64>, expected: f64) {
+        let actual = actual.unwrap();
+        assert!(
+            (actual - expected).abs() < 1e-15,
+            "Expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn test_empty_overlay() {
+        let overrides = PricingOverrides::from_toml_str("").unwrap();
+        assert!(overrides.is_empty());
+    }
+
+    #[test]
+    fn test_new_model_and_rate_override() {
+        let overrides = PricingOverrides::from_toml_str(
+            r#"
+            [models."llama-internal"]
+            input = 0.2
+            output = 0.6
+            provider = "self-hosted"
+
+            [models."claude-sonnet-4"]
+            output = 12
+            "#,
+        )
+        .unwrap();
+        let mut models = table();
+        overrides.apply(&mut models);
+
+        let llama = &models["llama-internal"];
+        assert_rate(llama.input_cost_per_token, 2e-7);
+        assert_rate(llama.output_cost_per_token, 6e-7);
+        assert_eq!(llama.provider.as_deref(), Some("self-hosted"));
+
+        // Only output changes; other rates and tiers are kept
+        let sonnet = &models["claude-sonnet-4"];
+        assert_rate(sonnet.output_cost_per_token, 1.2e-5);
+        assert_rate(sonnet.input_cost_per_token, 3e-6);
+        assert_eq!(sonnet.tiers.len(), 1);
+    }
+
+    #[test]
+    fn test_override_of_dated_model_starts_from_base_rates() {
+        let overrides =
+            PricingOverrides::from_toml_str("[models.\"claude-sonnet-4-20250514\"]\noutput = 10")
+                .unwrap();
+        let mut models = table();
+        overrides.apply(&mut models);
+
+        let dated = &models["claude-sonnet-4-20250514"];
+        assert_rate(dated.input_cost_per_token, 3e-6);
+        assert_rate(dated.output_cost_per_token, 1e-5);
+    }
+
+    #[test]
+    fn test_override_of_canonical_model_covers_dated_ids() {
+        let overrides =
+            PricingOverrides::from_toml_str("[models.\"claude-sonnet-4\"]\noutput = 12").unwrap();
+        let mut models = table();
+        let dated = models["claude-sonnet-4"].clone();
+        models.insert("claude-sonnet-4-20250514".to_string(), dated.clone());
+        models.insert("claude-sonnet-4-5-20250929".to_string(), dated.clone());
+        overrides.apply(&mut models);
+
+        assert_rate(
+            models["claude-sonnet-4-20250514"].output_cost_per_token,
+            1.2e-5,
+        );
+        assert_rate(
+            models["claude-sonnet-4-20250514"].input_cost_per_token,
+            3e-6,
+        );
+        // Another model sharing the prefix is untouched
+        assert_rate(
+            models["claude-sonnet-4-5-20250929"].output_cost_per_token,
+            1.5e-5,
+        );
+
+        // Superseded rates of the dated ID get the override too
+        let mut old = dated;
+        overrides.adjust("claude-sonnet-4-20250514", &mut old);
+        assert_rate(old.output_cost_per_token, 1.2e-5);
+    }
+
+    #[test]
+    fn test_rate_override_replaces_tier_rates() {
+        let overrides = PricingOverrides::from_toml_str(
+            r#"
+            [models."claude-sonnet-4"]
+            input = 2
+            "#,
+        )
+        .unwrap();
+        let mut models = table();
+        overrides.apply(&mut models);
+
+        let sonnet = &models["claude-sonnet-4"];
+        assert_eq!(sonnet.tiers[0].input_cost_per_token, None);
+        let tier = sonnet.tier_for(300_000).unwrap();
+        assert_eq!(tier.threshold, 200_000);
+        // Long prompts fall back to the overridden base rate
+        assert_rate(
+            tier.input_cost_per_token.or(sonnet.input_cost_per_token),
+            2e-6,
+        );
+    }
+
+    #[test]
+    fn test_aliases() {
+        let overrides = PricingOverrides::from_toml_str(
+            r#"
+            [aliases]
+            "corp-sonnet" = "claude-sonnet-4-20250514"
+            "corp-missing" = "no-such-model"
+            "#,
+        )
+        .unwrap();
+        let mut models = table();
+        overrides.apply(&mut models);
+
+        assert_rate(models["corp-sonnet"].input_cost_per_token, 3e-6);
+        assert!(!models.contains_key("corp-missing"));
+    }
+
+    #[test]
+    fn test_discounts_first_match_wins() {
+        let overrides = PricingOverrides::from_toml_str(
+            r#"
+            [[discounts]]
+            model = "CLAUDE-*"
+            percent = 20
+
+            [[discounts]]
+            provider = "anthropic"
+            percent = 50
+
+            [[discounts]]
+            provider = "openai"
+            percent = 10
+            "#,
+        )
+        .unwrap();
+        let mut models = table();
+        overrides.apply(&mut models);
+
+        let sonnet = &models["claude-sonnet-4"];
+        assert_rate(sonnet.input_cost_per_token, 2.4e-6);
+        assert_rate(sonnet.cache_read_input_token_cost, 2.4e-7);
+        assert_rate(sonnet.tiers[0].input_cost_per_token, 4.8e-6);
+        assert_rate(models["gpt-5"].output_cost_per_token, 9e-6);
+    }
+
+    #[test]
+    fn test_discount_provider_is_a_provider_id() {
+        let overrides = PricingOverrides::from_toml_str(
+            r#"
+            [[discounts]]
+            provider = "google-vertex"
+            percent = 50
+            "#,
+        )
+        .unwrap();
+        let mut models = table();
+        models.insert(
+            "vertex_ai/gemini-2.5-pro".to_string(),
+            serde_json::from_str(
+                r#"{"input_cost_per_token": 1.25e-6,
+                    "litellm_provider": "vertex_ai-language-models"}"#,
+            )
+            .unwrap(),
+        );
+        overrides.apply(&mut models);
+
+        assert_rate(
+            models["vertex_ai/gemini-2.5-pro"].input_cost_per_token,
+            6.25e-7,
+        );
+        assert_rate(models["claude-sonnet-4"].input_cost_per_token, 3e-6);
+    }
+
+    #[test]
+    fn test_discount_applies_to_aliases() {
+        let overrides = PricingOverrides::from_toml_str(
+            r#"
+            [aliases]
+            "corp-sonnet" = "claude-sonnet-4"
+
+            [[discounts]]
+            provider = "anthropic"
+            percent = 50
+            "#,
+        )
+        .unwrap();
+        let mut models = table();
+        overrides.apply(&mut models);
+
+        assert_rate(models["corp-sonnet"].input_cost_per_token, 1.5e-6);
+        assert_rate(models["claude-sonnet-4"].input_cost_per_token, 1.5e-6);
+    }
+
+    #[test]
+    fn test_rejects_invalid_overlay() {
+        let cases = [
+            ("[models.x]\ninput = -1", "models.\"x\".input"),
+            ("[models.x]\nfoo = 1", "unknown field"),
+            ("[aliases]\nx = \"x\"", "aliases.\"x\""),
+            ("[[discounts]]\npercent = 0", "discounts[0]: percent"),
+            ("[[discounts]]\npercent = 150", "discounts[0]: percent"),
+            (
+                "[[discounts]]\nmodel = \"[\"\npercent = 5",
+                "invalid model pattern",
+            ),
+            ("[[discounts]]\nprovider = \"openai\"", "missing field"),
+        ];
+        for (toml, expected) in cases {
+            let err = PricingOverrides::from_toml_str(toml)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(expected), "{:?}: {}", toml, err);
+        }
+    }
+
+    #[test]
+    fn test_load_missing_file_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let overrides = PricingOverrides::load_from(&dir.path().join(PRICING_OVERRIDES_FILE));
+        assert!(overrides.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_load_error_mentions_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(PRICING_OVERRIDES_FILE);
+        std::fs::write(&path, "[[discounts]]\npercent = 0").unwrap();
+        let err = PricingOverrides::load_from(&path).unwrap_err().to_string();
+        assert!(err.contains(PRICING_OVERRIDES_FILE));
+        assert!(err.contains("discounts[0]"));
+    }
+}
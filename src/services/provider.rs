@@ -0,0 +1,263 @@
+//! Providers: the API that served a request, and how its usage is costed
+//!
+//! The same model can be billed by Anthropic, AWS Bedrock, Vertex AI, OpenRouter
+//! or a flat-rate subscription such as GitHub Copilot. Entries name their
+//! provider when the CLI records one (OpenCode does); otherwise it is inferred
+//! from a Bedrock or Vertex model ID, then from the source CLI's own API.
+//!
+//! Each provider has a `CostPolicy`, set under `[provider_costs]` in the config.
+
+use std::collections::BTreeMap;
+
+use crate::services::canonical_source;
+use crate::services::normalizer::is_bedrock_id;
+use crate::types::UsageEntry;
+
+/// Provider of entries that neither name one nor imply one
+pub const UNKNOWN_PROVIDER: &str = "unknown";
+
+/// How a provider's usage is costed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CostPolicy {
+    /// Use the cost the CLI reported, pricing from tokens when it reported none
+    #[default]
+    Auto,
+    /// Always price from tokens, ignoring any reported cost
+    Calculate,
+    /// Only trust the reported cost; usage without one stays unpriced
+    Reported,
+    /// Free or flat-rate subscription: always $0
+    Free,
+}
+
+impl CostPolicy {
+    /// Lower-case name as written in the config file
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Calculate => "calculate",
+            Self::Reported => "reported",
+            Self::Free => "free",
+        }
+    }
+
+    /// Whether costs under this policy come from token counts (and so can be repriced)
+    pub fn prices_tokens(self) -> bool {
+        matches!(self, Self::Auto | Self::Calculate)
+    }
+}
+
+impl std::str::FromStr for CostPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "auto" => Ok(Self::Auto),
+            "calculate" => Ok(Self::Calculate),
+            "reported" => Ok(Self::Reported),
+            "free" => Ok(Self::Free),
+            _ => Err(format!(
+                "invalid cost policy '{}' (expected auto, calculate, reported or free)",
+                s
+            )),
+        }
+    }
+}
+
+/// Built-in policies; GitHub Copilot usage is covered by the subscription
+const DEFAULT_COST_POLICIES: &[(&str, CostPolicy)] = &[
+    ("github-copilot", CostPolicy::Free),
+    ("github-copilot-enterprise", CostPolicy::Free),
+];
+
+/// Cost policy per provider name; unlisted providers use `CostPolicy::Auto`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CostPolicies(BTreeMap<String, CostPolicy>);
+
+impl Default for CostPolicies {
+    fn default() -> Self {
+        Self(
+            DEFAULT_COST_POLICIES
+                .iter()
+                .map(|(provider, policy)| (provider.to_string(), *policy))
+                .collect(),
+        )
+    }
+}
+
+impl CostPolicies {
+    pub fn get(&self, provider: &str) -> CostPolicy {
+        self.0
+            .get(&provider.to_ascii_lowercase())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn set(&mut self, provider: &str, policy: CostPolicy) {
+        self.0.insert(provider.to_ascii_lowercase(), policy);
+    }
+
+    /// Providers with a policy, by name
+    pub fn iter(&self) -> impl Iterator<Item = (&str, CostPolicy)> {
+        self.0
+            .iter()
+            .map(|(provider, policy)| (provider.as_str(), *policy))
+    }
+}
+
+/// Provider that served `entry`, lower-cased
+pub fn provider_of(entry: &UsageEntry) -> String {
+    if let Some(provider) = entry
+        .provider
+        .as_deref()
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+    {
+        return provider.to_ascii_lowercase();
+    }
+    entry
+        .model
+        .as_deref()
+        .and_then(model_provider)
+        .or_else(|| source_provider(entry.source.as_deref().unwrap_or("")))
+        .unwrap_or(UNKNOWN_PROVIDER)
+        .to_string()
+}
+
+/// Provider implied by a Bedrock (`anthropic.claude-…-v1:0`) or Vertex (`model@version`) ID
+fn model_provider(model: &str) -> Option<&'static str> {
+    if is_bedrock_id(model) {
+        Some("amazon-bedrock")
+    } else if model.contains('@') {
+        Some("google-vertex")
+    } else {
+        None
+    }
+}
+
+/// First-party API of a source CLI, for entries that don't name a provider
+pub fn source_provider(source: &str) -> Option<&'static str> {
+    match canonical_source(source).as_str() {
+        "claude-code" => Some("anthropic"),
+        "codex" => Some("openai"),
+        "gemini" => Some("google"),
+        _ => None,
+    }
+}
+
+/// Provider ID of a LiteLLM `litellm_provider` value (e.g. "vertex_ai-language-models"
+/// is "google-vertex"); providers named the same way in both are passed through
+pub fn litellm_provider_id(litellm_provider: &str) -> String {
+    let litellm_provider = litellm_provider.trim().to_ascii_lowercase();
+    let id = if litellm_provider.starts_with("vertex_ai") {
+        "google-vertex"
+    } else if litellm_provider.starts_with("bedrock") {
+        "amazon-bedrock"
+    } else {
+        match litellm_provider.as_str() {
+            "gemini" => "google",
+            "text-completion-openai" => "openai",
+            _ => return litellm_provider,
+        }
+    };
+    id.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TokenCosts;
+
+    fn entry(source: &str, model: &str, provider: Option<&str>) -> UsageEntry {
+        UsageEntry {
+            timestamp: chrono::Utc::now(),
+            model: Some(model.to_string()),
+            input_tokens: 0,
+            output_tokens: 0,
+            cache_read_tokens: 0,
+            cache_creation_tokens: 0,
+            cache_creation_1h_tokens: 0,
+            thinking_tokens: 0,
+            cost_usd: None,
+            message_id: None,
+            request_id: None,
+            source: Some(source.to_string()),
+            provider: provider.map(String::from),
+            project: None,
+            session_id: None,
+            web_search_requests: 0,
+            service_tier: None,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            token_costs: TokenCosts::default(),
+            long_context_tier: None,
+            cost_reported: false,
+        }
+    }
+
+    #[test]
+    fn test_provider_of_prefers_recorded_provider() {
+        let e = entry("opencode", "claude-sonnet-4", Some("GitHub-Copilot"));
+        assert_eq!(provider_of(&e), "github-copilot");
+        let e = entry("opencode", "claude-sonnet-4", Some(" "));
+        assert_eq!(provider_of(&e), UNKNOWN_PROVIDER);
+    }
+
+    #[test]
+    fn test_provider_of_infers_from_model_then_source() {
+        let bedrock = entry("claude", "us.anthropic.claude-opus-4-1-20250805-v1:0", None);
+        assert_eq!(provider_of(&bedrock), "amazon-bedrock");
+        let vertex = entry("claude", "claude-sonnet-4@20250514", None);
+        assert_eq!(provider_of(&vertex), "google-vertex");
+        assert_eq!(
+            provider_of(&entry("claude", "claude-sonnet-4", None)),
+            "anthropic"
+        );
+        assert_eq!(provider_of(&entry("codex", "gpt-5", None)), "openai");
+        assert_eq!(
+            provider_of(&entry("gemini", "gemini-2.5-pro", None)),
+            "google"
+        );
+    }
+
+    #[test]
+    fn test_default_policies_make_copilot_free() {
+        let policies = CostPolicies::default();
+        assert_eq!(policies.get("github-copilot"), CostPolicy::Free);
+        assert_eq!(policies.get("github-copilot-enterprise"), CostPolicy::Free);
+        assert_eq!(policies.get("anthropic"), CostPolicy::Auto);
+        assert_eq!(policies.get(""), CostPolicy::Auto);
+    }
+
+    #[test]
+    fn test_parse_cost_policy() {
+        assert_eq!("Reported".parse(), Ok(CostPolicy::Reported));
+        assert_eq!(" free ".parse(), Ok(CostPolicy::Free));
+        assert!("cheap".parse::<CostPolicy>().is_err());
+        for policy in [
+            CostPolicy::Auto,
+            CostPolicy::Calculate,
+            CostPolicy::Reported,
+            CostPolicy::Free,
+        ] {
+            assert_eq!(policy.name().parse(), Ok(policy));
+        }
+    }
+
+    #[test]
+    fn test_litellm_provider_id() {
+        assert_eq!(litellm_provider_id("anthropic"), "anthropic");
+        assert_eq!(litellm_provider_id("openai"), "openai");
+        assert_eq!(litellm_provider_id("gemini"), "google");
+        assert_eq!(
+            litellm_provider_id("vertex_ai-language-models"),
+            "google-vertex"
+        );
+        assert_eq!(
+            litellm_provider_id("vertex_ai-anthropic_models"),
+            "google-vertex"
+        );
+        assert_eq!(litellm_provider_id("bedrock_converse"), "amazon-bedrock");
+        assert_eq!(litellm_provider_id("OpenRouter"), "openrouter");
+    }
+}
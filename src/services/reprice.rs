@@ -0,0 +1,435 @@
+//! Re-cost cached daily summaries from their token counts
+//!
+//! `toktrack reprice` runs cached days through the current pricing (history,
+//! overrides and all) so fixes apply to past days whose logs may be gone.
+//! Models without pricing keep their recorded cost, as does usage from
+//! providers whose cost policy isn't token-based (free or reported costs) and
+//! usage whose cost the tokens can't reproduce: costs the source reported and
+//! long-context requests priced before their tier existed (or, for days cached
+//! before prompt sizes were recorded, any usage of a model with tiers).
+//! Repriced models also get their cost split by token type, which fills it in
+//! for days cached before the split was recorded.
+//! Per-project costs aren't broken down by model, so they are scaled by the
+//! day's change in total cost.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::services::aggregator::merge_model_usage;
+use crate::services::provider::CostPolicies;
+use crate::services::{PricingService, UsageFilter};
+use crate::types::{DailySummary, ModelUsage, TokenCosts};
+
+/// Cost of one model over the repriced days
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct CostChange {
+    pub before_usd: f64,
+    pub after_usd: f64,
+    /// Days on which the model was repriced
+    pub days: u32,
+}
+
+impl CostChange {
+    pub fn delta_usd(&self) -> f64 {
+        self.after_usd - self.before_usd
+    }
+}
+
+/// Recompute model costs for the days, models and providers the filter allows,
+/// adding each repriced model's before/after cost to `changes`
+pub fn reprice_summaries(
+    summaries: &mut [DailySummary],
+    pricing: &PricingService,
+    filter: &UsageFilter,
+    policies: &CostPolicies,
+    changes: &mut BTreeMap<String, CostChange>,
+) {
+    for summary in summaries
+        .iter_mut()
+        .filter(|s| filter.date_range.contains(s.date))
+    {
+        let before_total = summary.total_cost_usd;
+        let mut repriced = BTreeSet::new();
+
+        if summary.providers.is_empty() {
+            for (model, usage) in summary.models.iter_mut() {
+                if !filter.matches_model(model) {
+                    continue;
+                }
+                if let Some(delta) =
+                    reprice_usage(pricing, model, None, summary.date, usage, changes)
+                {
+                    summary.total_cost_usd += delta;
+                    repriced.insert(model.clone());
+                }
+            }
+        } else {
+            for (provider, models) in summary.providers.iter_mut() {
+                if !filter.matches_provider(provider) || !policies.get(provider).prices_tokens() {
+                    continue;
+                }
+                for (model, usage) in models.iter_mut() {
+                    if !filter.matches_model(model) {
+                        continue;
+                    }
+                    let delta =
+                        reprice_usage(pricing, model, Some(provider), summary.date, usage, changes);
+                    if let Some(delta) = delta {
+                        summary.total_cost_usd += delta;
+                        repriced.insert(model.clone());
+                    }
+                }
+            }
+            // A model's costs are the sum of its providers'
+            for model in &repriced {
+                let mut total = ModelUsage::default();
+                for usage in summary.providers.values().filter_map(|m| m.get(model)) {
+                    merge_model_usage(&mut total, usage);
+                }
+                if let Some(usage) = summary.models.get_mut(model) {
+                    usage.cost_usd = total.cost_usd;
+                    usage.web_search_cost_usd = total.web_search_cost_usd;
+                    usage.service_tier_cost_usd = total.service_tier_cost_usd;
+                    usage.unpriced_tokens = total.unpriced_tokens;
+                    usage.token_costs = total.token_costs;
+                }
+            }
+        }
+
+        if !repriced.is_empty() {
+            summary.total_token_costs = TokenCosts::default();
+            for usage in summary.models.values() {
+                summary.total_token_costs += usage.token_costs;
+            }
+        }
+
+        for model in repriced {
+            changes.entry(model).or_default().days += 1;
+        }
+
+        if before_total > 0.0 {
+            let ratio = summary.total_cost_usd / before_total;
+            summary
+                .projects
+                .values_mut()
+                .for_each(|p| scale_costs(p, ratio));
+        }
+    }
+}
+
+/// Reprice one model's usage in place, adding its before/after cost to `changes`.
+/// Returns the change in cost, or `None` if the model is unpriced or its cost
+/// can't be reproduced.
+fn reprice_usage(
+    pricing: &PricingService,
+    model: &str,
+    provider: Option<&str>,
+    date: NaiveDate,
+    usage: &mut ModelUsage,
+    changes: &mut BTreeMap<String, CostChange>,
+) -> Option<f64> {
+    let charges = pricing.calculate_usage_charges(model, provider, date, usage)?;
+    let after = charges.total();
+    let change = changes.entry(model.to_string()).or_default();
+    change.before_usd += usage.cost_usd;
+    change.after_usd += after;
+
+    let delta = after - usage.cost_usd;
+    usage.cost_usd = after;
+    usage.web_search_cost_usd = charges.web_search_usd;
+    usage.service_tier_cost_usd = charges.service_tier_usd;
+    usage.unpriced_tokens = 0;
+    usage.token_costs = charges.token_costs;
+    Some(delta)
+}
+
+fn scale_costs(usage: &mut ModelUsage, ratio: f64) {
+    usage.cost_usd *= ratio;
+    usage.web_search_cost_usd *= ratio;
+    usage.service_tier_cost_usd *= ratio;
+    usage.token_costs = usage.token_costs.scaled(ratio);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::pricing::{ModelPricing, PricingCache, PricingTier, PRICING_CACHE_SCHEMA};
+    use crate::services::DateRange;
+    use crate::types::TierTokens;
+    use chrono::NaiveDate;
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    fn date(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 9, d).unwrap()
+    }
+
+    /// gpt-5 at $1/1M input and $10/1M output; claude-sonnet-4-5 at $3/$15,
+    /// or $6/$22.50 for prompts above 200K
+    fn pricing(temp_dir: &TempDir) -> PricingService {
+        let cache_path = temp_dir.path().join("pricing.json");
+        let cache = PricingCache {
+            fetched_at: 0,
+            schema: PRICING_CACHE_SCHEMA,
+            models: HashMap::from([
+                (
+                    "gpt-5".to_string(),
+                    ModelPricing {
+                        input_cost_per_token: Some(1e-6),
+                        output_cost_per_token: Some(1e-5),
+                        ..ModelPricing::default()
+                    },
+                ),
+                (
+                    "claude-sonnet-4-5".to_string(),
+                    ModelPricing {
+                        input_cost_per_token: Some(3e-6),
+                        output_cost_per_token: Some(1.5e-5),
+                        tiers: vec![PricingTier {
+                            threshold: 200_000,
+                            input_cost_per_token: Some(6e-6),
+                            output_cost_per_token: Some(2.25e-5),
+                            ..PricingTier::default()
+                        }],
+                        ..ModelPricing::default()
+                    },
+                ),
+            ]),
+        };
+        std::fs::write(&cache_path, serde_json::to_string(&cache).unwrap()).unwrap();
+        PricingService::from_cache_only_with_path(&cache_path).unwrap()
+    }
+
+    fn usage(input: u64, output: u64, cost_usd: f64) -> ModelUsage {
+        ModelUsage {
+            input_tokens: input,
+            output_tokens: output,
+            cost_usd,
+            count: 1,
+            ..ModelUsage::default()
+        }
+    }
+
+    fn summary(day: u32) -> DailySummary {
+        DailySummary {
+            date: date(day),
+            total_input_tokens: 2_000_000,
+            total_output_tokens: 100_000,
+            total_cache_read_tokens: 0,
+            total_cache_creation_tokens: 0,
+            total_thinking_tokens: 0,
+            total_cost_usd: 5.0,
+            total_token_costs: TokenCosts::default(),
+            models: HashMap::from([
+                ("gpt-5".to_string(), usage(1_000_000, 100_000, 1.0)),
+                ("internal-llm".to_string(), usage(1_000_000, 0, 4.0)),
+            ]),
+            projects: HashMap::from([("/work/app".to_string(), usage(2_000_000, 100_000, 5.0))]),
+            providers: HashMap::new(),
+            sources: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_reprices_known_models_and_keeps_unpriced() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut summaries = vec![summary(1), summary(2)];
+        // Priced since the day was cached
+        summaries[1]
+            .models
+            .get_mut("gpt-5")
+            .unwrap()
+            .unpriced_tokens = 1_100_000;
+        let mut changes = BTreeMap::new();
+
+        reprice_summaries(
+            &mut summaries,
+            &pricing(&temp_dir),
+            &UsageFilter::default(),
+            &CostPolicies::default(),
+            &mut changes,
+        );
+
+        // gpt-5: 1M input ($1) + 100K output ($1) = $2 per day
+        assert_eq!(summaries[0].models["gpt-5"].cost_usd, 2.0);
+        assert_eq!(summaries[0].models["internal-llm"].cost_usd, 4.0);
+        assert_eq!(summaries[0].total_cost_usd, 6.0);
+        assert_eq!(summaries[0].projects["/work/app"].cost_usd, 6.0);
+        assert_eq!(summaries[1].models["gpt-5"].unpriced_tokens, 0);
+        // Repricing also fills in the cost split by token type
+        let split = TokenCosts {
+            input_usd: 1.0,
+            output_usd: 1.0,
+            ..TokenCosts::default()
+        };
+        assert_eq!(summaries[0].models["gpt-5"].token_costs, split);
+        assert_eq!(summaries[0].total_token_costs, split);
+
+        assert_eq!(changes.len(), 1);
+        let gpt = &changes["gpt-5"];
+        assert_eq!((gpt.before_usd, gpt.after_usd, gpt.days), (2.0, 4.0, 2));
+        assert_eq!(gpt.delta_usd(), 2.0);
+    }
+
+    #[test]
+    fn test_respects_date_range_and_model_filter() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut summaries = vec![summary(1), summary(2)];
+        let mut changes = BTreeMap::new();
+        let filter = UsageFilter {
+            date_range: DateRange {
+                since: Some(date(2)),
+                until: None,
+            },
+            sources: None,
+            models: Some(vec!["claude-*".into()]),
+            providers: None,
+        };
+
+        reprice_summaries(
+            &mut summaries,
+            &pricing(&temp_dir),
+            &filter,
+            &CostPolicies::default(),
+            &mut changes,
+        );
+        assert!(changes.is_empty());
+        assert_eq!(summaries[1].total_cost_usd, 5.0);
+
+        let filter = UsageFilter {
+            models: None,
+            ..filter
+        };
+        reprice_summaries(
+            &mut summaries,
+            &pricing(&temp_dir),
+            &filter,
+            &CostPolicies::default(),
+            &mut changes,
+        );
+        assert_eq!(summaries[0].total_cost_usd, 5.0);
+        assert_eq!(summaries[1].total_cost_usd, 6.0);
+        assert_eq!(changes["gpt-5"].days, 1);
+    }
+
+    #[test]
+    fn test_reprices_per_provider_and_skips_free_providers() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut day = summary(1);
+        day.models
+            .insert("gpt-5".to_string(), usage(2_000_000, 200_000, 1.0));
+        day.providers = HashMap::from([
+            (
+                "openai".to_string(),
+                HashMap::from([("gpt-5".to_string(), usage(1_000_000, 100_000, 1.0))]),
+            ),
+            (
+                "github-copilot".to_string(),
+                HashMap::from([("gpt-5".to_string(), usage(1_000_000, 100_000, 0.0))]),
+            ),
+        ]);
+        let mut summaries = vec![day];
+        let mut changes = BTreeMap::new();
+
+        reprice_summaries(
+            &mut summaries,
+            &pricing(&temp_dir),
+            &UsageFilter::default(),
+            &CostPolicies::default(),
+            &mut changes,
+        );
+
+        // Only the OpenAI half is repriced ($1 -> $2); Copilot stays free
+        let day = &summaries[0];
+        assert_eq!(day.providers["openai"]["gpt-5"].cost_usd, 2.0);
+        assert_eq!(day.providers["github-copilot"]["gpt-5"].cost_usd, 0.0);
+        assert_eq!(day.models["gpt-5"].cost_usd, 2.0);
+        assert_eq!(day.total_cost_usd, 6.0);
+        let gpt = &changes["gpt-5"];
+        assert_eq!((gpt.before_usd, gpt.after_usd, gpt.days), (1.0, 2.0, 1));
+    }
+
+    #[test]
+    fn test_reprices_long_context_tokens_at_their_tier() {
+        let temp_dir = TempDir::new().unwrap();
+        let tiered = ModelUsage {
+            max_prompt_tokens: Some(250_000),
+            tier_tokens: BTreeMap::from([(
+                200_000,
+                TierTokens {
+                    input_tokens: 600_000,
+                    output_tokens: 30_000,
+                    ..TierTokens::default()
+                },
+            )]),
+            ..usage(1_000_000, 100_000, 1.0)
+        };
+        let mut day = summary(1);
+        day.models
+            .insert("claude-sonnet-4-5".to_string(), tiered.clone());
+        // Same tokens, but the tier didn't exist when the day was priced
+        let mut untiered_day = summary(2);
+        untiered_day.models.insert(
+            "claude-sonnet-4-5".to_string(),
+            ModelUsage {
+                tier_tokens: BTreeMap::new(),
+                ..tiered.clone()
+            },
+        );
+        // Cached before prompt sizes were recorded
+        let mut old_day = summary(3);
+        old_day.models.insert(
+            "claude-sonnet-4-5".to_string(),
+            ModelUsage {
+                max_prompt_tokens: None,
+                tier_tokens: BTreeMap::new(),
+                ..tiered
+            },
+        );
+        let mut summaries = vec![day, untiered_day, old_day];
+        let mut changes = BTreeMap::new();
+
+        reprice_summaries(
+            &mut summaries,
+            &pricing(&temp_dir),
+            &UsageFilter {
+                models: Some(vec!["claude-*".into()]),
+                ..UsageFilter::default()
+            },
+            &CostPolicies::default(),
+            &mut changes,
+        );
+
+        // Base: 400K input ($1.20) + 70K output ($1.05)
+        // Tier: 600K input ($3.60) + 30K output ($0.675)
+        let usage = &summaries[0].models["claude-sonnet-4-5"];
+        assert!((usage.cost_usd - 6.525).abs() < 1e-9);
+        assert!((usage.token_costs.input_usd - 4.8).abs() < 1e-9);
+        assert_eq!(summaries[1].models["claude-sonnet-4-5"].cost_usd, 1.0);
+        assert_eq!(summaries[2].models["claude-sonnet-4-5"].cost_usd, 1.0);
+        assert_eq!(changes["claude-sonnet-4-5"].days, 1);
+    }
+
+    #[test]
+    fn test_keeps_costs_reported_by_the_source() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut day = summary(1);
+        day.models.get_mut("gpt-5").unwrap().cost_reported = true;
+        let mut summaries = vec![day];
+        let mut changes = BTreeMap::new();
+
+        reprice_summaries(
+            &mut summaries,
+            &pricing(&temp_dir),
+            &UsageFilter::default(),
+            &CostPolicies::default(),
+            &mut changes,
+        );
+
+        assert_eq!(summaries[0].models["gpt-5"].cost_usd, 1.0);
+        assert_eq!(summaries[0].total_cost_usd, 5.0);
+        assert!(changes.is_empty());
+    }
+}
@@ -10,22 +10,28 @@ use ratatui::{
     buffer::Buffer, layout::Rect, style::Style, widgets::Widget, DefaultTerminal, Frame,
 };
 
-use super::theme::Theme;
+use super::theme::{SpikeThresholds, Theme};
 
 use crate::services::update_checker::{check_for_update, execute_update, UpdateCheckResult};
-use crate::services::{Aggregator, DataLoaderService};
-use crate::types::{CacheWarning, SourceUsage, StatsData, TotalSummary};
+use crate::services::{
+    budget, forecast, Aggregator, BudgetStatus, Config, DataLoaderService, DateRange, UsageFilter,
+};
+use crate::types::{
+    CacheWarning, DailySummary, PricingInfo, SessionSummary, SourceUsage, StatsData, TotalSummary,
+};
 
 use super::widgets::{
     daily::{DailyData, DailyView, DailyViewMode},
+    filter_popup::{FilterPopup, FilterState},
     help::HelpPopup,
     model_breakdown::{ModelBreakdownPopup, ModelBreakdownState},
     models::{ModelsData, ModelsView},
     overview::{Overview, OverviewData},
     quit_confirm::{QuitConfirmPopup, QuitConfirmState},
+    sessions::{SessionsData, SessionsView},
     spinner::{LoadingStage, Spinner},
     stats::StatsView,
-    tabs::Tab,
+    tabs::{Tab, UnpricedBadge},
     update_popup::{DimOverlay, UpdateMessagePopup, UpdatePopup},
 };
 
@@ -34,6 +40,10 @@ use super::widgets::{
 pub struct TuiConfig {
     pub initial_tab: Tab,
     pub initial_view_mode: DailyViewMode,
+    /// Initial date range / source / model filter for every view
+    pub filter: UsageFilter,
+    /// Settings from ~/.toktrack/config.toml
+    pub user_config: Config,
 }
 
 /// Application state
@@ -54,10 +64,16 @@ pub struct AppData {
     pub total: TotalSummary,
     pub daily_tokens: Vec<(NaiveDate, u64)>,
     pub models_data: ModelsData,
+    /// Per-project totals, shown on the Models tab with `p`
+    pub projects_data: ModelsData,
     pub daily_data: DailyData,
     pub stats_data: StatsData,
     /// Usage breakdown by source CLI
     pub source_usage: Vec<SourceUsage>,
+    /// Unfiltered per-source summaries, kept so filters can be re-applied without reloading
+    pub source_summaries: Vec<(String, Vec<DailySummary>)>,
+    /// Configured budgets evaluated for the current periods
+    pub budget_status: Vec<BudgetStatus>,
     /// Cache warning indicator for display in TUI
     #[allow(dead_code)] // Reserved for warning indicator feature
     pub cache_warning: Option<CacheWarning>,
@@ -105,6 +121,8 @@ pub struct App {
     weekly_selected: Option<usize>,
     monthly_selected: Option<usize>,
     daily_view_mode: DailyViewMode,
+    /// Models tab lists projects instead of models
+    show_projects: bool,
     show_help: bool,
     update_status: UpdateStatus,
     update_selection: u8, // 0 = Update now, 1 = Skip
@@ -112,6 +130,16 @@ pub struct App {
     theme: Theme,
     quit_confirm: Option<QuitConfirmState>,
     model_breakdown: Option<ModelBreakdownState>,
+    /// Per-session data, loaded separately since it needs raw entries
+    sessions_data: Option<SessionsData>,
+    /// Whether session loading has been started (on first visit to the Sessions tab)
+    sessions_requested: bool,
+    sessions_scroll: usize,
+    /// Unfiltered sessions, re-filtered whenever the filter changes
+    sessions: Vec<SessionSummary>,
+    filter: UsageFilter,
+    filter_popup: Option<FilterState>,
+    user_config: Config,
 }
 
 impl App {
@@ -131,6 +159,7 @@ impl App {
             weekly_selected: None,
             monthly_selected: None,
             daily_view_mode: config.initial_view_mode,
+            show_projects: false,
             show_help: false,
             update_status: UpdateStatus::Checking,
             update_selection: 0,
@@ -138,6 +167,13 @@ impl App {
             theme,
             quit_confirm: None,
             model_breakdown: None,
+            sessions_data: None,
+            sessions_requested: false,
+            sessions_scroll: 0,
+            sessions: Vec::new(),
+            filter: config.filter,
+            filter_popup: None,
+            user_config: config.user_config,
         }
     }
 
@@ -188,12 +224,10 @@ impl App {
                 }
 
                 match key.code {
-                    KeyCode::Esc => {
-                        // Esc only closes popups (e.g., help), does not trigger quit
-                        if self.show_help {
-                            self.show_help = false;
-                        }
-                        // If no popup is open, Esc does nothing
+                    // Esc only closes popups (e.g., help), does not trigger quit.
+                    // If no popup is open, Esc does nothing
+                    KeyCode::Esc if self.show_help => {
+                        self.show_help = false;
                     }
                     KeyCode::Tab => {
                         self.current_tab = self.current_tab.next();
@@ -207,12 +241,10 @@ impl App {
                     KeyCode::Down | KeyCode::Char('j') => {
                         self.select_next();
                     }
-                    KeyCode::Enter => {
-                        if self.current_tab == Tab::Daily {
-                            self.open_model_breakdown();
-                        }
+                    KeyCode::Enter if self.current_tab == Tab::Daily => {
+                        self.open_model_breakdown();
                     }
-                    KeyCode::Char(c @ '1'..='4') => {
+                    KeyCode::Char(c @ '1'..='5') => {
                         if let Some(tab) = Tab::from_number(c as u8 - b'0') {
                             self.current_tab = tab;
                         }
@@ -220,6 +252,9 @@ impl App {
                     KeyCode::Char('?') => {
                         self.show_help = !self.show_help;
                     }
+                    KeyCode::Char('f') => {
+                        self.open_filter_popup();
+                    }
                     KeyCode::Char('d') if self.current_tab == Tab::Daily => {
                         self.daily_view_mode = DailyViewMode::Daily;
                     }
@@ -229,6 +264,9 @@ impl App {
                     KeyCode::Char('m') if self.current_tab == Tab::Daily => {
                         self.daily_view_mode = DailyViewMode::Monthly;
                     }
+                    KeyCode::Char('p') if self.current_tab == Tab::Models => {
+                        self.show_projects = !self.show_projects;
+                    }
                     _ => {}
                 }
             }
@@ -285,6 +323,36 @@ impl App {
         }
     }
 
+    /// Handle keyboard events when filter popup is displayed.
+    /// Toggles apply immediately so every tab re-renders behind the popup.
+    pub fn handle_filter_event(&mut self, event: Event) {
+        if let Event::Key(key) = event {
+            if key.kind == KeyEventKind::Press {
+                let Some(state) = self.filter_popup.as_mut() else {
+                    return;
+                };
+                match key.code {
+                    KeyCode::Esc | KeyCode::Char('f') | KeyCode::Char('q') => {
+                        self.filter_popup = None;
+                    }
+                    KeyCode::Up | KeyCode::Char('k') => state.move_up(),
+                    KeyCode::Down | KeyCode::Char('j') => state.move_down(),
+                    KeyCode::Char(' ') | KeyCode::Enter => {
+                        state.toggle();
+                        let filter = state.to_filter(&self.filter);
+                        self.set_filter(filter);
+                    }
+                    KeyCode::Char('a') => {
+                        state.enable_all();
+                        let filter = state.to_filter(&self.filter);
+                        self.set_filter(filter);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
     /// Handle keyboard events when update overlay is displayed
     pub fn handle_update_event(&mut self, event: Event) {
         if let Event::Key(key) = event {
@@ -345,8 +413,103 @@ impl App {
         }
     }
 
+    /// Whether session loading should start now: true once, on the first
+    /// frame the Sessions tab is shown
+    fn take_sessions_request(&mut self) -> bool {
+        if self.current_tab != Tab::Sessions || self.sessions_requested {
+            return false;
+        }
+        self.sessions_requested = true;
+        true
+    }
+
+    /// Apply session loading result (errors fall back to an empty list)
+    fn apply_sessions_result(&mut self, result: Result<Vec<SessionSummary>, String>) {
+        self.sessions = result.unwrap_or_default();
+        self.rebuild_sessions();
+    }
+
+    /// Rebuild the Sessions tab from the unfiltered sessions and the current filter
+    fn rebuild_sessions(&mut self) {
+        let filtered: Vec<SessionSummary> = self
+            .sessions
+            .iter()
+            .filter_map(|s| self.filter.restrict_session(s))
+            .collect();
+        self.sessions_scroll = 0;
+        self.sessions_data = Some(SessionsData::from_sessions(&filtered));
+    }
+
+    /// Open the filter popup with every source and model seen in the loaded data
+    fn open_filter_popup(&mut self) {
+        let AppState::Ready { data } = &self.state else {
+            return;
+        };
+        let mut sources = Vec::new();
+        let mut models = std::collections::BTreeSet::new();
+        for (source, summaries) in &data.source_summaries {
+            if summaries.is_empty() {
+                continue;
+            }
+            sources.push(source.clone());
+            for summary in summaries {
+                models.extend(summary.models.keys().cloned());
+            }
+        }
+        sources.sort();
+        let models: Vec<String> = models.into_iter().collect();
+        self.filter_popup = Some(FilterState::new(&sources, &models, &self.filter));
+    }
+
+    /// Replace the active filter and rebuild every view from the retained per-source data
+    fn set_filter(&mut self, filter: UsageFilter) {
+        self.filter = filter;
+
+        if let AppState::Ready { data } = &mut self.state {
+            let source_summaries = std::mem::take(&mut data.source_summaries);
+            let cache_warning = data.cache_warning.take();
+            let pricing = data.stats_data.pricing;
+            let (summaries, source_usage) =
+                DataLoaderService::apply_filter(&source_summaries, &self.filter);
+            let result = build_app_data_from_summaries(
+                summaries,
+                source_usage,
+                source_summaries,
+                cache_warning,
+                pricing,
+                &self.filter.date_range,
+                &self.user_config,
+            );
+            self.daily_selected = None;
+            self.weekly_selected = None;
+            self.monthly_selected = None;
+            self.apply_data_result(result);
+        }
+
+        if self.sessions_data.is_some() {
+            self.rebuild_sessions();
+        }
+    }
+
+    /// Scroll the Sessions tab by one row in the given direction
+    fn scroll_sessions(&mut self, down: bool) {
+        let max = self
+            .sessions_data
+            .as_ref()
+            .map_or(0, SessionsData::max_scroll_offset);
+        self.sessions_scroll = if down {
+            (self.sessions_scroll + 1).min(max)
+        } else {
+            self.sessions_scroll.saturating_sub(1)
+        };
+    }
+
     /// Select previous row (move up) in Daily tab
     fn select_prev(&mut self) {
+        if self.current_tab == Tab::Sessions {
+            self.scroll_sessions(false);
+            return;
+        }
         if self.current_tab != Tab::Daily {
             return;
         }
@@ -379,6 +542,10 @@ impl App {
 
     /// Select next row (move down) in Daily tab
     fn select_next(&mut self) {
+        if self.current_tab == Tab::Sessions {
+            self.scroll_sessions(true);
+            return;
+        }
         if self.current_tab != Tab::Daily {
             return;
         }
@@ -453,14 +620,17 @@ impl App {
                     DailyViewMode::Monthly => summary.date.format("%Y-%m").to_string(),
                 };
 
-                // Collect models as Vec
-                let models: Vec<_> = summary
-                    .models
-                    .iter()
-                    .map(|(k, v)| (k.clone(), v.clone()))
-                    .collect();
-
-                self.model_breakdown = Some(ModelBreakdownState::new(date_label, models));
+                // Split models by source CLI when the summary knows them
+                self.model_breakdown = Some(if summary.sources.is_empty() {
+                    let models: Vec<_> = summary
+                        .models
+                        .iter()
+                        .map(|(k, v)| (k.clone(), v.clone()))
+                        .collect();
+                    ModelBreakdownState::new(date_label, models)
+                } else {
+                    ModelBreakdownState::from_sources(date_label, &summary.sources)
+                });
             }
         }
     }
@@ -515,14 +685,21 @@ impl Widget for &App {
                             total: &data.total,
                             daily_tokens: &data.daily_tokens,
                             source_usage: &data.source_usage,
+                            budgets: &data.budget_status,
                         };
                         let overview = Overview::new(overview_data, today, self.theme)
                             .with_tab(self.current_tab);
                         overview.render(area, buf);
                     }
                     Tab::Models => {
-                        let models_view = ModelsView::new(&data.models_data, self.theme)
-                            .with_tab(self.current_tab);
+                        let models_data = if self.show_projects {
+                            &data.projects_data
+                        } else {
+                            &data.models_data
+                        };
+                        let models_view = ModelsView::new(models_data, self.theme)
+                            .with_tab(self.current_tab)
+                            .with_projects(self.show_projects);
                         models_view.render(area, buf);
                     }
                     Tab::Daily => {
@@ -534,7 +711,8 @@ impl Widget for &App {
                             data.stats_data.daily_avg_cost,
                         )
                         .with_tab(self.current_tab)
-                        .with_selected_index(self.active_selected());
+                        .with_selected_index(self.active_selected())
+                        .with_spike_thresholds(SpikeThresholds::from_config(&self.user_config));
                         daily_view.render(area, buf);
                     }
                     Tab::Stats => {
@@ -542,14 +720,33 @@ impl Widget for &App {
                             StatsView::new(&data.stats_data, self.theme).with_tab(self.current_tab);
                         stats_view.render(area, buf);
                     }
+                    Tab::Sessions => {
+                        let sessions_view = SessionsView::new(
+                            self.sessions_data.as_ref(),
+                            self.sessions_scroll,
+                            self.theme,
+                        )
+                        .with_tab(self.current_tab);
+                        sessions_view.render(area, buf);
+                    }
                 }
 
+                UnpricedBadge::new(data.stats_data.unpriced.models.len(), self.theme)
+                    .render(area, buf);
+
                 // Render help popup overlay if active
                 if self.show_help {
                     let popup_area = HelpPopup::centered_area(area);
                     HelpPopup::new(self.theme).render(popup_area, buf);
                 }
 
+                // Render filter popup if active
+                if let Some(ref state) = self.filter_popup {
+                    DimOverlay.render(area, buf);
+                    let popup_area = FilterPopup::centered_area(area, state.items.len());
+                    FilterPopup::new(state, self.theme).render(popup_area, buf);
+                }
+
                 // Render model breakdown popup if active
                 if let Some(ref state) = self.model_breakdown {
                     DimOverlay.render(area, buf);
@@ -613,18 +810,51 @@ pub fn run(config: TuiConfig) -> anyhow::Result<()> {
 
 /// Load data synchronously (extracted for background thread).
 /// Uses cache-first strategy via DataLoaderService.
-fn load_data_sync() -> Result<Box<AppData>, String> {
-    let result = DataLoaderService::new().load().map_err(|e| e.to_string())?;
+fn load_data_sync(filter: UsageFilter, user_config: &Config) -> Result<Box<AppData>, String> {
+    let date_range = filter.date_range;
+    let result = DataLoaderService::from_config(user_config)
+        .with_filter(filter)
+        .load()
+        .map_err(|e| e.to_string())?;
+
+    build_app_data_from_summaries(
+        result.summaries,
+        result.source_usage,
+        result.source_summaries,
+        result.cache_warning,
+        result.pricing,
+        &date_range,
+        user_config,
+    )
+}
 
-    build_app_data_from_summaries(result.summaries, result.source_usage, result.cache_warning)
+/// Load per-session data (parses raw entries, so slower than the cached daily load).
+/// Only the date range is applied; source/model filters are applied in the app.
+fn load_sessions_sync(
+    filter: UsageFilter,
+    user_config: &Config,
+) -> Result<Vec<SessionSummary>, String> {
+    let entries = DataLoaderService::from_config(user_config)
+        .with_filter(UsageFilter {
+            date_range: filter.date_range,
+            ..UsageFilter::default()
+        })
+        .load_entries()
+        .map_err(|e| e.to_string())?;
+    Ok(Aggregator::by_session(&entries))
 }
 
 /// Build AppData from DailySummary list (no raw entries needed).
 fn build_app_data_from_summaries(
-    summaries: Vec<crate::types::DailySummary>,
+    summaries: Vec<DailySummary>,
     source_usage: Vec<SourceUsage>,
+    source_summaries: Vec<(String, Vec<DailySummary>)>,
     cache_warning: Option<CacheWarning>,
+    pricing: Option<PricingInfo>,
+    date_range: &DateRange,
+    user_config: &Config,
 ) -> Result<Box<AppData>, String> {
+    let today = Local::now().date_naive();
     let total = Aggregator::total_from_daily(&summaries);
 
     let daily_tokens: Vec<(NaiveDate, u64)> = summaries
@@ -641,32 +871,56 @@ fn build_app_data_from_summaries(
         })
         .collect();
 
-    let model_map = Aggregator::by_model_from_daily(&summaries);
-    let models_data = ModelsData::from_model_usage(&model_map);
-    let stats_data = StatsData::from_daily_summaries(&summaries);
-    let daily_data = DailyData::from_daily_summaries(summaries);
+    let provider_map = Aggregator::by_provider_from_daily(&summaries);
+    let models_data = ModelsData::from_provider_usage(&provider_map);
+    let projects_data =
+        ModelsData::from_project_usage(&Aggregator::by_project_from_daily(&summaries));
+    let stats_data = forecast::with_current(
+        StatsData::from_daily_summaries(&summaries).with_pricing(pricing),
+        &summaries,
+        date_range,
+        today,
+        user_config.week_start,
+    );
+    let daily_data = DailyData::from_daily_summaries(summaries, user_config.week_start);
+    let budget_status = budget::evaluate(
+        &user_config.budgets,
+        &source_summaries,
+        today,
+        user_config.week_start,
+    );
 
     Ok(Box::new(AppData {
         total,
         daily_tokens,
         models_data,
+        projects_data,
         daily_data,
         stats_data,
         source_usage,
+        source_summaries,
+        budget_status,
         cache_warning,
     }))
 }
 
 fn run_app(terminal: &mut DefaultTerminal, config: TuiConfig, theme: Theme) -> anyhow::Result<()> {
+    let filter = config.filter.clone();
+    let user_config = config.user_config.clone();
     let mut app = App::new(config, theme);
 
     // Spawn background thread for data loading
     let (data_tx, data_rx) = mpsc::channel();
+    let data_filter = filter.clone();
+    let data_config = user_config.clone();
     thread::spawn(move || {
-        let result = load_data_sync();
+        let result = load_data_sync(data_filter, &data_config);
         let _ = data_tx.send(result);
     });
 
+    // Session loading needs a full parse, so it starts on the first visit to the Sessions tab
+    let (sessions_tx, sessions_rx) = mpsc::channel();
+
     // Spawn background thread for update check
     let (update_tx, update_rx) = mpsc::channel();
     thread::spawn(move || {
@@ -696,6 +950,24 @@ fn run_app(terminal: &mut DefaultTerminal, config: TuiConfig, theme: Theme) -> a
             }
         }
 
+        // Spawn background thread for session loading when first needed
+        if app.take_sessions_request() {
+            let tx = sessions_tx.clone();
+            let filter = filter.clone();
+            let user_config = user_config.clone();
+            thread::spawn(move || {
+                let result = load_sessions_sync(filter, &user_config);
+                let _ = tx.send(result);
+            });
+        }
+
+        // Check for session loading completion (non-blocking)
+        if app.sessions_data.is_none() {
+            if let Ok(result) = sessions_rx.try_recv() {
+                app.apply_sessions_result(result);
+            }
+        }
+
         // Check for update check completion (non-blocking)
         if app.update_status == UpdateStatus::Checking {
             if let Ok(result) = update_rx.try_recv() {
@@ -743,11 +1015,13 @@ fn run_app(terminal: &mut DefaultTerminal, config: TuiConfig, theme: Theme) -> a
         // Poll for events with 100ms timeout for spinner animation
         if event::poll(Duration::from_millis(100))? {
             let ev = event::read()?;
-            // Priority chain: quit_confirm > model_breakdown > update > main
+            // Priority chain: quit_confirm > model_breakdown > filter > update > main
             if app.quit_confirm.is_some() {
                 app.handle_quit_confirm_event(ev);
             } else if app.model_breakdown.is_some() {
                 app.handle_model_breakdown_event(ev);
+            } else if app.filter_popup.is_some() {
+                app.handle_filter_event(ev);
             } else if app.update_status.shows_overlay() {
                 app.handle_update_event(ev);
             } else {
@@ -764,8 +1038,10 @@ fn run_app(terminal: &mut DefaultTerminal, config: TuiConfig, theme: Theme) -> a
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::TokenCosts;
+    use chrono::Weekday;
     use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, HashMap};
 
     /// Helper to create a ready app with minimal data for testing
     fn make_ready_app() -> App {
@@ -781,13 +1057,17 @@ mod tests {
                 total_cache_creation_tokens: 0,
                 total_thinking_tokens: 0,
                 total_cost_usd: 0.01,
+                total_token_costs: TokenCosts::default(),
                 models: HashMap::new(),
+                projects: HashMap::new(),
+                providers: HashMap::new(),
+                sources: HashMap::new(),
             })
             .collect();
 
         let daily_tokens: Vec<(NaiveDate, u64)> = summaries.iter().map(|d| (d.date, 150)).collect();
 
-        let daily_data = DailyData::from_daily_summaries(summaries.clone());
+        let daily_data = DailyData::from_daily_summaries(summaries.clone(), Weekday::Sun);
         let stats_data = crate::types::StatsData::from_daily_summaries(&summaries);
         let models_data = super::ModelsData::from_model_usage(&HashMap::new());
 
@@ -801,9 +1081,12 @@ mod tests {
                 total: crate::types::TotalSummary::default(),
                 daily_tokens,
                 models_data,
+                projects_data: ModelsData::from_project_usage(&HashMap::new()),
                 daily_data,
                 stats_data,
                 source_usage: vec![],
+                source_summaries: vec![],
+                budget_status: vec![],
                 cache_warning: None,
             }),
         };
@@ -908,6 +1191,9 @@ mod tests {
         app.handle_event(Event::Key(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE)));
         assert_eq!(app.current_tab, Tab::Stats);
 
+        app.handle_event(Event::Key(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE)));
+        assert_eq!(app.current_tab, Tab::Sessions);
+
         // Wrap around
         app.handle_event(Event::Key(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE)));
         assert_eq!(app.current_tab, Tab::Overview);
@@ -921,13 +1207,13 @@ mod tests {
         // Shift+Tab (BackTab)
         let event = Event::Key(KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT));
         app.handle_event(event);
-        assert_eq!(app.current_tab, Tab::Stats);
+        assert_eq!(app.current_tab, Tab::Sessions);
 
         app.handle_event(Event::Key(KeyEvent::new(
             KeyCode::BackTab,
             KeyModifiers::SHIFT,
         )));
-        assert_eq!(app.current_tab, Tab::Models);
+        assert_eq!(app.current_tab, Tab::Stats);
     }
 
     #[test]
@@ -950,6 +1236,11 @@ mod tests {
         app.handle_event(event);
         assert_eq!(app.current_tab, Tab::Models);
 
+        // Press '5' to go to Sessions
+        let event = Event::Key(KeyEvent::new(KeyCode::Char('5'), KeyModifiers::NONE));
+        app.handle_event(event);
+        assert_eq!(app.current_tab, Tab::Sessions);
+
         // Press '1' to go back to Overview
         let event = Event::Key(KeyEvent::new(KeyCode::Char('1'), KeyModifiers::NONE));
         app.handle_event(event);
@@ -1022,6 +1313,26 @@ mod tests {
         assert_eq!(app.daily_view_mode, DailyViewMode::Daily);
     }
 
+    #[test]
+    fn test_p_toggles_projects_on_models_tab_only() {
+        let mut app = make_ready_app();
+        let press_p = |app: &mut App| {
+            app.handle_event(Event::Key(KeyEvent::new(
+                KeyCode::Char('p'),
+                KeyModifiers::NONE,
+            )))
+        };
+
+        press_p(&mut app);
+        assert!(!app.show_projects);
+
+        app.current_tab = Tab::Models;
+        press_p(&mut app);
+        assert!(app.show_projects);
+        press_p(&mut app);
+        assert!(!app.show_projects);
+    }
+
     #[test]
     fn test_independent_selection_positions() {
         let mut app = make_ready_app();
@@ -1142,10 +1453,14 @@ mod tests {
             total_cache_creation_tokens: 0,
             total_thinking_tokens: 0,
             total_cost_usd: 0.01,
+            total_token_costs: TokenCosts::default(),
             models: HashMap::new(),
+            projects: HashMap::new(),
+            providers: HashMap::new(),
+            sources: HashMap::new(),
         }];
         let daily_tokens: Vec<(NaiveDate, u64)> = vec![(summaries[0].date, 150)];
-        let daily_data = DailyData::from_daily_summaries(summaries.clone());
+        let daily_data = DailyData::from_daily_summaries(summaries.clone(), Weekday::Sun);
         let stats_data = crate::types::StatsData::from_daily_summaries(&summaries);
         let models_data = ModelsData::from_model_usage(&HashMap::new());
 
@@ -1153,9 +1468,12 @@ mod tests {
             total: crate::types::TotalSummary::default(),
             daily_tokens,
             models_data,
+            projects_data: ModelsData::from_project_usage(&HashMap::new()),
             daily_data,
             stats_data,
             source_usage: vec![],
+            source_summaries: vec![],
+            budget_status: vec![],
             cache_warning: None,
         })));
 
@@ -1228,6 +1546,7 @@ mod tests {
         let config = TuiConfig::default();
         assert_eq!(config.initial_tab, Tab::Overview);
         assert_eq!(config.initial_view_mode, DailyViewMode::Daily);
+        assert_eq!(config.filter, UsageFilter::default());
     }
 
     #[test]
@@ -1235,6 +1554,8 @@ mod tests {
         let config = TuiConfig {
             initial_tab: Tab::Daily,
             initial_view_mode: DailyViewMode::Weekly,
+            filter: UsageFilter::default(),
+            user_config: Config::default(),
         };
         let app = App::new(config, Theme::Dark);
 
@@ -1605,9 +1926,18 @@ mod tests {
                         output_tokens: 50,
                         cache_read_tokens: 0,
                         cache_creation_tokens: 0,
+                        cache_creation_1h_tokens: 0,
                         thinking_tokens: 0,
                         cost_usd: 0.01,
                         count: 1,
+                        web_search_requests: 0,
+                        web_search_cost_usd: 0.0,
+                        service_tier_cost_usd: 0.0,
+                        unpriced_tokens: 0,
+                        token_costs: TokenCosts::default(),
+                        cost_reported: false,
+                        max_prompt_tokens: None,
+                        tier_tokens: BTreeMap::new(),
                     },
                 );
                 DailySummary {
@@ -1618,14 +1948,18 @@ mod tests {
                     total_cache_creation_tokens: 0,
                     total_thinking_tokens: 0,
                     total_cost_usd: 0.01,
+                    total_token_costs: TokenCosts::default(),
                     models,
+                    projects: HashMap::new(),
+                    providers: HashMap::new(),
+                    sources: HashMap::new(),
                 }
             })
             .collect();
 
         let daily_tokens: Vec<(NaiveDate, u64)> = summaries.iter().map(|d| (d.date, 150)).collect();
 
-        let daily_data = DailyData::from_daily_summaries(summaries.clone());
+        let daily_data = DailyData::from_daily_summaries(summaries.clone(), Weekday::Sun);
         let stats_data = crate::types::StatsData::from_daily_summaries(&summaries);
         let models_data = super::ModelsData::from_model_usage(&HashMap::new());
 
@@ -1639,9 +1973,12 @@ mod tests {
                 total: crate::types::TotalSummary::default(),
                 daily_tokens,
                 models_data,
+                projects_data: ModelsData::from_project_usage(&HashMap::new()),
                 daily_data,
                 stats_data,
                 source_usage: vec![],
+                source_summaries: vec![],
+                budget_status: vec![],
                 cache_warning: None,
             }),
         };
@@ -1650,4 +1987,225 @@ mod tests {
         app.monthly_scroll = monthly_scroll;
         app
     }
+
+    fn make_session(id: &str, source: &str, model: &str, hours_ago: i64) -> SessionSummary {
+        let start = chrono::Utc::now() - chrono::Duration::hours(hours_ago);
+        let usage = crate::types::ModelUsage {
+            input_tokens: 100,
+            output_tokens: 0,
+            cache_read_tokens: 0,
+            cache_creation_tokens: 0,
+            cache_creation_1h_tokens: 0,
+            thinking_tokens: 0,
+            cost_usd: 0.01,
+            count: 1,
+            web_search_requests: 0,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            unpriced_tokens: 0,
+            token_costs: TokenCosts::default(),
+            cost_reported: false,
+            max_prompt_tokens: None,
+            tier_tokens: BTreeMap::new(),
+        };
+        SessionSummary {
+            session_id: id.to_string(),
+            source: source.to_string(),
+            project: None,
+            start,
+            end: start,
+            duration_secs: 0,
+            total_input_tokens: 100,
+            total_output_tokens: 0,
+            total_cache_read_tokens: 0,
+            total_cache_creation_tokens: 0,
+            total_thinking_tokens: 0,
+            total_cost_usd: 0.01,
+            models: HashMap::from([(model.to_string(), usage)]),
+        }
+    }
+
+    #[test]
+    fn test_sessions_scroll_clamped() {
+        let mut app = App {
+            current_tab: Tab::Sessions,
+            ..App::default()
+        };
+
+        // Not loaded yet: scrolling is a no-op
+        app.handle_event(Event::Key(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE)));
+        assert_eq!(app.sessions_scroll, 0);
+
+        let sessions: Vec<SessionSummary> = (0..17)
+            .map(|h| make_session(&h.to_string(), "claude", "claude-sonnet-4", h))
+            .collect();
+        app.apply_sessions_result(Ok(sessions));
+
+        for _ in 0..5 {
+            app.handle_event(Event::Key(KeyEvent::new(
+                KeyCode::Char('j'),
+                KeyModifiers::NONE,
+            )));
+        }
+        // 17 rows, 15 visible → max offset 2
+        assert_eq!(app.sessions_scroll, 2);
+
+        app.handle_event(Event::Key(KeyEvent::new(
+            KeyCode::Char('k'),
+            KeyModifiers::NONE,
+        )));
+        assert_eq!(app.sessions_scroll, 1);
+    }
+
+    #[test]
+    fn test_sessions_requested_once_on_first_visit() {
+        let mut app = App::default();
+        assert!(!app.take_sessions_request());
+
+        app.current_tab = Tab::Sessions;
+        assert!(app.take_sessions_request());
+        assert!(!app.take_sessions_request());
+
+        app.current_tab = Tab::Overview;
+        app.current_tab = Tab::Sessions;
+        assert!(!app.take_sessions_request());
+    }
+
+    #[test]
+    fn test_sessions_load_error_falls_back_to_empty() {
+        let mut app = App::default();
+        app.apply_sessions_result(Err("boom".to_string()));
+        assert!(app.sessions_data.as_ref().unwrap().sessions.is_empty());
+    }
+
+    /// Ready app with two sources, each with its own model, loaded via per-source summaries
+    fn make_filterable_app() -> App {
+        let make = |model: &str, input: u64, cost: f64| {
+            let usage = crate::types::ModelUsage {
+                input_tokens: input,
+                output_tokens: 0,
+                cache_read_tokens: 0,
+                cache_creation_tokens: 0,
+                cache_creation_1h_tokens: 0,
+                thinking_tokens: 0,
+                cost_usd: cost,
+                count: 1,
+                web_search_requests: 0,
+                web_search_cost_usd: 0.0,
+                service_tier_cost_usd: 0.0,
+                unpriced_tokens: 0,
+                token_costs: TokenCosts::default(),
+                cost_reported: false,
+                max_prompt_tokens: None,
+                tier_tokens: BTreeMap::new(),
+            };
+            DailySummary {
+                date: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+                total_input_tokens: input,
+                total_output_tokens: 0,
+                total_cache_read_tokens: 0,
+                total_cache_creation_tokens: 0,
+                total_thinking_tokens: 0,
+                total_cost_usd: cost,
+                total_token_costs: TokenCosts::default(),
+                models: HashMap::from([(model.to_string(), usage)]),
+                projects: HashMap::new(),
+                providers: HashMap::new(),
+                sources: HashMap::new(),
+            }
+        };
+        let source_summaries = vec![
+            ("claude".to_string(), vec![make("claude-opus-4", 100, 1.0)]),
+            ("gemini".to_string(), vec![make("gemini-2.5-pro", 50, 0.5)]),
+        ];
+        let (summaries, source_usage) =
+            DataLoaderService::apply_filter(&source_summaries, &UsageFilter::default());
+        let data = build_app_data_from_summaries(
+            summaries,
+            source_usage,
+            source_summaries,
+            None,
+            None,
+            &DateRange::default(),
+            &Config::default(),
+        );
+
+        let mut app = App::default();
+        app.apply_data_result(data);
+        app.apply_sessions_result(Ok(vec![
+            make_session("a", "claude", "claude-opus-4", 1),
+            make_session("b", "gemini", "gemini-2.5-pro", 2),
+        ]));
+        app
+    }
+
+    #[test]
+    fn test_f_key_opens_filter_popup_with_sources_and_models() {
+        let mut app = make_filterable_app();
+        app.handle_event(Event::Key(KeyEvent::new(
+            KeyCode::Char('f'),
+            KeyModifiers::NONE,
+        )));
+
+        let state = app.filter_popup.as_ref().unwrap();
+        let names: Vec<&str> = state.items.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["claude", "gemini", "claude-opus-4", "gemini-2.5-pro"]
+        );
+
+        app.handle_filter_event(Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)));
+        assert!(app.filter_popup.is_none());
+    }
+
+    #[test]
+    fn test_f_key_ignored_while_loading() {
+        let mut app = App::default();
+        app.handle_event(Event::Key(KeyEvent::new(
+            KeyCode::Char('f'),
+            KeyModifiers::NONE,
+        )));
+        assert!(app.filter_popup.is_none());
+    }
+
+    #[test]
+    fn test_filter_toggle_rebuilds_views() {
+        let mut app = make_filterable_app();
+        app.handle_event(Event::Key(KeyEvent::new(
+            KeyCode::Char('f'),
+            KeyModifiers::NONE,
+        )));
+
+        // Toggle off the first source ("claude")
+        app.handle_filter_event(Event::Key(KeyEvent::new(
+            KeyCode::Char(' '),
+            KeyModifiers::NONE,
+        )));
+        assert_eq!(app.filter.sources, Some(vec!["gemini".to_string()]));
+
+        match &app.state {
+            AppState::Ready { data } => {
+                assert_eq!(data.total.total_input_tokens, 50);
+                assert_eq!(data.source_usage.len(), 1);
+                assert_eq!(data.source_usage[0].source, "gemini");
+                // Unfiltered data is retained for further toggling
+                assert_eq!(data.source_summaries.len(), 2);
+            }
+            _ => panic!("expected Ready state"),
+        }
+        let sessions = &app.sessions_data.as_ref().unwrap().sessions;
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].source, "gemini");
+
+        // 'a' re-enables everything
+        app.handle_filter_event(Event::Key(KeyEvent::new(
+            KeyCode::Char('a'),
+            KeyModifiers::NONE,
+        )));
+        assert_eq!(app.filter, UsageFilter::default());
+        match &app.state {
+            AppState::Ready { data } => assert_eq!(data.total.total_input_tokens, 150),
+            _ => panic!("expected Ready state"),
+        }
+    }
 }
@@ -1,6 +1,7 @@
 //! TUI module for interactive terminal interface
 
 mod app;
+pub mod plain;
 pub mod theme;
 pub mod widgets;
 
@@ -0,0 +1,105 @@
+//! Plain-text rendering of styled lines for non-interactive output
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+
+/// Flatten a styled line into a string, emitting ANSI escapes when `color` is true.
+/// Trailing whitespace is trimmed.
+pub fn line_to_string(line: &Line, color: bool) -> String {
+    let mut out = String::new();
+    for span in &line.spans {
+        let sgr = if color { sgr_codes(span.style) } else { None };
+        match sgr {
+            Some(codes) => {
+                out.push_str(&format!("\x1b[{}m{}\x1b[0m", codes, span.content));
+            }
+            None => out.push_str(&span.content),
+        }
+    }
+    out.trim_end().to_string()
+}
+
+/// SGR parameter list for a style, or `None` if the style is unstyled
+fn sgr_codes(style: Style) -> Option<String> {
+    let mut codes = Vec::new();
+    let modifiers = [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+        (Modifier::REVERSED, "7"),
+    ];
+    for (modifier, code) in modifiers {
+        if style.add_modifier.contains(modifier) {
+            codes.push(code.to_string());
+        }
+    }
+    if let Some(fg) = style.fg.and_then(fg_code) {
+        codes.push(fg);
+    }
+    (!codes.is_empty()).then(|| codes.join(";"))
+}
+
+/// Foreground SGR parameter for a ratatui color
+fn fg_code(color: Color) -> Option<String> {
+    let code = match color {
+        Color::Reset => return None,
+        Color::Black => "30".to_string(),
+        Color::Red => "31".to_string(),
+        Color::Green => "32".to_string(),
+        Color::Yellow => "33".to_string(),
+        Color::Blue => "34".to_string(),
+        Color::Magenta => "35".to_string(),
+        Color::Cyan => "36".to_string(),
+        Color::Gray => "37".to_string(),
+        Color::DarkGray => "90".to_string(),
+        Color::LightRed => "91".to_string(),
+        Color::LightGreen => "92".to_string(),
+        Color::LightYellow => "93".to_string(),
+        Color::LightBlue => "94".to_string(),
+        Color::LightMagenta => "95".to_string(),
+        Color::LightCyan => "96".to_string(),
+        Color::White => "97".to_string(),
+        Color::Indexed(n) => format!("38;5;{}", n),
+        Color::Rgb(r, g, b) => format!("38;2;{};{};{}", r, g, b),
+    };
+    Some(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::text::Span;
+
+    fn sample_line() -> Line<'static> {
+        Line::from(vec![
+            Span::styled("Date", Style::default().fg(Color::Yellow)),
+            Span::raw("  "),
+            Span::styled(
+                "$1.00",
+                Style::default()
+                    .fg(Color::Indexed(208))
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("   "),
+        ])
+    }
+
+    #[test]
+    fn test_line_to_string_plain() {
+        assert_eq!(line_to_string(&sample_line(), false), "Date  $1.00");
+    }
+
+    #[test]
+    fn test_line_to_string_colored() {
+        let s = line_to_string(&sample_line(), true);
+        assert!(s.starts_with("\x1b[33mDate\x1b[0m  "));
+        assert!(s.ends_with("\x1b[1;38;5;208m$1.00\x1b[0m"));
+    }
+
+    #[test]
+    fn test_unstyled_span_has_no_escape() {
+        let line = Line::from(Span::raw("plain"));
+        assert_eq!(line_to_string(&line, true), "plain");
+    }
+}
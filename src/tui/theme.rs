@@ -2,6 +2,9 @@
 
 use ratatui::style::Color;
 
+use crate::services::budget::BudgetState;
+use crate::services::Config;
+
 /// Heatmap intensity level for theme-aware coloring
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HeatmapLevel {
@@ -20,18 +23,50 @@ pub enum SpikeLevel {
     High,
 }
 
+/// Cost-to-average ratios at which a day is flagged as a spike
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpikeThresholds {
+    pub elevated: f64,
+    pub high: f64,
+}
+
+impl Default for SpikeThresholds {
+    fn default() -> Self {
+        Self::from_config(&Config::default())
+    }
+}
+
+impl SpikeThresholds {
+    /// Thresholds from `display.spike_elevated` / `display.spike_high`
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            elevated: config.spike_elevated,
+            high: config.spike_high,
+        }
+    }
+}
+
 /// Determine spike level for a cost value relative to the daily average.
 /// Returns Normal if avg_cost is 0 (no data or single day).
-pub fn spike_level(cost: f64, avg_cost: f64) -> SpikeLevel {
-    if avg_cost > 0.0 && cost >= avg_cost * 2.0 {
+pub fn spike_level(cost: f64, avg_cost: f64, thresholds: SpikeThresholds) -> SpikeLevel {
+    if avg_cost > 0.0 && cost >= avg_cost * thresholds.high {
         SpikeLevel::High
-    } else if avg_cost > 0.0 && cost >= avg_cost * 1.5 {
+    } else if avg_cost > 0.0 && cost >= avg_cost * thresholds.elevated {
         SpikeLevel::Elevated
     } else {
         SpikeLevel::Normal
     }
 }
 
+/// Budget usage on the spike palette: warning is elevated, exceeded is high
+pub fn budget_level(state: BudgetState) -> SpikeLevel {
+    match state {
+        BudgetState::Ok => SpikeLevel::Normal,
+        BudgetState::Warning => SpikeLevel::Elevated,
+        BudgetState::Exceeded => SpikeLevel::High,
+    }
+}
+
 /// Terminal color scheme (dark or light background)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Theme {
@@ -139,6 +174,19 @@ impl Theme {
         }
     }
 
+    /// Color of the `index`-th series in stacked bars (one per source CLI)
+    pub fn series_color(self, index: usize) -> Color {
+        let palette = [
+            self.bar(),
+            self.accent(),
+            self.cost(),
+            self.date(),
+            self.stat_warm(),
+            self.stat_blue(),
+        ];
+        palette[index % palette.len()]
+    }
+
     /// Spike detection color based on spike level
     pub fn spike_color(self, level: SpikeLevel) -> Color {
         match level {
@@ -205,6 +253,15 @@ mod tests {
         assert_eq!(t.spike_high(), Color::Indexed(160));
     }
 
+    #[test]
+    fn test_series_color_starts_at_bar_and_cycles() {
+        let t = Theme::Dark;
+        assert_eq!(t.series_color(0), t.bar());
+        assert_eq!(t.series_color(1), t.accent());
+        assert_ne!(t.series_color(1), t.series_color(2));
+        assert_eq!(t.series_color(6), t.series_color(0));
+    }
+
     #[test]
     fn test_default_is_dark() {
         assert_eq!(Theme::default(), Theme::Dark);
@@ -234,31 +291,69 @@ mod tests {
 
     #[test]
     fn test_spike_level_normal() {
-        assert_eq!(spike_level(1.0, 1.0), SpikeLevel::Normal);
-        assert_eq!(spike_level(1.49, 1.0), SpikeLevel::Normal);
+        assert_eq!(
+            spike_level(1.0, 1.0, SpikeThresholds::default()),
+            SpikeLevel::Normal
+        );
+        assert_eq!(
+            spike_level(1.49, 1.0, SpikeThresholds::default()),
+            SpikeLevel::Normal
+        );
     }
 
     #[test]
     fn test_spike_level_elevated() {
-        assert_eq!(spike_level(1.5, 1.0), SpikeLevel::Elevated);
-        assert_eq!(spike_level(1.99, 1.0), SpikeLevel::Elevated);
+        assert_eq!(
+            spike_level(1.5, 1.0, SpikeThresholds::default()),
+            SpikeLevel::Elevated
+        );
+        assert_eq!(
+            spike_level(1.99, 1.0, SpikeThresholds::default()),
+            SpikeLevel::Elevated
+        );
     }
 
     #[test]
     fn test_spike_level_high() {
-        assert_eq!(spike_level(2.0, 1.0), SpikeLevel::High);
-        assert_eq!(spike_level(5.0, 1.0), SpikeLevel::High);
+        assert_eq!(
+            spike_level(2.0, 1.0, SpikeThresholds::default()),
+            SpikeLevel::High
+        );
+        assert_eq!(
+            spike_level(5.0, 1.0, SpikeThresholds::default()),
+            SpikeLevel::High
+        );
     }
 
     #[test]
     fn test_spike_level_zero_avg() {
-        assert_eq!(spike_level(0.0, 0.0), SpikeLevel::Normal);
-        assert_eq!(spike_level(100.0, 0.0), SpikeLevel::Normal);
+        assert_eq!(
+            spike_level(0.0, 0.0, SpikeThresholds::default()),
+            SpikeLevel::Normal
+        );
+        assert_eq!(
+            spike_level(100.0, 0.0, SpikeThresholds::default()),
+            SpikeLevel::Normal
+        );
+    }
+
+    #[test]
+    fn test_spike_level_custom_thresholds() {
+        let thresholds = SpikeThresholds {
+            elevated: 1.2,
+            high: 3.0,
+        };
+        assert_eq!(spike_level(1.1, 1.0, thresholds), SpikeLevel::Normal);
+        assert_eq!(spike_level(2.0, 1.0, thresholds), SpikeLevel::Elevated);
+        assert_eq!(spike_level(3.0, 1.0, thresholds), SpikeLevel::High);
     }
 
     #[test]
     fn test_spike_level_zero_cost() {
-        assert_eq!(spike_level(0.0, 1.0), SpikeLevel::Normal);
+        assert_eq!(
+            spike_level(0.0, 1.0, SpikeThresholds::default()),
+            SpikeLevel::Normal
+        );
     }
 
     // ========== Spike color tests ==========
@@ -1,5 +1,8 @@
 //! Daily view widget - displays per-day usage statistics with sparklines
 
+use std::collections::HashMap;
+
+use chrono::Weekday;
 use ratatui::{
     buffer::Buffer,
     layout::{Alignment, Constraint, Layout, Rect},
@@ -11,8 +14,9 @@ use ratatui::{
 use super::overview::format_number;
 use super::tabs::{Tab, TabBar};
 use crate::services::{display_name, Aggregator};
-use crate::tui::theme::{spike_level, Theme};
-use crate::types::DailySummary;
+use crate::tui::plain::line_to_string;
+use crate::tui::theme::{spike_level, SpikeThresholds, Theme};
+use crate::types::{DailySummary, ModelUsage};
 
 /// View mode within the Daily tab
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -54,6 +58,37 @@ pub fn format_sparkline(tokens: u64, max: u64, width: usize) -> String {
     format!("{}{}", "▓".repeat(filled), "░".repeat(empty))
 }
 
+/// Filled width of each stacked segment, so segments add up to the same fill
+/// as `format_sparkline` gives their sum
+/// Example: values=[250, 250], max=1000, width=8 → [2, 2]
+pub fn stacked_widths(values: &[f64], max: f64, width: usize) -> Vec<usize> {
+    if max <= 0.0 {
+        return vec![0; values.len()];
+    }
+    let mut cumulative = 0.0;
+    let mut drawn = 0usize;
+    values
+        .iter()
+        .map(|&v| {
+            cumulative += v.max(0.0);
+            let end = ((cumulative / max) * width as f64).round() as usize;
+            let end = end.min(width);
+            let segment = end.saturating_sub(drawn);
+            drawn = drawn.max(end);
+            segment
+        })
+        .collect()
+}
+
+/// Tokens counted toward a row's sparkline (same fields as the Total column)
+fn sparkline_tokens(usage: &ModelUsage) -> u64 {
+    usage
+        .input_tokens
+        .saturating_add(usage.output_tokens)
+        .saturating_add(usage.cache_read_tokens)
+        .saturating_add(usage.cache_creation_tokens)
+}
+
 /// Data for the daily view (holds daily, weekly, and monthly aggregations)
 #[derive(Debug)]
 pub struct DailyData {
@@ -64,12 +99,14 @@ pub struct DailyData {
     pub weekly_max_tokens: u64,
     pub monthly_summaries: Vec<DailySummary>,
     pub monthly_max_tokens: u64,
+    /// Source CLIs by total tokens descending; fixes each source's sparkline color
+    pub sources: Vec<String>,
 }
 
 impl DailyData {
-    /// Create DailyData from aggregated daily summaries
+    /// Create DailyData from aggregated daily summaries, with weeks starting on `week_start`.
     /// Expects summaries in ascending order (from Aggregator::daily)
-    pub fn from_daily_summaries(summaries: Vec<DailySummary>) -> Self {
+    pub fn from_daily_summaries(summaries: Vec<DailySummary>, week_start: Weekday) -> Self {
         let calc_max = |s: &[DailySummary]| -> u64 {
             s.iter()
                 .map(|d| {
@@ -83,13 +120,22 @@ impl DailyData {
                 .unwrap_or(0)
         };
 
-        let weekly_summaries = Aggregator::weekly(&summaries);
+        let weekly_summaries = Aggregator::weekly(&summaries, week_start);
         let monthly_summaries = Aggregator::monthly(&summaries);
 
         let daily_max_tokens = calc_max(&summaries);
         let weekly_max_tokens = calc_max(&weekly_summaries);
         let monthly_max_tokens = calc_max(&monthly_summaries);
 
+        let mut source_tokens: HashMap<&str, u64> = HashMap::new();
+        for (source, models) in summaries.iter().flat_map(|s| &s.sources) {
+            let tokens: u64 = models.values().map(sparkline_tokens).sum();
+            *source_tokens.entry(source).or_default() += tokens;
+        }
+        let mut sources: Vec<(&str, u64)> = source_tokens.into_iter().collect();
+        sources.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        let sources = sources.into_iter().map(|(s, _)| s.to_string()).collect();
+
         Self {
             daily_summaries: summaries,
             daily_max_tokens,
@@ -97,6 +143,7 @@ impl DailyData {
             weekly_max_tokens,
             monthly_summaries,
             monthly_max_tokens,
+            sources,
         }
     }
 
@@ -178,6 +225,7 @@ pub struct DailyView<'a> {
     view_mode: DailyViewMode,
     theme: Theme,
     avg_cost: f64,
+    spike_thresholds: SpikeThresholds,
 }
 
 impl<'a> DailyView<'a> {
@@ -196,6 +244,7 @@ impl<'a> DailyView<'a> {
             view_mode,
             theme,
             avg_cost,
+            spike_thresholds: SpikeThresholds::default(),
         }
     }
 
@@ -209,6 +258,38 @@ impl<'a> DailyView<'a> {
         self
     }
 
+    pub fn with_spike_thresholds(mut self, thresholds: SpikeThresholds) -> Self {
+        self.spike_thresholds = thresholds;
+        self
+    }
+
+    /// Render every row as a plain-text table (header, rule, rows) for
+    /// non-interactive output. Columns are hidden for `width` the same way
+    /// as in the TUI; ANSI colors are emitted only when `color` is true.
+    pub fn render_plain(&self, width: u16, color: bool) -> String {
+        let visible = visible_columns(width.min(MAX_CONTENT_WIDTH));
+        let (summaries, max_tokens) = self.data.for_mode(self.view_mode);
+
+        let rule = Line::from(Span::styled(
+            "─".repeat(table_width_for(&visible) as usize),
+            Style::default().fg(self.theme.muted()),
+        ));
+
+        let mut lines = vec![self.header_line(&visible), rule];
+        lines.extend(
+            summaries
+                .iter()
+                .map(|s| self.row_line(s, max_tokens, &visible, false)),
+        );
+
+        let mut out = String::new();
+        for line in &lines {
+            out.push_str(&line_to_string(line, color));
+            out.push('\n');
+        }
+        out
+    }
+
     /// Calculate the maximum valid scroll offset for the given mode
     pub fn max_scroll_offset(data: &DailyData, mode: DailyViewMode) -> usize {
         let (summaries, _) = data.for_mode(mode);
@@ -315,6 +396,24 @@ impl DailyView<'_> {
             spans.push(Span::styled(format!("{}:{}", key, mode.label()), style));
         }
 
+        // Legend for the per-source sparkline colors
+        if self.data.sources.len() > 1 {
+            spans.push(Span::raw("    "));
+            for (i, source) in self.data.sources.iter().enumerate() {
+                if i > 0 {
+                    spans.push(Span::raw(" "));
+                }
+                spans.push(Span::styled(
+                    "▓",
+                    Style::default().fg(self.theme.series_color(i)),
+                ));
+                spans.push(Span::styled(
+                    format!(" {}", source),
+                    Style::default().fg(self.theme.muted()),
+                ));
+            }
+        }
+
         let indicator = Paragraph::new(Line::from(spans)).alignment(Alignment::Center);
         indicator.render(area, buf);
     }
@@ -322,6 +421,19 @@ impl DailyView<'_> {
     fn render_header(&self, area: Rect, buf: &mut Buffer, visible: &[usize]) {
         let tw = table_width_for(visible);
         let offset = Self::calculate_table_offset(area.width, tw);
+        let paragraph = Paragraph::new(self.header_line(visible)).alignment(Alignment::Left);
+        paragraph.render(
+            Rect {
+                x: area.x + offset,
+                y: area.y,
+                width: tw.min(area.width),
+                height: area.height,
+            },
+            buf,
+        );
+    }
+
+    fn header_line(&self, visible: &[usize]) -> Line<'static> {
         let date_label = self.view_mode.date_column_label();
         let header_style = Style::default()
             .fg(self.theme.text())
@@ -342,17 +454,7 @@ impl DailyView<'_> {
             spans.push(Span::styled(formatted, header_style));
         }
 
-        let header = Line::from(spans);
-        let paragraph = Paragraph::new(header).alignment(Alignment::Left);
-        paragraph.render(
-            Rect {
-                x: area.x + offset,
-                y: area.y,
-                width: tw.min(area.width),
-                height: area.height,
-            },
-            buf,
-        );
+        Line::from(spans)
     }
 
     fn render_daily_rows(&self, area: Rect, buf: &mut Buffer, visible: &[usize]) {
@@ -396,6 +498,18 @@ impl DailyView<'_> {
         visible: &[usize],
         is_selected: bool,
     ) {
+        let row = self.row_line(summary, max_tokens, visible, is_selected);
+        let paragraph = Paragraph::new(row).alignment(Alignment::Left);
+        paragraph.render(area, buf);
+    }
+
+    fn row_line(
+        &self,
+        summary: &DailySummary,
+        max_tokens: u64,
+        visible: &[usize],
+        is_selected: bool,
+    ) -> Line<'static> {
         let total_tokens = summary.total_input_tokens
             + summary.total_output_tokens
             + summary.total_cache_read_tokens
@@ -452,8 +566,6 @@ impl DailyView<'_> {
             primary_model
         };
 
-        let sparkline = format_sparkline(total_tokens, max_tokens, 14);
-
         // Format date based on view mode
         let date_str = match self.view_mode {
             DailyViewMode::Daily | DailyViewMode::Weekly => {
@@ -503,6 +615,21 @@ impl DailyView<'_> {
                 continue;
             }
 
+            // COL_USAGE is stacked: one colored segment per source CLI
+            if col == COL_USAGE {
+                let style = |color| {
+                    let style = Style::default().fg(color);
+                    if is_selected {
+                        style.add_modifier(selection_modifier)
+                    } else {
+                        style
+                    }
+                };
+                spans.push(Span::styled(" ".repeat(4), style(self.theme.bar())));
+                spans.extend(self.usage_segments(summary, total_tokens, max_tokens, 14, style));
+                continue;
+            }
+
             let (text, base_style) = match col {
                 COL_DATE => {
                     // Prepend marker to date column
@@ -531,8 +658,11 @@ impl DailyView<'_> {
                 ),
                 COL_COST => {
                     let cost_color = if self.view_mode == DailyViewMode::Daily {
-                        self.theme
-                            .spike_color(spike_level(summary.total_cost_usd, self.avg_cost))
+                        self.theme.spike_color(spike_level(
+                            summary.total_cost_usd,
+                            self.avg_cost,
+                            self.spike_thresholds,
+                        ))
                     } else {
                         self.theme.text()
                     };
@@ -541,10 +671,6 @@ impl DailyView<'_> {
                         Style::default().fg(cost_color),
                     )
                 }
-                COL_USAGE => (
-                    format!("{:>18}", sparkline),
-                    Style::default().fg(self.theme.bar()),
-                ),
                 _ => unreachable!(),
             };
 
@@ -561,9 +687,47 @@ impl DailyView<'_> {
             spans.push(Span::styled(text, style));
         }
 
-        let row = Line::from(spans);
-        let paragraph = Paragraph::new(row).alignment(Alignment::Left);
-        paragraph.render(area, buf);
+        Line::from(spans)
+    }
+
+    /// Sparkline spans for one row: a segment per source in `DailyData::sources`
+    /// order, or a single bar when the summary has no per-source breakdown
+    fn usage_segments(
+        &self,
+        summary: &DailySummary,
+        total_tokens: u64,
+        max_tokens: u64,
+        width: usize,
+        style: impl Fn(ratatui::style::Color) -> Style,
+    ) -> Vec<Span<'static>> {
+        if summary.sources.is_empty() {
+            return vec![Span::styled(
+                format_sparkline(total_tokens, max_tokens, width),
+                style(self.theme.bar()),
+            )];
+        }
+
+        let values: Vec<f64> = self
+            .data
+            .sources
+            .iter()
+            .map(|source| {
+                summary.sources.get(source).map_or(0.0, |models| {
+                    models.values().map(sparkline_tokens).sum::<u64>() as f64
+                })
+            })
+            .collect();
+        let widths = stacked_widths(&values, max_tokens as f64, width);
+
+        let mut spans: Vec<Span<'static>> = widths
+            .iter()
+            .enumerate()
+            .filter(|(_, &w)| w > 0)
+            .map(|(i, &w)| Span::styled("▓".repeat(w), style(self.theme.series_color(i))))
+            .collect();
+        let empty = width.saturating_sub(widths.iter().sum());
+        spans.push(Span::styled("░".repeat(empty), style(self.theme.bar())));
+        spans
     }
 
     fn render_keybindings(&self, area: Rect, buf: &mut Buffer) {
@@ -592,6 +756,7 @@ impl DailyView<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::TokenCosts;
     use chrono::NaiveDate;
     use std::collections::HashMap;
 
@@ -629,8 +794,34 @@ mod tests {
         assert_eq!(format_sparkline(2000, 1000, 8), "▓▓▓▓▓▓▓▓");
     }
 
+    // ========== stacked_widths tests ==========
+
+    #[test]
+    fn test_stacked_widths_match_sparkline_fill() {
+        assert_eq!(stacked_widths(&[250.0, 250.0], 1000.0, 8), vec![2, 2]);
+        // Rounding is cumulative, so the stack fills like the total's sparkline
+        assert_eq!(
+            stacked_widths(&[100.0, 100.0, 100.0], 1000.0, 8),
+            vec![1, 1, 0]
+        );
+        assert_eq!(format_sparkline(300, 1000, 8), "▓▓░░░░░░");
+        assert_eq!(stacked_widths(&[600.0, 600.0], 1000.0, 8), vec![5, 3]);
+    }
+
+    #[test]
+    fn test_stacked_widths_zero_max() {
+        assert_eq!(stacked_widths(&[100.0, 200.0], 0.0, 8), vec![0, 0]);
+    }
+
     // ========== DailyData tests ==========
 
+    fn usage(input: u64) -> ModelUsage {
+        ModelUsage {
+            input_tokens: input,
+            ..ModelUsage::default()
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn make_daily_summary(
         year: i32,
@@ -650,13 +841,17 @@ mod tests {
             total_cache_creation_tokens: cache_creation,
             total_thinking_tokens: 0,
             total_cost_usd: cost,
+            total_token_costs: TokenCosts::default(),
             models: HashMap::new(),
+            projects: HashMap::new(),
+            providers: HashMap::new(),
+            sources: HashMap::new(),
         }
     }
 
     #[test]
     fn test_daily_data_empty() {
-        let data = DailyData::from_daily_summaries(vec![]);
+        let data = DailyData::from_daily_summaries(vec![], Weekday::Sun);
         assert!(data.daily_summaries.is_empty());
         assert_eq!(data.daily_max_tokens, 0);
     }
@@ -670,7 +865,7 @@ mod tests {
             make_daily_summary(2024, 1, 20, 300, 150, 30, 15, 0.03),
         ];
 
-        let data = DailyData::from_daily_summaries(summaries);
+        let data = DailyData::from_daily_summaries(summaries, Weekday::Sun);
 
         assert_eq!(data.daily_summaries.len(), 3);
         // Should remain ascending (oldest first)
@@ -679,6 +874,28 @@ mod tests {
         assert_eq!(data.daily_summaries[2].date.to_string(), "2024-01-20");
     }
 
+    #[test]
+    fn test_daily_data_orders_sources_by_tokens() {
+        let mut day = make_daily_summary(2024, 1, 10, 400, 0, 0, 0, 0.0);
+        day.sources = HashMap::from([
+            (
+                "codex".to_string(),
+                HashMap::from([("gpt-5".to_string(), usage(100))]),
+            ),
+            (
+                "claude".to_string(),
+                HashMap::from([("claude-sonnet-4".to_string(), usage(300))]),
+            ),
+        ]);
+
+        let data = DailyData::from_daily_summaries(vec![day], Weekday::Sun);
+
+        assert_eq!(data.sources, vec!["claude", "codex"]);
+        // Weekly and monthly rollups keep the breakdown
+        assert_eq!(data.weekly_summaries[0].sources.len(), 2);
+        assert_eq!(data.monthly_summaries[0].sources.len(), 2);
+    }
+
     #[test]
     fn test_daily_data_max_tokens() {
         let summaries = vec![
@@ -687,7 +904,7 @@ mod tests {
             make_daily_summary(2024, 1, 20, 300, 150, 30, 15, 0.03), // total: 495
         ];
 
-        let data = DailyData::from_daily_summaries(summaries);
+        let data = DailyData::from_daily_summaries(summaries, Weekday::Sun);
 
         assert_eq!(data.daily_max_tokens, 495);
     }
@@ -696,7 +913,7 @@ mod tests {
 
     #[test]
     fn test_daily_view_scroll_bounds_empty() {
-        let data = DailyData::from_daily_summaries(vec![]);
+        let data = DailyData::from_daily_summaries(vec![], Weekday::Sun);
         assert_eq!(DailyView::max_scroll_offset(&data, DailyViewMode::Daily), 0);
     }
 
@@ -706,7 +923,7 @@ mod tests {
             make_daily_summary(2024, 1, 10, 100, 50, 10, 5, 0.01),
             make_daily_summary(2024, 1, 15, 200, 100, 20, 10, 0.02),
         ];
-        let data = DailyData::from_daily_summaries(summaries);
+        let data = DailyData::from_daily_summaries(summaries, Weekday::Sun);
         // 2 items < VISIBLE_ROWS (15), so max offset is 0
         assert_eq!(DailyView::max_scroll_offset(&data, DailyViewMode::Daily), 0);
     }
@@ -716,7 +933,7 @@ mod tests {
         let summaries: Vec<DailySummary> = (1..=20)
             .map(|d| make_daily_summary(2024, 1, d, 100, 50, 10, 5, 0.01))
             .collect();
-        let data = DailyData::from_daily_summaries(summaries);
+        let data = DailyData::from_daily_summaries(summaries, Weekday::Sun);
         // 20 items, VISIBLE_ROWS = 15, so max offset = 5
         assert_eq!(DailyView::max_scroll_offset(&data, DailyViewMode::Daily), 5);
     }
@@ -731,7 +948,7 @@ mod tests {
             make_daily_summary(2025, 1, 15, 200, 100, 0, 0, 0.02), // Wed, week of Jan 12
             make_daily_summary(2025, 1, 20, 300, 150, 0, 0, 0.03), // Mon, week of Jan 19
         ];
-        let data = DailyData::from_daily_summaries(summaries);
+        let data = DailyData::from_daily_summaries(summaries, Weekday::Sun);
 
         assert_eq!(data.daily_summaries.len(), 3);
         assert_eq!(data.weekly_summaries.len(), 2);
@@ -745,7 +962,7 @@ mod tests {
             make_daily_summary(2025, 1, 20, 200, 100, 0, 0, 0.02),
             make_daily_summary(2025, 2, 3, 300, 150, 0, 0, 0.03),
         ];
-        let data = DailyData::from_daily_summaries(summaries);
+        let data = DailyData::from_daily_summaries(summaries, Weekday::Sun);
 
         let (daily, _) = data.for_mode(DailyViewMode::Daily);
         assert_eq!(daily.len(), 3);
@@ -775,6 +992,74 @@ mod tests {
     // Hide order: Input → Output → Cache → Usage (keeps Usage visible longest)
     // Full: 141, -Input: 123, -Output: 105, -Cache: 87, -Usage: 69
 
+    // ========== render_plain tests ==========
+
+    #[test]
+    fn test_render_plain_lists_all_rows() {
+        let summaries: Vec<_> = (1..=20)
+            .map(|d| make_daily_summary(2024, 1, d, 1000, 500, 0, 0, 0.5))
+            .collect();
+        let data = DailyData::from_daily_summaries(summaries, Weekday::Sun);
+        let view = DailyView::new(&data, 0, DailyViewMode::Daily, Theme::Dark, 0.5);
+        let out = view.render_plain(u16::MAX, false);
+        let lines: Vec<&str> = out.lines().collect();
+
+        // Header + rule + every row (not limited to VISIBLE_ROWS)
+        assert_eq!(lines.len(), 22);
+        assert!(lines[0].contains("Date") && lines[0].contains("Usage"));
+        assert!(lines[2].contains("2024-01-01"));
+        assert!(lines[21].contains("2024-01-20"));
+        assert!(lines[2].contains("1,500"));
+        assert!(!out.contains('\x1b'));
+    }
+
+    #[test]
+    fn test_render_plain_stacks_usage_by_source() {
+        let mut day = make_daily_summary(2024, 1, 1, 1000, 0, 0, 0, 0.5);
+        day.sources = HashMap::from([
+            (
+                "claude".to_string(),
+                HashMap::from([("claude-sonnet-4".to_string(), usage(500))]),
+            ),
+            (
+                "codex".to_string(),
+                HashMap::from([("gpt-5".to_string(), usage(250))]),
+            ),
+        ]);
+        let mut other = make_daily_summary(2024, 1, 2, 2000, 0, 0, 0, 0.5);
+        other.sources = HashMap::from([(
+            "claude".to_string(),
+            HashMap::from([("claude-sonnet-4".to_string(), usage(2000))]),
+        )]);
+        let data = DailyData::from_daily_summaries(vec![day, other], Weekday::Sun);
+        let view = DailyView::new(&data, 0, DailyViewMode::Daily, Theme::Dark, 0.0);
+
+        let plain = view.render_plain(u16::MAX, false);
+        // 750 of the 2,000 max over 14 cells: 4 + 1 filled, stacked per source
+        assert!(plain.contains("    ▓▓▓▓▓░░░░░░░░░"));
+        assert!(plain.contains("    ▓▓▓▓▓▓▓▓▓▓▓▓▓▓"));
+
+        let colored = view.render_plain(u16::MAX, true);
+        assert!(colored.contains("\x1b["));
+    }
+
+    #[test]
+    fn test_render_plain_hides_columns_and_colors() {
+        let data = DailyData::from_daily_summaries(
+            vec![make_daily_summary(2024, 1, 1, 1000, 500, 0, 0, 0.5)],
+            Weekday::Sun,
+        );
+        let view = DailyView::new(&data, 0, DailyViewMode::Monthly, Theme::Dark, 0.0);
+
+        let narrow = view.render_plain(80, false);
+        assert!(narrow.starts_with("  Month"));
+        assert!(!narrow.contains("Input"));
+        assert!(narrow.contains("2024-01 "));
+
+        let colored = view.render_plain(80, true);
+        assert!(colored.contains("\x1b["));
+    }
+
     #[test]
     fn test_visible_columns_full_width() {
         // >= 141: all 8 columns visible
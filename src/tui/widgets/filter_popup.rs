@@ -0,0 +1,365 @@
+//! Filter popup widget - toggles sources and models on and off
+
+use glob::Pattern;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+
+use crate::services::{display_name, UsageFilter};
+use crate::tui::theme::Theme;
+
+/// Width and height of the filter popup
+const POPUP_WIDTH: u16 = 44;
+const POPUP_MIN_HEIGHT: u16 = 10;
+const POPUP_MAX_HEIGHT: u16 = 24;
+
+/// Kind of filter item
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterKind {
+    Source,
+    Model,
+}
+
+/// A single toggleable row
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterItem {
+    pub kind: FilterKind,
+    pub name: String,
+    pub enabled: bool,
+}
+
+/// State for filter popup
+#[derive(Debug, Clone)]
+pub struct FilterState {
+    /// Sources first, then models (each sorted by name)
+    pub items: Vec<FilterItem>,
+    pub cursor: usize,
+}
+
+impl FilterState {
+    /// Build items from the available sources/models, enabled per the current filter
+    pub fn new(sources: &[String], models: &[String], filter: &UsageFilter) -> Self {
+        let mut items: Vec<FilterItem> = sources
+            .iter()
+            .map(|s| FilterItem {
+                kind: FilterKind::Source,
+                name: s.clone(),
+                enabled: filter.matches_source(s),
+            })
+            .collect();
+        items.extend(models.iter().map(|m| FilterItem {
+            kind: FilterKind::Model,
+            name: m.clone(),
+            enabled: filter.matches_model(m),
+        }));
+        Self { items, cursor: 0 }
+    }
+
+    pub fn move_up(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        if self.cursor + 1 < self.items.len() {
+            self.cursor += 1;
+        }
+    }
+
+    /// Toggle the item under the cursor
+    pub fn toggle(&mut self) {
+        if let Some(item) = self.items.get_mut(self.cursor) {
+            item.enabled = !item.enabled;
+        }
+    }
+
+    /// Enable every item
+    pub fn enable_all(&mut self) {
+        for item in &mut self.items {
+            item.enabled = true;
+        }
+    }
+
+    /// Convert toggles back into a filter, keeping the given filter's date range
+    /// and provider allow-list.
+    /// A fully-enabled group becomes `None` (no restriction).
+    pub fn to_filter(&self, base: &UsageFilter) -> UsageFilter {
+        let group = |kind: FilterKind, escape: bool| -> Option<Vec<String>> {
+            let items: Vec<&FilterItem> = self.items.iter().filter(|i| i.kind == kind).collect();
+            if items.iter().all(|i| i.enabled) {
+                return None;
+            }
+            Some(
+                items
+                    .iter()
+                    .filter(|i| i.enabled)
+                    .map(|i| {
+                        if escape {
+                            Pattern::escape(&i.name)
+                        } else {
+                            i.name.clone()
+                        }
+                    })
+                    .collect(),
+            )
+        };
+        UsageFilter {
+            date_range: base.date_range,
+            sources: group(FilterKind::Source, false),
+            models: group(FilterKind::Model, true),
+            providers: base.providers.clone(),
+        }
+    }
+}
+
+/// Filter popup overlay
+pub struct FilterPopup<'a> {
+    state: &'a FilterState,
+    theme: Theme,
+}
+
+impl<'a> FilterPopup<'a> {
+    pub fn new(state: &'a FilterState, theme: Theme) -> Self {
+        Self { state, theme }
+    }
+
+    /// Calculate centered popup area with dynamic height based on item count
+    pub fn centered_area(area: Rect, item_count: usize) -> Rect {
+        // Height = border (2) + padding (1) + section headers (2) + items + padding (1) + footer (1)
+        let content_height = 7 + item_count as u16;
+        let height = content_height.clamp(POPUP_MIN_HEIGHT, POPUP_MAX_HEIGHT);
+
+        let x = area.x + (area.width.saturating_sub(POPUP_WIDTH)) / 2;
+        let y = area.y + (area.height.saturating_sub(height)) / 2;
+        Rect {
+            x,
+            y,
+            width: POPUP_WIDTH.min(area.width),
+            height: height.min(area.height),
+        }
+    }
+
+    /// Build display lines: section headers plus one line per item.
+    /// Returns the lines and the line index of the cursor.
+    fn build_lines(&self) -> (Vec<Line<'static>>, usize) {
+        let mut lines = Vec::new();
+        let mut cursor_line = 0;
+        let mut last_kind = None;
+
+        for (i, item) in self.state.items.iter().enumerate() {
+            if last_kind != Some(item.kind) {
+                let header = match item.kind {
+                    FilterKind::Source => "Sources",
+                    FilterKind::Model => "Models",
+                };
+                lines.push(Line::from(Span::styled(
+                    header,
+                    Style::default()
+                        .fg(self.theme.date())
+                        .add_modifier(Modifier::BOLD),
+                )));
+                last_kind = Some(item.kind);
+            }
+
+            let selected = i == self.state.cursor;
+            if selected {
+                cursor_line = lines.len();
+            }
+            let label = match item.kind {
+                FilterKind::Source => item.name.clone(),
+                FilterKind::Model => display_name(&item.name),
+            };
+            let label = if label.chars().count() > 32 {
+                format!("{}…", label.chars().take(31).collect::<String>())
+            } else {
+                label
+            };
+            let mut label_style = Style::default().fg(if item.enabled {
+                self.theme.text()
+            } else {
+                self.theme.muted()
+            });
+            if selected {
+                label_style = label_style.add_modifier(Modifier::REVERSED);
+            }
+            lines.push(Line::from(vec![
+                Span::styled(
+                    if item.enabled { " [x] " } else { " [ ] " },
+                    Style::default().fg(self.theme.accent()),
+                ),
+                Span::styled(label, label_style),
+            ]));
+        }
+
+        (lines, cursor_line)
+    }
+}
+
+impl Widget for FilterPopup<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // Clear the area first (for overlay effect)
+        Clear.render(area, buf);
+
+        let block = Block::default()
+            .title(" Filter ")
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.accent()));
+
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        // Apply internal padding: 1 top, 1 left/right; reserve 2 rows for footer
+        let list_area = Rect {
+            x: inner.x + 1,
+            y: inner.y + 1,
+            width: inner.width.saturating_sub(2),
+            height: inner.height.saturating_sub(3),
+        };
+
+        let (lines, cursor_line) = self.build_lines();
+        if lines.is_empty() {
+            Paragraph::new(Line::from(Span::styled(
+                "No data to filter",
+                Style::default().fg(self.theme.muted()),
+            )))
+            .alignment(Alignment::Center)
+            .render(list_area, buf);
+        } else {
+            // Scroll so the cursor stays visible
+            let visible = list_area.height as usize;
+            let offset = cursor_line.saturating_sub(visible.saturating_sub(1));
+            for (i, line) in lines.into_iter().skip(offset).take(visible).enumerate() {
+                Paragraph::new(line).render(
+                    Rect {
+                        y: list_area.y + i as u16,
+                        height: 1,
+                        ..list_area
+                    },
+                    buf,
+                );
+            }
+        }
+
+        // Footer hint
+        if inner.height > 0 {
+            let footer = Line::from(Span::styled(
+                "Space: Toggle  a: All  Esc: Close",
+                Style::default().fg(self.theme.muted()),
+            ));
+            Paragraph::new(footer).alignment(Alignment::Center).render(
+                Rect {
+                    y: inner.y + inner.height - 1,
+                    height: 1,
+                    ..inner
+                },
+                buf,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_state() -> FilterState {
+        FilterState::new(
+            &["claude".to_string(), "gemini".to_string()],
+            &[
+                "claude-opus-4".to_string(),
+                "claude-sonnet-4".to_string(),
+                "gemini-2.5-pro".to_string(),
+            ],
+            &UsageFilter::default(),
+        )
+    }
+
+    #[test]
+    fn test_state_all_enabled_by_default() {
+        let state = make_state();
+        assert_eq!(state.items.len(), 5);
+        assert!(state.items.iter().all(|i| i.enabled));
+        assert_eq!(
+            state.to_filter(&UsageFilter::default()),
+            UsageFilter::default()
+        );
+    }
+
+    #[test]
+    fn test_state_reflects_existing_filter() {
+        let filter = UsageFilter {
+            sources: Some(vec!["gemini".into()]),
+            models: Some(vec!["claude-*".into()]),
+            ..UsageFilter::default()
+        };
+        let state = FilterState::new(
+            &["claude".to_string(), "gemini".to_string()],
+            &["claude-opus-4".to_string(), "gemini-2.5-pro".to_string()],
+            &filter,
+        );
+        let enabled: Vec<bool> = state.items.iter().map(|i| i.enabled).collect();
+        assert_eq!(enabled, vec![false, true, true, false]);
+    }
+
+    #[test]
+    fn test_toggle_produces_filter() {
+        let mut state = make_state();
+        // Disable "claude" source
+        state.toggle();
+        // Disable "claude-sonnet-4"
+        state.move_down();
+        state.move_down();
+        state.move_down();
+        state.toggle();
+
+        let filter = state.to_filter(&UsageFilter::default());
+        assert_eq!(filter.sources, Some(vec!["gemini".to_string()]));
+        assert!(filter.matches_model("claude-opus-4"));
+        assert!(!filter.matches_model("claude-sonnet-4"));
+        assert!(filter.matches_model("gemini-2.5-pro"));
+
+        state.enable_all();
+        assert_eq!(
+            state.to_filter(&UsageFilter::default()),
+            UsageFilter::default()
+        );
+    }
+
+    #[test]
+    fn test_cursor_bounds() {
+        let mut state = make_state();
+        state.move_up();
+        assert_eq!(state.cursor, 0);
+        for _ in 0..10 {
+            state.move_down();
+        }
+        assert_eq!(state.cursor, 4);
+    }
+
+    #[test]
+    fn test_popup_renders_without_panic() {
+        let state = make_state();
+        let area = Rect::new(0, 0, 80, 30);
+        let popup_area = FilterPopup::centered_area(area, state.items.len());
+        let mut buf = Buffer::empty(area);
+        FilterPopup::new(&state, Theme::Dark).render(popup_area, &mut buf);
+
+        let content: String = buf.content().iter().map(|c| c.symbol()).collect();
+        assert!(content.contains("Filter"));
+        assert!(content.contains("Sources"));
+        assert!(content.contains("Models"));
+        assert!(content.contains("[x]"));
+    }
+
+    #[test]
+    fn test_centered_area_small_terminal() {
+        let area = Rect::new(0, 0, 30, 8);
+        let popup_area = FilterPopup::centered_area(area, 40);
+        assert!(popup_area.width <= area.width);
+        assert!(popup_area.height <= area.height);
+    }
+}
@@ -15,7 +15,7 @@ const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 /// Width and height of the help popup
 const POPUP_WIDTH: u16 = 42;
-const POPUP_HEIGHT: u16 = 18;
+const POPUP_HEIGHT: u16 = 20;
 
 /// Help popup widget showing keyboard shortcuts
 pub struct HelpPopup {
@@ -68,17 +68,19 @@ impl Widget for HelpPopup {
             Constraint::Length(1), // [1] Navigation header
             Constraint::Length(1), // [2] Separator
             Constraint::Length(1), // [3] Tab/Shift+Tab
-            Constraint::Length(1), // [4] 1-4
+            Constraint::Length(1), // [4] 1-5
             Constraint::Length(1), // [5] Up/Down
             Constraint::Length(1), // [6] d/w/m
             Constraint::Length(1), // [7] Enter
-            Constraint::Length(1), // [8] Padding
-            Constraint::Length(1), // [9] General header
-            Constraint::Length(1), // [10] Separator
-            Constraint::Length(1), // [11] Ctrl+C
-            Constraint::Length(1), // [12] ?
-            Constraint::Length(1), // [13] Padding
-            Constraint::Length(1), // [14] Close hint
+            Constraint::Length(1), // [8] p
+            Constraint::Length(1), // [9] Padding
+            Constraint::Length(1), // [10] General header
+            Constraint::Length(1), // [11] Separator
+            Constraint::Length(1), // [12] Ctrl+C
+            Constraint::Length(1), // [13] ?
+            Constraint::Length(1), // [14] f
+            Constraint::Length(1), // [15] Padding
+            Constraint::Length(1), // [16] Close hint
             Constraint::Min(0),    // Remaining
         ])
         .split(inner);
@@ -105,7 +107,7 @@ impl Widget for HelpPopup {
 
         // Keybindings
         render_keybinding(chunks[3], buf, "Tab / Shift+Tab", "Switch view", self.theme);
-        render_keybinding(chunks[4], buf, "1-4", "Jump to view", self.theme);
+        render_keybinding(chunks[4], buf, "1-5", "Jump to view", self.theme);
         render_keybinding(
             chunks[5],
             buf,
@@ -121,6 +123,7 @@ impl Widget for HelpPopup {
             self.theme,
         );
         render_keybinding(chunks[7], buf, "Enter", "View details (Daily)", self.theme);
+        render_keybinding(chunks[8], buf, "p", "Projects (Models)", self.theme);
 
         // General section
         let gen_header = Line::from(vec![Span::styled(
@@ -131,18 +134,19 @@ impl Widget for HelpPopup {
         )]);
         Paragraph::new(gen_header)
             .alignment(Alignment::Left)
-            .render(chunks[9], buf);
+            .render(chunks[10], buf);
 
         // Separator
         buf.set_string(
-            chunks[10].x,
-            chunks[10].y,
+            chunks[11].x,
+            chunks[11].y,
             &sep,
             Style::default().fg(self.theme.muted()),
         );
 
-        render_keybinding(chunks[11], buf, "Ctrl+C", "Quit", self.theme);
-        render_keybinding(chunks[12], buf, "?", "Toggle help", self.theme);
+        render_keybinding(chunks[12], buf, "Ctrl+C", "Quit", self.theme);
+        render_keybinding(chunks[13], buf, "?", "Toggle help", self.theme);
+        render_keybinding(chunks[14], buf, "f", "Filter sources/models", self.theme);
 
         // Close hint
         let hint = Line::from(vec![Span::styled(
@@ -151,7 +155,7 @@ impl Widget for HelpPopup {
         )]);
         Paragraph::new(hint)
             .alignment(Alignment::Center)
-            .render(chunks[14], buf);
+            .render(chunks[16], buf);
     }
 }
 
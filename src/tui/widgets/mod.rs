@@ -1,6 +1,7 @@
 //! TUI widgets
 
 pub mod daily;
+pub mod filter_popup;
 pub mod heatmap;
 pub mod help;
 pub mod legend;
@@ -8,6 +9,7 @@ pub mod model_breakdown;
 pub mod models;
 pub mod overview;
 pub mod quit_confirm;
+pub mod sessions;
 pub mod spinner;
 pub mod stats;
 pub mod tabs;
@@ -1,5 +1,7 @@
 //! Model breakdown popup widget - displays per-model usage details for a selected date
 
+use std::collections::HashMap;
+
 use ratatui::{
     buffer::Buffer,
     layout::{Alignment, Constraint, Layout, Rect},
@@ -10,31 +12,60 @@ use ratatui::{
 
 use crate::services::display_name;
 use crate::tui::theme::Theme;
-use crate::types::ModelUsage;
+use crate::types::{ModelUsage, TokenCosts};
 
+use super::daily::stacked_widths;
 use super::overview::format_number;
 
 /// Width and height of the model breakdown popup
-const POPUP_WIDTH: u16 = 54;
+const POPUP_WIDTH: u16 = 94;
 const POPUP_MIN_HEIGHT: u16 = 10;
 const POPUP_MAX_HEIGHT: u16 = 21;
 
+/// Width of the stacked cost-by-type bar
+const COST_BAR_WIDTH: usize = 14;
+
 /// State for model breakdown popup
 #[derive(Debug, Clone)]
 pub struct ModelBreakdownState {
     /// Date label to display in title (e.g., "2026-02-05")
     pub date_label: String,
-    /// Models sorted by cost descending
-    pub models: Vec<(String, ModelUsage)>,
+    /// (model, source CLI, usage) rows sorted by cost descending;
+    /// the source is empty when the summary has no per-source breakdown
+    pub models: Vec<(String, String, ModelUsage)>,
 }
 
 impl ModelBreakdownState {
     /// Create a new state from date label and model map
     pub fn new(date_label: String, models: Vec<(String, ModelUsage)>) -> Self {
+        let rows = models
+            .into_iter()
+            .map(|(model, usage)| (model, String::new(), usage))
+            .collect();
+        Self::from_rows(date_label, rows)
+    }
+
+    /// Create a new state with one row per model and source CLI
+    pub fn from_sources(
+        date_label: String,
+        sources: &HashMap<String, HashMap<String, ModelUsage>>,
+    ) -> Self {
+        let rows = sources
+            .iter()
+            .flat_map(|(source, models)| {
+                models
+                    .iter()
+                    .map(|(model, usage)| (model.clone(), source.clone(), usage.clone()))
+            })
+            .collect();
+        Self::from_rows(date_label, rows)
+    }
+
+    fn from_rows(date_label: String, models: Vec<(String, String, ModelUsage)>) -> Self {
         // Filter out zero-token models and sort by cost descending
         let mut models: Vec<_> = models
             .into_iter()
-            .filter(|(_, usage)| {
+            .filter(|(_, _, usage)| {
                 let total = usage.input_tokens
                     + usage.output_tokens
                     + usage.cache_read_tokens
@@ -43,9 +74,11 @@ impl ModelBreakdownState {
             })
             .collect();
         models.sort_by(|a, b| {
-            b.1.cost_usd
-                .partial_cmp(&a.1.cost_usd)
+            b.2.cost_usd
+                .partial_cmp(&a.2.cost_usd)
                 .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.0.cmp(&b.0))
+                .then_with(|| a.1.cmp(&b.1))
         });
         Self { date_label, models }
     }
@@ -125,9 +158,13 @@ impl Widget for ModelBreakdownPopup<'_> {
             .fg(self.theme.text())
             .add_modifier(Modifier::BOLD);
         let header = Line::from(vec![
-            Span::styled(format!("{:<22}", "Model"), header_style),
+            Span::styled(format!("{:<18}", "Model"), header_style),
+            Span::styled(format!("{:<10}", "CLI"), header_style),
             Span::styled(format!("{:>12}", "Total"), header_style),
+            Span::styled(format!("{:>10}", "Write 5m"), header_style),
+            Span::styled(format!("{:>10}", "Write 1h"), header_style),
             Span::styled(format!("{:>12}", "Cost"), header_style),
+            Span::styled(format!("  {:<14}", "Cost by type"), header_style),
         ]);
         Paragraph::new(header)
             .alignment(Alignment::Left)
@@ -142,40 +179,65 @@ impl Widget for ModelBreakdownPopup<'_> {
             Style::default().fg(self.theme.muted()),
         );
 
+        // Cost bars are scaled to the most expensive row
+        let max_cost = self
+            .state
+            .models
+            .iter()
+            .map(|(_, _, usage)| usage.cost_usd)
+            .fold(0.0, f64::max);
+
         // Model rows
-        for (i, (model_name, usage)) in self.state.models.iter().take(models_to_show).enumerate() {
+        for (i, (model_name, source, usage)) in
+            self.state.models.iter().take(models_to_show).enumerate()
+        {
             let chunk_idx = i + 2;
             let display = display_name(model_name);
-            let truncated = if display.chars().count() > 20 {
-                format!("{}…", display.chars().take(19).collect::<String>())
-            } else {
-                display
-            };
+            let truncated = truncate(&display, 16);
 
             let total_tokens = usage.input_tokens
                 + usage.output_tokens
                 + usage.cache_read_tokens
                 + usage.cache_creation_tokens;
 
-            let row = Line::from(vec![
+            let mut row = vec![
                 Span::styled(
-                    format!("{:<22}", truncated),
+                    format!("{:<18}", truncated),
                     Style::default().fg(self.theme.accent()),
                 ),
+                Span::styled(
+                    format!("{:<10}", truncate(source, 9)),
+                    Style::default().fg(self.theme.muted()),
+                ),
                 Span::styled(
                     format!("{:>12}", format_number(total_tokens)),
                     Style::default().fg(self.theme.text()),
                 ),
+                Span::styled(
+                    format!("{:>10}", format_number(usage.cache_creation_5m_tokens())),
+                    Style::default().fg(self.theme.muted()),
+                ),
+                Span::styled(
+                    format!("{:>10}", format_number(usage.cache_creation_1h_tokens)),
+                    Style::default().fg(self.theme.muted()),
+                ),
                 Span::styled(
                     format!("{:>12}", format!("${:.2}", usage.cost_usd)),
                     Style::default().fg(self.theme.cost()),
                 ),
-            ]);
-            Paragraph::new(row)
+                Span::raw("  "),
+            ];
+            row.extend(self.cost_bar(usage, max_cost));
+            Paragraph::new(Line::from(row))
                 .alignment(Alignment::Left)
                 .render(chunks[chunk_idx], buf);
         }
 
+        // Legend for the cost bar colors, on the padding line
+        Paragraph::new(self.legend())
+            .alignment(Alignment::Center)
+            .render(chunks[chunks.len() - 2], buf);
+
         // Footer hint
         let footer_idx = chunks.len() - 1;
         let footer = Line::from(Span::styled(
@@ -188,9 +250,72 @@ impl Widget for ModelBreakdownPopup<'_> {
     }
 }
 
+impl ModelBreakdownPopup<'_> {
+    /// Stacked bar of a row's cost by token type. Cost not split by type
+    /// (web searches, days cached before the split) is drawn last, muted.
+    fn cost_bar(&self, usage: &ModelUsage, max_cost: f64) -> Vec<Span<'static>> {
+        let mut values: Vec<f64> = usage
+            .token_costs
+            .parts()
+            .iter()
+            .map(|(_, usd)| *usd)
+            .collect();
+        values.push((usage.cost_usd - usage.token_costs.total()).max(0.0));
+        let widths = stacked_widths(&values, max_cost, COST_BAR_WIDTH);
+
+        let mut spans: Vec<Span<'static>> = widths
+            .iter()
+            .enumerate()
+            .filter(|(_, &w)| w > 0)
+            .map(|(i, &w)| {
+                let color = if i < values.len() - 1 {
+                    self.theme.series_color(i)
+                } else {
+                    self.theme.muted()
+                };
+                Span::styled("▓".repeat(w), Style::default().fg(color))
+            })
+            .collect();
+        let empty = COST_BAR_WIDTH.saturating_sub(widths.iter().sum());
+        spans.push(Span::styled(
+            "░".repeat(empty),
+            Style::default().fg(self.theme.muted()),
+        ));
+        spans
+    }
+
+    fn legend(&self) -> Line<'static> {
+        let mut spans = Vec::new();
+        for (i, (label, _)) in TokenCosts::default().parts().iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::raw("  "));
+            }
+            spans.push(Span::styled(
+                "▓",
+                Style::default().fg(self.theme.series_color(i)),
+            ));
+            spans.push(Span::styled(
+                format!(" {}", label),
+                Style::default().fg(self.theme.muted()),
+            ));
+        }
+        Line::from(spans)
+    }
+}
+
+/// Shorten `s` to `max` chars, ending in "…" when cut
+fn truncate(s: &str, max: usize) -> String {
+    if s.chars().count() > max {
+        format!("{}…", s.chars().take(max - 1).collect::<String>())
+    } else {
+        s.to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::BTreeMap;
 
     fn make_usage(input: u64, output: u64, cost: f64) -> ModelUsage {
         ModelUsage {
@@ -198,9 +323,18 @@ mod tests {
             output_tokens: output,
             cache_read_tokens: 0,
             cache_creation_tokens: 0,
+            cache_creation_1h_tokens: 0,
             thinking_tokens: 0,
             cost_usd: cost,
             count: 1,
+            web_search_requests: 0,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            unpriced_tokens: 0,
+            token_costs: TokenCosts::default(),
+            cost_reported: false,
+            max_prompt_tokens: None,
+            tier_tokens: BTreeMap::new(),
         }
     }
 
@@ -218,6 +352,106 @@ mod tests {
         assert_eq!(state.models[2].0, "cheap");
     }
 
+    #[test]
+    fn test_state_from_sources_splits_models_by_cli() {
+        let sources = HashMap::from([
+            (
+                "claude".to_string(),
+                HashMap::from([("claude-sonnet-4-5".to_string(), make_usage(100, 50, 3.00))]),
+            ),
+            (
+                "opencode".to_string(),
+                HashMap::from([
+                    ("claude-sonnet-4-5".to_string(), make_usage(100, 50, 1.00)),
+                    ("idle".to_string(), make_usage(0, 0, 0.0)),
+                ]),
+            ),
+        ]);
+        let state = ModelBreakdownState::from_sources("2026-02-05".to_string(), &sources);
+
+        let rows: Vec<_> = state
+            .models
+            .iter()
+            .map(|(model, source, _)| (model.as_str(), source.as_str()))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("claude-sonnet-4-5", "claude"),
+                ("claude-sonnet-4-5", "opencode")
+            ]
+        );
+
+        let area = Rect::new(0, 0, 100, 30);
+        let popup_area = ModelBreakdownPopup::centered_area(area, state.models.len());
+        let mut buf = Buffer::empty(area);
+        ModelBreakdownPopup::new(&state, Theme::Dark).render(popup_area, &mut buf);
+        let content: String = buf.content().iter().map(|c| c.symbol()).collect();
+        assert!(content.contains("CLI"));
+        assert!(content.contains("opencode"));
+    }
+
+    #[test]
+    fn test_popup_stacks_cost_by_token_type() {
+        let split = ModelUsage {
+            cost_usd: 4.0,
+            token_costs: TokenCosts {
+                output_usd: 2.0,
+                cache_read_usd: 1.0,
+                ..TokenCosts::default()
+            },
+            ..make_usage(1000, 500, 4.0)
+        };
+        let half = ModelUsage {
+            cost_usd: 2.0,
+            token_costs: TokenCosts {
+                input_usd: 2.0,
+                ..TokenCosts::default()
+            },
+            ..make_usage(1000, 500, 2.0)
+        };
+        let state = ModelBreakdownState::new(
+            "2026-02-05".to_string(),
+            vec![("gpt-5".to_string(), split), ("o3".to_string(), half)],
+        );
+        let popup = ModelBreakdownPopup::new(&state, Theme::Dark);
+
+        // output 7 + cache read 4 (cumulative rounding) + 3 unsplit; half the max fills 7
+        let bar = |spans: Vec<Span>| {
+            spans
+                .iter()
+                .map(|s| s.content.to_string())
+                .collect::<String>()
+        };
+        let widths = |spans: Vec<Span>| {
+            spans
+                .iter()
+                .map(|s| s.content.chars().count())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            widths(popup.cost_bar(&state.models[0].2, 4.0)),
+            vec![7, 4, 3, 0]
+        );
+        assert_eq!(
+            popup.cost_bar(&state.models[0].2, 4.0)[2].style.fg,
+            Some(Theme::Dark.muted())
+        );
+        assert_eq!(
+            bar(popup.cost_bar(&state.models[1].2, 4.0)),
+            "▓▓▓▓▓▓▓░░░░░░░"
+        );
+
+        let area = Rect::new(0, 0, 100, 30);
+        let popup_area = ModelBreakdownPopup::centered_area(area, state.models.len());
+        let mut buf = Buffer::empty(area);
+        ModelBreakdownPopup::new(&state, Theme::Dark).render(popup_area, &mut buf);
+        let content: String = buf.content().iter().map(|c| c.symbol()).collect();
+        assert!(content.contains("Cost by type"));
+        assert!(content.contains("▓ cache write"));
+        assert!(content.contains("▓▓▓▓▓▓▓▓▓▓▓▓▓▓"));
+    }
+
     #[test]
     fn test_state_empty_models() {
         let state = ModelBreakdownState::new("2026-02-05".to_string(), vec![]);
@@ -284,6 +518,30 @@ mod tests {
         assert!(content.contains("Esc"));
     }
 
+    #[test]
+    fn test_popup_shows_cache_write_ttl_split() {
+        let usage = ModelUsage {
+            cache_creation_tokens: 5_000,
+            cache_creation_1h_tokens: 1_200,
+            ..make_usage(1000, 500, 1.50)
+        };
+        let state = ModelBreakdownState::new(
+            "2026-02-05".to_string(),
+            vec![("claude-sonnet-4-5".to_string(), usage)],
+        );
+
+        let area = Rect::new(0, 0, 80, 30);
+        let popup_area = ModelBreakdownPopup::centered_area(area, state.models.len());
+        let mut buf = Buffer::empty(area);
+        ModelBreakdownPopup::new(&state, Theme::Dark).render(popup_area, &mut buf);
+
+        let content: String = buf.content().iter().map(|c| c.symbol()).collect();
+        assert!(content.contains("Write 5m"));
+        assert!(content.contains("Write 1h"));
+        assert!(content.contains("3,800"));
+        assert!(content.contains("1,200"));
+    }
+
     #[test]
     fn test_popup_renders_display_names() {
         let models = vec![(
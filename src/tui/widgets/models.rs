@@ -1,6 +1,6 @@
 //! Models view widget - displays per-model usage statistics
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 use ratatui::{
     buffer::Buffer,
@@ -14,7 +14,7 @@ use super::overview::format_number;
 use super::tabs::{Tab, TabBar};
 use crate::services::display_name;
 use crate::tui::theme::Theme;
-use crate::types::ModelUsage;
+use crate::types::{ModelUsage, TokenCosts};
 
 /// Format a percentage bar with filled/empty blocks
 /// Example: 50.0% with width 10 → "█████░░░░░"
@@ -28,8 +28,11 @@ pub fn format_percentage_bar(percent: f64, width: usize) -> String {
 #[derive(Debug, Clone)]
 pub struct ModelSummary {
     pub name: String,
+    /// Provider that served the model (empty when not broken down by provider)
+    pub provider: String,
     pub total_tokens: u64,
     pub cost_usd: f64,
+    pub token_costs: TokenCosts,
 }
 
 /// Data for the models view
@@ -39,24 +42,70 @@ pub struct ModelsData {
     pub models: Vec<ModelSummary>,
     /// Total cost across all models (for percentage calculation)
     pub total_cost: f64,
+    /// Tokens no price was found for, missing from the costs above
+    pub unpriced_tokens: u64,
+    /// Number of models with unpriced tokens
+    pub unpriced_models: usize,
 }
 
 impl ModelsData {
     /// Create ModelsData from Aggregator::by_model() output
+    #[allow(dead_code)]
     pub fn from_model_usage(model_map: &HashMap<String, ModelUsage>) -> Self {
-        let total_cost: f64 = model_map.values().map(|m| m.cost_usd).sum();
+        Self::from_rows(
+            model_map
+                .iter()
+                .map(|(name, usage)| (name.as_str(), "", usage)),
+        )
+    }
+
+    /// Create ModelsData with one row per model and provider,
+    /// from Aggregator::by_provider_from_daily() output
+    pub fn from_provider_usage(
+        provider_map: &HashMap<String, HashMap<String, ModelUsage>>,
+    ) -> Self {
+        Self::from_rows(provider_map.iter().flat_map(|(provider, models)| {
+            models
+                .iter()
+                .map(move |(name, usage)| (name.as_str(), provider.as_str(), usage))
+        }))
+    }
+
+    /// Create ModelsData with one row per project,
+    /// from Aggregator::by_project_from_daily() output
+    pub fn from_project_usage(project_map: &HashMap<String, ModelUsage>) -> Self {
+        Self::from_rows(
+            project_map
+                .iter()
+                .map(|(project, usage)| (project.as_str(), "", usage)),
+        )
+    }
+
+    /// Build from (model, provider, usage) rows
+    fn from_rows<'a>(rows: impl Iterator<Item = (&'a str, &'a str, &'a ModelUsage)>) -> Self {
+        let rows: Vec<_> = rows.collect();
+        let total_cost: f64 = rows.iter().map(|(_, _, m)| m.cost_usd).sum();
+        let unpriced_tokens = rows.iter().map(|(_, _, m)| m.unpriced_tokens).sum();
+        let unpriced_models = rows
+            .iter()
+            .filter(|(_, _, m)| m.unpriced_tokens > 0)
+            .map(|(name, _, _)| *name)
+            .collect::<BTreeSet<_>>()
+            .len();
 
-        let mut models: Vec<ModelSummary> = model_map
+        let mut models: Vec<ModelSummary> = rows
             .iter()
-            .map(|(name, usage)| {
+            .map(|(name, provider, usage)| {
                 let total_tokens = usage.input_tokens
                     + usage.output_tokens
                     + usage.cache_read_tokens
                     + usage.cache_creation_tokens;
                 ModelSummary {
-                    name: name.clone(),
+                    name: name.to_string(),
+                    provider: provider.to_string(),
                     total_tokens,
                     cost_usd: usage.cost_usd,
+                    token_costs: usage.token_costs,
                 }
             })
             .filter(|m| m.total_tokens > 0) // Filter out zero-token models
@@ -69,21 +118,32 @@ impl ModelsData {
                 .unwrap_or(std::cmp::Ordering::Equal)
         });
 
-        Self { models, total_cost }
+        Self {
+            models,
+            total_cost,
+            unpriced_tokens,
+            unpriced_models,
+        }
     }
 }
 
 /// Maximum content width for Models view (consistent with Overview)
 const MAX_CONTENT_WIDTH: u16 = 170;
 
-/// Table width: Model(30) + Tokens(18) + Cost(12) + Usage(18) = 78
-const TABLE_WIDTH: u16 = 78;
+/// Table width: Model(26) + Provider(18) + Tokens(14) + Cost(12)
+/// + cost by token type (5 × 9) + Usage(16) = 131
+const TABLE_WIDTH: u16 = 131;
+
+/// Headers of the cost-by-type columns, in `TokenCosts::parts` order
+const TOKEN_COST_HEADERS: [&str; 5] = ["Output", "Input", "Write", "Read", "Think"];
 
 /// Models view widget
 pub struct ModelsView<'a> {
     data: &'a ModelsData,
     selected_tab: Tab,
     theme: Theme,
+    /// Rows are projects (from `ModelsData::from_project_usage`) rather than models
+    by_project: bool,
 }
 
 impl<'a> ModelsView<'a> {
@@ -92,6 +152,7 @@ impl<'a> ModelsView<'a> {
             data,
             selected_tab: Tab::Models,
             theme,
+            by_project: false,
         }
     }
 
@@ -99,6 +160,12 @@ impl<'a> ModelsView<'a> {
         self.selected_tab = tab;
         self
     }
+
+    /// Label rows as projects; project paths take the model and provider columns
+    pub fn with_projects(mut self, by_project: bool) -> Self {
+        self.by_project = by_project;
+        self
+    }
 }
 
 impl Widget for ModelsView<'_> {
@@ -115,12 +182,14 @@ impl Widget for ModelsView<'_> {
 
         // Calculate layout with models list
         let max_model_rows = self.data.models.len().min(10) as u16; // Show up to 10 models
+        let unpriced_rows = u16::from(self.data.unpriced_tokens > 0);
         let chunks = Layout::vertical([
             Constraint::Length(1),              // Top padding
             Constraint::Length(1),              // Tabs
             Constraint::Length(1),              // Separator
             Constraint::Length(1),              // Header
             Constraint::Length(max_model_rows), // Model rows
+            Constraint::Length(unpriced_rows),  // Unpriced row
             Constraint::Length(1),              // Separator
             Constraint::Length(1),              // Keybindings
             Constraint::Min(0),                 // Remaining space
@@ -139,11 +208,14 @@ impl Widget for ModelsView<'_> {
         // Render model rows
         self.render_models(chunks[4], buf);
 
+        // Render unpriced row
+        self.render_unpriced(chunks[5], buf);
+
         // Render separator
-        self.render_separator(chunks[5], buf);
+        self.render_separator(chunks[6], buf);
 
         // Render keybindings
-        self.render_keybindings(chunks[6], buf);
+        self.render_keybindings(chunks[7], buf);
     }
 }
 
@@ -171,16 +243,21 @@ impl ModelsView<'_> {
     fn render_header(&self, area: Rect, buf: &mut Buffer) {
         let offset = self.calculate_table_offset(area.width);
 
-        // Column widths: Model(30), Tokens(18), Cost(12), Usage(18)
-        let header = Line::from(vec![
+        // Column widths: Model(26), Provider(18), Tokens(14), Cost(12), 5 × 9, Usage(16)
+        let name_header = if self.by_project {
+            format!("{:<44}", "Project")
+        } else {
+            format!("{:<26}{:<18}", "Model", "Provider")
+        };
+        let mut header = vec![
             Span::styled(
-                format!("{:<30}", "Model"),
+                name_header,
                 Style::default()
                     .fg(self.theme.text())
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                format!("{:>18}", "Tokens"),
+                format!("{:>14}", "Tokens"),
                 Style::default()
                     .fg(self.theme.text())
                     .add_modifier(Modifier::BOLD),
@@ -191,15 +268,24 @@ impl ModelsView<'_> {
                     .fg(self.theme.text())
                     .add_modifier(Modifier::BOLD),
             ),
+        ];
+        // Cost-by-type headers take their series color, as in the breakdown popup
+        header.extend(TOKEN_COST_HEADERS.iter().enumerate().map(|(i, label)| {
             Span::styled(
-                format!("{:>18}", "Usage"),
+                format!("{:>9}", label),
                 Style::default()
-                    .fg(self.theme.text())
+                    .fg(self.theme.series_color(i))
                     .add_modifier(Modifier::BOLD),
-            ),
-        ]);
-
-        let paragraph = Paragraph::new(header).alignment(Alignment::Left);
+            )
+        }));
+        header.push(Span::styled(
+            format!("{:>16}", "Usage"),
+            Style::default()
+                .fg(self.theme.text())
+                .add_modifier(Modifier::BOLD),
+        ));
+
+        let paragraph = Paragraph::new(Line::from(header)).alignment(Alignment::Left);
         paragraph.render(
             Rect {
                 x: area.x + offset,
@@ -234,34 +320,49 @@ impl ModelsView<'_> {
 
             let bar = format_percentage_bar(percent, 14);
 
-            // Convert to display name and truncate if too long (UTF-8 safe)
-            let name = display_name(&model.name);
-            let name = if name.chars().count() > 28 {
-                format!("{}…", name.chars().take(27).collect::<String>())
+            let mut row = if self.by_project {
+                // Paths keep their end, which names the project
+                vec![Span::styled(
+                    format!("{:<44}", truncate_start(&model.name, 42)),
+                    Style::default().fg(self.theme.accent()),
+                )]
             } else {
-                name
+                // Convert to display name and truncate if too long (UTF-8 safe)
+                let name = truncate(display_name(&model.name), 24);
+                let provider = truncate(model.provider.clone(), 16);
+                vec![
+                    Span::styled(
+                        format!("{:<26}", name),
+                        Style::default().fg(self.theme.accent()),
+                    ),
+                    Span::styled(
+                        format!("{:<18}", provider),
+                        Style::default().fg(self.theme.muted()),
+                    ),
+                ]
             };
-
-            let row = Line::from(vec![
-                Span::styled(
-                    format!("{:<30}", name),
-                    Style::default().fg(self.theme.accent()),
-                ),
+            row.extend([
                 Span::styled(
-                    format!("{:>18}", format_number(model.total_tokens)),
+                    format!("{:>14}", format_number(model.total_tokens)),
                     Style::default().fg(self.theme.text()),
                 ),
                 Span::styled(
                     format!("{:>12}", format!("${:.2}", model.cost_usd)),
                     Style::default().fg(self.theme.cost()),
                 ),
-                Span::styled(
-                    format!("{:>18}", bar),
-                    Style::default().fg(self.theme.bar()),
-                ),
             ]);
-
-            let paragraph = Paragraph::new(row).alignment(Alignment::Left);
+            row.extend(model.token_costs.parts().iter().map(|(_, usd)| {
+                Span::styled(
+                    format!("{:>9}", format!("${:.2}", usd)),
+                    Style::default().fg(self.theme.muted()),
+                )
+            }));
+            row.push(Span::styled(
+                format!("{:>16}", bar),
+                Style::default().fg(self.theme.bar()),
+            ));
+
+            let paragraph = Paragraph::new(Line::from(row)).alignment(Alignment::Left);
             paragraph.render(
                 Rect {
                     x: area.x + offset,
@@ -274,6 +375,38 @@ impl ModelsView<'_> {
         }
     }
 
+    /// Tokens left out of the costs because their models have no known price
+    fn render_unpriced(&self, area: Rect, buf: &mut Buffer) {
+        if area.height == 0 {
+            return;
+        }
+        let offset = self.calculate_table_offset(area.width);
+        let style = Style::default().fg(self.theme.spike_warn());
+        let noun = if self.by_project { "project" } else { "model" };
+        let label = match self.data.unpriced_models {
+            1 => format!("Unpriced (1 {})", noun),
+            n => format!("Unpriced ({} {}s)", n, noun),
+        };
+
+        let row = Line::from(vec![
+            Span::styled(format!("{:<44}", label), style),
+            Span::styled(
+                format!("{:>14}", format_number(self.data.unpriced_tokens)),
+                style,
+            ),
+            Span::styled(format!("{:>12}", "?"), style),
+        ]);
+        Paragraph::new(row).render(
+            Rect {
+                x: area.x + offset,
+                y: area.y,
+                width: TABLE_WIDTH.min(area.width),
+                height: 1,
+            },
+            buf,
+        );
+    }
+
     fn render_keybindings(&self, area: Rect, buf: &mut Buffer) {
         let bindings = Paragraph::new(Line::from(vec![
             Span::styled("Ctrl+C", Style::default().fg(self.theme.accent())),
@@ -282,6 +415,16 @@ impl ModelsView<'_> {
             Span::styled("Tab", Style::default().fg(self.theme.accent())),
             Span::styled(": Switch view", Style::default().fg(self.theme.muted())),
             Span::raw("  "),
+            Span::styled("p", Style::default().fg(self.theme.accent())),
+            Span::styled(
+                if self.by_project {
+                    ": By model"
+                } else {
+                    ": By project"
+                },
+                Style::default().fg(self.theme.muted()),
+            ),
+            Span::raw("  "),
             Span::styled("?", Style::default().fg(self.theme.accent())),
             Span::styled(": Help", Style::default().fg(self.theme.muted())),
         ]))
@@ -291,9 +434,29 @@ impl ModelsView<'_> {
     }
 }
 
+/// Keep the last `max` characters of `s`, starting with an ellipsis when shortened
+fn truncate_start(s: &str, max: usize) -> String {
+    let len = s.chars().count();
+    if len > max {
+        format!("…{}", s.chars().skip(len - (max - 1)).collect::<String>())
+    } else {
+        s.to_string()
+    }
+}
+
+/// Cut `s` to `max` characters, ending in an ellipsis when shortened (UTF-8 safe)
+fn truncate(s: String, max: usize) -> String {
+    if s.chars().count() > max {
+        format!("{}…", s.chars().take(max - 1).collect::<String>())
+    } else {
+        s
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::BTreeMap;
 
     // ========== format_percentage_bar tests ==========
 
@@ -345,9 +508,18 @@ mod tests {
                 output_tokens: 500,
                 cache_read_tokens: 100,
                 cache_creation_tokens: 50,
+                cache_creation_1h_tokens: 0,
                 thinking_tokens: 0,
                 cost_usd: 0.05,
                 count: 10,
+                web_search_requests: 0,
+                web_search_cost_usd: 0.0,
+                service_tier_cost_usd: 0.0,
+                unpriced_tokens: 0,
+                token_costs: TokenCosts::default(),
+                cost_reported: false,
+                max_prompt_tokens: None,
+                tier_tokens: BTreeMap::new(),
             },
         );
 
@@ -370,9 +542,18 @@ mod tests {
                 output_tokens: 50,
                 cache_read_tokens: 0,
                 cache_creation_tokens: 0,
+                cache_creation_1h_tokens: 0,
                 thinking_tokens: 0,
                 cost_usd: 0.01,
                 count: 1,
+                web_search_requests: 0,
+                web_search_cost_usd: 0.0,
+                service_tier_cost_usd: 0.0,
+                unpriced_tokens: 0,
+                token_costs: TokenCosts::default(),
+                cost_reported: false,
+                max_prompt_tokens: None,
+                tier_tokens: BTreeMap::new(),
             },
         );
         model_map.insert(
@@ -382,9 +563,18 @@ mod tests {
                 output_tokens: 500,
                 cache_read_tokens: 0,
                 cache_creation_tokens: 0,
+                cache_creation_1h_tokens: 0,
                 thinking_tokens: 0,
                 cost_usd: 0.50,
                 count: 5,
+                web_search_requests: 0,
+                web_search_cost_usd: 0.0,
+                service_tier_cost_usd: 0.0,
+                unpriced_tokens: 0,
+                token_costs: TokenCosts::default(),
+                cost_reported: false,
+                max_prompt_tokens: None,
+                tier_tokens: BTreeMap::new(),
             },
         );
         model_map.insert(
@@ -394,9 +584,18 @@ mod tests {
                 output_tokens: 250,
                 cache_read_tokens: 0,
                 cache_creation_tokens: 0,
+                cache_creation_1h_tokens: 0,
                 thinking_tokens: 0,
                 cost_usd: 0.10,
                 count: 3,
+                web_search_requests: 0,
+                web_search_cost_usd: 0.0,
+                service_tier_cost_usd: 0.0,
+                unpriced_tokens: 0,
+                token_costs: TokenCosts::default(),
+                cost_reported: false,
+                max_prompt_tokens: None,
+                tier_tokens: BTreeMap::new(),
             },
         );
 
@@ -409,6 +608,39 @@ mod tests {
         assert_eq!(data.models[2].name, "claude-haiku");
     }
 
+    #[test]
+    fn test_unpriced_row_shown_only_with_unpriced_tokens() {
+        let render = |model_map: &HashMap<String, ModelUsage>| {
+            let data = ModelsData::from_model_usage(model_map);
+            let area = Rect::new(0, 0, 100, 20);
+            let mut buf = Buffer::empty(area);
+            ModelsView::new(&data, Theme::Dark).render(area, &mut buf);
+            buf.content().iter().map(|c| c.symbol()).collect::<String>()
+        };
+
+        let mut model_map = HashMap::from([(
+            "claude-sonnet-4".to_string(),
+            ModelUsage {
+                input_tokens: 1000,
+                cost_usd: 0.01,
+                ..ModelUsage::default()
+            },
+        )]);
+        assert!(!render(&model_map).contains("Unpriced"));
+
+        model_map.insert(
+            "internal-llm".to_string(),
+            ModelUsage {
+                input_tokens: 2000,
+                unpriced_tokens: 2000,
+                ..ModelUsage::default()
+            },
+        );
+        let data = ModelsData::from_model_usage(&model_map);
+        assert_eq!((data.unpriced_tokens, data.unpriced_models), (2000, 1));
+        assert!(render(&model_map).contains("Unpriced (1 model)"));
+    }
+
     #[test]
     fn test_models_data_total_cost() {
         let mut model_map: HashMap<String, ModelUsage> = HashMap::new();
@@ -419,9 +651,18 @@ mod tests {
                 output_tokens: 50,
                 cache_read_tokens: 0,
                 cache_creation_tokens: 0,
+                cache_creation_1h_tokens: 0,
                 thinking_tokens: 0,
                 cost_usd: 0.10,
                 count: 1,
+                web_search_requests: 0,
+                web_search_cost_usd: 0.0,
+                service_tier_cost_usd: 0.0,
+                unpriced_tokens: 0,
+                token_costs: TokenCosts::default(),
+                cost_reported: false,
+                max_prompt_tokens: None,
+                tier_tokens: BTreeMap::new(),
             },
         );
         model_map.insert(
@@ -431,9 +672,18 @@ mod tests {
                 output_tokens: 100,
                 cache_read_tokens: 0,
                 cache_creation_tokens: 0,
+                cache_creation_1h_tokens: 0,
                 thinking_tokens: 0,
                 cost_usd: 0.20,
                 count: 1,
+                web_search_requests: 0,
+                web_search_cost_usd: 0.0,
+                service_tier_cost_usd: 0.0,
+                unpriced_tokens: 0,
+                token_costs: TokenCosts::default(),
+                cost_reported: false,
+                max_prompt_tokens: None,
+                tier_tokens: BTreeMap::new(),
             },
         );
 
@@ -441,4 +691,118 @@ mod tests {
 
         assert!((data.total_cost - 0.30).abs() < f64::EPSILON);
     }
+
+    #[test]
+    fn test_models_data_rows_per_provider() {
+        let usage = |input_tokens, cost_usd, unpriced_tokens| ModelUsage {
+            input_tokens,
+            cost_usd,
+            unpriced_tokens,
+            ..ModelUsage::default()
+        };
+        let provider_map = HashMap::from([
+            (
+                "anthropic".to_string(),
+                HashMap::from([("claude-sonnet-4".to_string(), usage(1000, 0.30, 0))]),
+            ),
+            (
+                "github-copilot".to_string(),
+                HashMap::from([
+                    ("claude-sonnet-4".to_string(), usage(4000, 0.0, 0)),
+                    ("internal-llm".to_string(), usage(500, 0.0, 500)),
+                ]),
+            ),
+        ]);
+
+        let data = ModelsData::from_provider_usage(&provider_map);
+        assert_eq!(data.models.len(), 3);
+        assert_eq!(
+            (
+                data.models[0].name.as_str(),
+                data.models[0].provider.as_str()
+            ),
+            ("claude-sonnet-4", "anthropic")
+        );
+        assert!((data.total_cost - 0.30).abs() < f64::EPSILON);
+        assert_eq!((data.unpriced_tokens, data.unpriced_models), (500, 1));
+
+        let area = Rect::new(0, 0, 100, 20);
+        let mut buf = Buffer::empty(area);
+        ModelsView::new(&data, Theme::Dark).render(area, &mut buf);
+        let rendered: String = buf.content().iter().map(|c| c.symbol()).collect();
+        assert!(rendered.contains("Provider"));
+        assert!(rendered.contains("github-copilot"));
+    }
+
+    #[test]
+    fn test_models_view_by_project() {
+        let project_map = HashMap::from([
+            (
+                "/home/me/work/clients/acme/very-long-repository-name".to_string(),
+                ModelUsage {
+                    input_tokens: 1000,
+                    cost_usd: 2.0,
+                    ..ModelUsage::default()
+                },
+            ),
+            (
+                "/home/me/my_app".to_string(),
+                ModelUsage {
+                    input_tokens: 500,
+                    cost_usd: 0.5,
+                    ..ModelUsage::default()
+                },
+            ),
+        ]);
+        let data = ModelsData::from_project_usage(&project_map);
+        assert_eq!(data.models[1].name, "/home/me/my_app");
+        assert!((data.total_cost - 2.5).abs() < f64::EPSILON);
+
+        let area = Rect::new(0, 0, 140, 20);
+        let mut buf = Buffer::empty(area);
+        ModelsView::new(&data, Theme::Dark)
+            .with_projects(true)
+            .render(area, &mut buf);
+        let rendered: String = buf.content().iter().map(|c| c.symbol()).collect();
+        assert!(rendered.contains("Project"));
+        assert!(!rendered.contains("Provider"));
+        assert!(rendered.contains("/home/me/my_app"));
+        assert!(rendered.contains("…rk/clients/acme/very-long-repository-name"));
+        assert!(rendered.contains("p: By model"));
+    }
+
+    #[test]
+    fn test_truncate_start_keeps_end() {
+        assert_eq!(truncate_start("/a/b", 10), "/a/b");
+        assert_eq!(truncate_start("/home/me/app", 6), "…e/app");
+    }
+
+    #[test]
+    fn test_models_view_shows_cost_by_token_type() {
+        let model_map = HashMap::from([(
+            "claude-sonnet-4".to_string(),
+            ModelUsage {
+                output_tokens: 1000,
+                cache_read_tokens: 9000,
+                cost_usd: 12.5,
+                token_costs: TokenCosts {
+                    output_usd: 9.75,
+                    cache_read_usd: 2.75,
+                    ..TokenCosts::default()
+                },
+                ..ModelUsage::default()
+            },
+        )]);
+        let data = ModelsData::from_model_usage(&model_map);
+        assert_eq!(data.models[0].token_costs.output_usd, 9.75);
+
+        let area = Rect::new(0, 0, 140, 20);
+        let mut buf = Buffer::empty(area);
+        ModelsView::new(&data, Theme::Dark).render(area, &mut buf);
+        let rendered: String = buf.content().iter().map(|c| c.symbol()).collect();
+        for header in TOKEN_COST_HEADERS {
+            assert!(rendered.contains(header));
+        }
+        assert!(rendered.contains("$12.50    $9.75    $0.00    $0.00    $2.75    $0.00"));
+    }
 }
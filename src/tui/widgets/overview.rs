@@ -12,7 +12,8 @@ use ratatui::{
 use super::heatmap::Heatmap;
 use super::legend::Legend;
 use super::tabs::{Tab, TabBar};
-use crate::tui::theme::Theme;
+use crate::services::BudgetStatus;
+use crate::tui::theme::{budget_level, SpikeLevel, Theme};
 use crate::types::{SourceUsage, TotalSummary};
 
 /// Format a number with thousand separators (e.g., 1234567 -> "1,234,567")
@@ -37,18 +38,32 @@ pub fn format_number(n: u64) -> String {
     result
 }
 
+/// Right-align `name` in `width` columns, truncating with an ellipsis if needed
+fn fit_name(name: &str, width: usize) -> String {
+    let name = if name.chars().count() > width - 1 {
+        format!("{}…", name.chars().take(width - 2).collect::<String>())
+    } else {
+        name.to_string()
+    };
+    format!("{:>width$}", name, width = width)
+}
+
 /// Data for the overview display (references to avoid cloning)
 #[derive(Debug)]
 pub struct OverviewData<'a> {
     pub total: &'a TotalSummary,
     pub daily_tokens: &'a [(NaiveDate, u64)],
     pub source_usage: &'a [SourceUsage],
+    pub budgets: &'a [BudgetStatus],
 }
 
 /// Maximum content width for Overview (keeps layout clean on wide terminals)
 /// 52 weeks * 3-char cells + 4 label = 160, so 170 gives some padding
 const MAX_CONTENT_WIDTH: u16 = 170;
 
+/// Maximum number of budgets listed on the Overview
+const MAX_BUDGET_ROWS: usize = 4;
+
 /// Overview widget combining all elements
 pub struct Overview<'a> {
     data: OverviewData<'a>,
@@ -89,43 +104,43 @@ impl Widget for Overview<'_> {
         let source_rows = self.data.source_usage.len().min(4) as u16;
         let show_sources = source_rows > 0;
 
+        // Budget section height (1 row per budget, 0-4 budgets shown)
+        let budget_rows = self.data.budgets.len().min(MAX_BUDGET_ROWS) as u16;
+        let show_budgets = budget_rows > 0;
+
+        // Optional sections (label + rows + blank) collapse to zero height when empty
+        let section = |show: bool, rows: u16| {
+            if show {
+                [1, rows, 1]
+            } else {
+                [0, 0, 0]
+            }
+        };
+        let [sources_label, sources, sources_blank] = section(show_sources, source_rows);
+        let [budgets_label, budgets, budgets_blank] = section(show_budgets, budget_rows);
+
         // Fixed-height layout (no expansion, keybindings stay with content):
         // - Top padding (1) + Tabs (1) + Separator (1) + Hero (3) + Sub-stats (1) + Blank (1)
-        // - [Sources section if present]
+        // - [Sources section if present] + [Budgets section if present]
         // - Heatmap (10: 7 rows grid + month labels + blank + legend) + Separator (1) + Keybindings (1)
-        let chunks = if show_sources {
-            Layout::vertical([
-                Constraint::Length(1),           // 0: Top padding
-                Constraint::Length(1),           // 1: Tabs
-                Constraint::Length(1),           // 2: Separator
-                Constraint::Length(3),           // 3: Hero stat
-                Constraint::Length(1),           // 4: Sub-stats (Cost only)
-                Constraint::Length(1),           // 5: Blank
-                Constraint::Length(1),           // 6: "Sources:" label
-                Constraint::Length(source_rows), // 7: Source bars
-                Constraint::Length(1),           // 8: Blank
-                Constraint::Length(10),          // 9: Heatmap
-                Constraint::Length(1),           // 10: Separator
-                Constraint::Length(1),           // 11: Keybindings
-            ])
-            .split(centered_area)
-        } else {
-            Layout::vertical([
-                Constraint::Length(1),  // 0: Top padding
-                Constraint::Length(1),  // 1: Tabs
-                Constraint::Length(1),  // 2: Separator
-                Constraint::Length(3),  // 3: Hero stat
-                Constraint::Length(1),  // 4: Sub-stats (Cost only)
-                Constraint::Length(1),  // 5: Blank
-                Constraint::Length(0),  // 6: (empty, Sources label placeholder)
-                Constraint::Length(0),  // 7: (empty, Source bars placeholder)
-                Constraint::Length(0),  // 8: (empty, Blank placeholder)
-                Constraint::Length(10), // 9: Heatmap
-                Constraint::Length(1),  // 10: Separator
-                Constraint::Length(1),  // 11: Keybindings
-            ])
-            .split(centered_area)
-        };
+        let chunks = Layout::vertical([
+            Constraint::Length(1),             // 0: Top padding
+            Constraint::Length(1),             // 1: Tabs
+            Constraint::Length(1),             // 2: Separator
+            Constraint::Length(3),             // 3: Hero stat
+            Constraint::Length(1),             // 4: Sub-stats (Cost only)
+            Constraint::Length(1),             // 5: Blank
+            Constraint::Length(sources_label), // 6: "Sources:" label
+            Constraint::Length(sources),       // 7: Source bars
+            Constraint::Length(sources_blank), // 8: Blank
+            Constraint::Length(budgets_label), // 9: "Budgets:" label
+            Constraint::Length(budgets),       // 10: Budget bars
+            Constraint::Length(budgets_blank), // 11: Blank
+            Constraint::Length(10),            // 12: Heatmap
+            Constraint::Length(1),             // 13: Separator
+            Constraint::Length(1),             // 14: Keybindings
+        ])
+        .split(centered_area);
 
         // Top padding (chunks[0]) - nothing to render
 
@@ -145,19 +160,26 @@ impl Widget for Overview<'_> {
 
         // Render sources section if present
         if show_sources {
-            self.render_sources_label(chunks[6], buf);
+            self.render_section_label("Sources:", chunks[6], buf);
             self.render_source_bars(chunks[7], buf);
             // chunks[8] is blank
         }
 
+        // Render budgets section if present
+        if show_budgets {
+            self.render_section_label("Budgets:", chunks[9], buf);
+            self.render_budget_bars(chunks[10], buf);
+            // chunks[11] is blank
+        }
+
         // Render heatmap with legend
-        self.render_heatmap_section(chunks[9], buf);
+        self.render_heatmap_section(chunks[12], buf);
 
         // Render separator
-        self.render_separator(chunks[10], buf);
+        self.render_separator(chunks[13], buf);
 
         // Render keybindings
-        self.render_keybindings(chunks[11], buf);
+        self.render_keybindings(chunks[14], buf);
     }
 }
 
@@ -214,9 +236,9 @@ impl Overview<'_> {
         stats.render(area, buf);
     }
 
-    fn render_sources_label(&self, area: Rect, buf: &mut Buffer) {
+    fn render_section_label(&self, text: &str, area: Rect, buf: &mut Buffer) {
         let label = Paragraph::new(Line::from(Span::styled(
-            "Sources:",
+            text,
             Style::default()
                 .fg(self.theme.text())
                 .add_modifier(Modifier::BOLD),
@@ -254,19 +276,7 @@ impl Overview<'_> {
             }
 
             // Source name (left-padded, fixed width)
-            let name = if source.source.chars().count() > SOURCE_NAME_WIDTH - 1 {
-                format!(
-                    "{}…",
-                    source
-                        .source
-                        .chars()
-                        .take(SOURCE_NAME_WIDTH - 2)
-                        .collect::<String>()
-                )
-            } else {
-                source.source.clone()
-            };
-            let name_display = format!("{:>width$}", name, width = SOURCE_NAME_WIDTH);
+            let name_display = fit_name(&source.source, SOURCE_NAME_WIDTH);
 
             // Bar representation
             let ratio = source.total_tokens as f64 / max_tokens as f64;
@@ -292,6 +302,51 @@ impl Overview<'_> {
         }
     }
 
+    fn render_budget_bars(&self, area: Rect, buf: &mut Buffer) {
+        const LABEL_WIDTH: usize = 20;
+        const BAR_WIDTH: usize = 20;
+        const TOTAL_LINE_WIDTH: usize = LABEL_WIDTH + 2 + BAR_WIDTH + 2 + 25; // label + "  " + bar + "  " + amounts
+
+        let x_offset = area.width.saturating_sub(TOTAL_LINE_WIDTH as u16) / 2;
+
+        for (i, status) in self.data.budgets.iter().take(MAX_BUDGET_ROWS).enumerate() {
+            let y = area.y + i as u16;
+            if y >= area.y + area.height {
+                break;
+            }
+
+            let level = budget_level(status.state());
+            let bar_color = match level {
+                SpikeLevel::Normal => self.theme.bar(),
+                _ => self.theme.spike_color(level),
+            };
+
+            // Bar fills at 100% of the limit; overspend is shown by color and percentage
+            let filled = (status.ratio().min(1.0) * BAR_WIDTH as f64).round() as usize;
+            let filled = filled.min(BAR_WIDTH);
+            let bar = format!("{}{}", "█".repeat(filled), "░".repeat(BAR_WIDTH - filled));
+
+            let amounts = format!(
+                "${:.2} / ${:.2} ({:.0}%)",
+                status.spent_usd,
+                status.budget.limit_usd,
+                status.ratio() * 100.0
+            );
+
+            let line = Line::from(vec![
+                Span::styled(
+                    fit_name(&status.budget.label(), LABEL_WIDTH),
+                    Style::default().fg(self.theme.muted()),
+                ),
+                Span::raw("  "),
+                Span::styled(bar, Style::default().fg(bar_color)),
+                Span::raw("  "),
+                Span::styled(amounts, Style::default().fg(self.theme.spike_color(level))),
+            ]);
+            buf.set_line(area.x + x_offset, y, &line, area.width - x_offset);
+        }
+    }
+
     fn render_heatmap_section(&self, area: Rect, buf: &mut Buffer) {
         const HEATMAP_GRID_ROWS: u16 = 7;
         const MONTH_LABEL_ROWS: u16 = 1;
@@ -370,4 +425,47 @@ mod tests {
     fn test_format_number_million() {
         assert_eq!(format_number(1000000), "1,000,000");
     }
+
+    // ========== budget section tests ==========
+
+    #[test]
+    fn test_render_budget_rows() {
+        use crate::services::budget::{Budget, BudgetPeriod};
+
+        let today = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+        let budgets = [BudgetStatus {
+            budget: Budget {
+                period: BudgetPeriod::Monthly,
+                limit_usd: 100.0,
+                source: Some("codex".into()),
+                model: None,
+            },
+            start: NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(),
+            end: NaiveDate::from_ymd_opt(2026, 10, 31).unwrap(),
+            spent_usd: 120.0,
+        }];
+        let total = TotalSummary::default();
+        let data = OverviewData {
+            total: &total,
+            daily_tokens: &[],
+            source_usage: &[],
+            budgets: &budgets,
+        };
+
+        let area = Rect::new(0, 0, 120, 30);
+        let mut buf = Buffer::empty(area);
+        Overview::new(data, today, Theme::Dark).render(area, &mut buf);
+
+        let content: String = buf.content().iter().map(|c| c.symbol()).collect();
+        assert!(content.contains("Budgets:"));
+        assert!(content.contains("monthly codex"));
+        assert!(content.contains("$120.00 / $100.00 (120%)"));
+        assert!(!content.contains("Sources:"));
+
+        // Exceeded budgets use the high spike color
+        let amount = content.find("$120.00").unwrap();
+        let x = content[..amount].chars().count() as u16 % area.width;
+        let y = content[..amount].chars().count() as u16 / area.width;
+        assert_eq!(buf[(x, y)].fg, Theme::Dark.spike_high());
+    }
 }
@@ -0,0 +1,440 @@
+//! Sessions view widget - displays per-session usage
+
+use chrono::{DateTime, Local};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Paragraph, Widget},
+};
+
+use super::overview::format_number;
+use super::tabs::{Tab, TabBar};
+use crate::services::display_name;
+use crate::tui::theme::Theme;
+use crate::types::SessionSummary;
+
+/// Format a duration in seconds as a compact string
+/// Example: 45 → "45s", 750 → "12m", 3900 → "1h 05m"
+pub fn format_duration(secs: i64) -> String {
+    let secs = secs.max(0);
+    if secs < 60 {
+        format!("{}s", secs)
+    } else if secs < 3600 {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{}h {:02}m", secs / 3600, (secs % 3600) / 60)
+    }
+}
+
+/// Format a session's last activity, with the date only if it differs from the start's
+/// Example: same day → "14:05", next day → "03-11 00:40"
+pub fn format_last_active(start: DateTime<Local>, end: DateTime<Local>) -> String {
+    if end.date_naive() == start.date_naive() {
+        end.format("%H:%M").to_string()
+    } else {
+        end.format("%m-%d %H:%M").to_string()
+    }
+}
+
+/// Session row for display
+#[derive(Debug, Clone)]
+pub struct SessionRow {
+    pub start: DateTime<Local>,
+    /// Time of the last request
+    pub end: DateTime<Local>,
+    pub duration_secs: i64,
+    pub source: String,
+    /// Highest-cost model, with "+N" when the session used several
+    pub models: String,
+    pub total_tokens: u64,
+    pub cost_usd: f64,
+}
+
+/// Data for the sessions view
+#[derive(Debug, Default)]
+pub struct SessionsData {
+    /// Sessions sorted by start time descending (most recent first)
+    pub sessions: Vec<SessionRow>,
+}
+
+impl SessionsData {
+    /// Create SessionsData from Aggregator::by_session() output
+    pub fn from_sessions(sessions: &[SessionSummary]) -> Self {
+        let mut rows: Vec<SessionRow> = sessions
+            .iter()
+            .map(|s| SessionRow {
+                start: s.start.with_timezone(&Local),
+                end: s.end.with_timezone(&Local),
+                duration_secs: s.duration_secs,
+                source: s.source.clone(),
+                models: summarize_models(s),
+                total_tokens: s.total_tokens(),
+                cost_usd: s.total_cost_usd,
+            })
+            .collect();
+
+        rows.sort_by_key(|r| std::cmp::Reverse(r.start));
+
+        Self { sessions: rows }
+    }
+
+    /// Maximum scroll offset for the given number of sessions
+    pub fn max_scroll_offset(&self) -> usize {
+        self.sessions.len().saturating_sub(VISIBLE_ROWS)
+    }
+}
+
+/// Primary model (by cost, then tokens) plus a count of the others
+fn summarize_models(session: &SessionSummary) -> String {
+    let primary = session.models.iter().max_by(|(a_name, a), (b_name, b)| {
+        a.cost_usd
+            .partial_cmp(&b.cost_usd)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| {
+                (a.input_tokens + a.output_tokens).cmp(&(b.input_tokens + b.output_tokens))
+            })
+            // Deterministic tie-break on name
+            .then_with(|| b_name.cmp(a_name))
+    });
+
+    match primary {
+        Some((name, _)) if session.models.len() > 1 => {
+            format!("{} +{}", display_name(name), session.models.len() - 1)
+        }
+        Some((name, _)) => display_name(name),
+        None => "-".to_string(),
+    }
+}
+
+/// Maximum content width for Sessions view (consistent with other views)
+const MAX_CONTENT_WIDTH: u16 = 170;
+
+/// Visible rows for scrolling (excluding header)
+pub const VISIBLE_ROWS: usize = 15;
+
+/// Table width: Start(18) + Last Active(13) + Duration(10) + Source(10) + Models(30)
+/// + Tokens(16) + Cost(12) = 109
+const TABLE_WIDTH: u16 = 109;
+
+/// Sessions view widget
+pub struct SessionsView<'a> {
+    data: Option<&'a SessionsData>,
+    scroll_offset: usize,
+    selected_tab: Tab,
+    theme: Theme,
+}
+
+impl<'a> SessionsView<'a> {
+    /// Create a sessions view; `None` renders a loading placeholder
+    pub fn new(data: Option<&'a SessionsData>, scroll_offset: usize, theme: Theme) -> Self {
+        Self {
+            data,
+            scroll_offset,
+            selected_tab: Tab::Sessions,
+            theme,
+        }
+    }
+
+    pub fn with_tab(mut self, tab: Tab) -> Self {
+        self.selected_tab = tab;
+        self
+    }
+}
+
+impl Widget for SessionsView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // Apply max width constraint and center the content
+        let content_width = area.width.min(MAX_CONTENT_WIDTH);
+        let x_offset = (area.width.saturating_sub(content_width)) / 2;
+        let centered_area = Rect {
+            x: area.x + x_offset,
+            y: area.y,
+            width: content_width,
+            height: area.height,
+        };
+
+        let row_count = match self.data {
+            Some(data) if !data.sessions.is_empty() => data.sessions.len().min(VISIBLE_ROWS),
+            _ => 1, // Placeholder message
+        } as u16;
+        let chunks = Layout::vertical([
+            Constraint::Length(1),         // Top padding
+            Constraint::Length(1),         // Tabs
+            Constraint::Length(1),         // Separator
+            Constraint::Length(1),         // Header
+            Constraint::Length(row_count), // Session rows
+            Constraint::Length(1),         // Separator
+            Constraint::Length(1),         // Keybindings
+            Constraint::Min(0),            // Remaining space
+        ])
+        .split(centered_area);
+
+        self.render_tabs(chunks[1], buf);
+        self.render_separator(chunks[2], buf);
+        self.render_header(chunks[3], buf);
+        self.render_sessions(chunks[4], buf);
+        self.render_separator(chunks[5], buf);
+        self.render_keybindings(chunks[6], buf);
+    }
+}
+
+impl SessionsView<'_> {
+    /// Calculate horizontal offset to center the table
+    fn calculate_table_offset(&self, area_width: u16) -> u16 {
+        area_width.saturating_sub(TABLE_WIDTH) / 2
+    }
+
+    fn table_rect(&self, area: Rect, y: u16) -> Rect {
+        let offset = self.calculate_table_offset(area.width);
+        Rect {
+            x: area.x + offset,
+            y,
+            width: TABLE_WIDTH.min(area.width),
+            height: 1,
+        }
+    }
+
+    fn render_tabs(&self, area: Rect, buf: &mut Buffer) {
+        let tab_bar = TabBar::new(self.selected_tab, self.theme);
+        tab_bar.render(area, buf);
+    }
+
+    fn render_separator(&self, area: Rect, buf: &mut Buffer) {
+        let line = "─".repeat(area.width as usize);
+        buf.set_string(
+            area.x,
+            area.y,
+            &line,
+            Style::default().fg(self.theme.muted()),
+        );
+    }
+
+    fn render_header(&self, area: Rect, buf: &mut Buffer) {
+        let bold = Style::default()
+            .fg(self.theme.text())
+            .add_modifier(Modifier::BOLD);
+        let header = Line::from(vec![
+            Span::styled(format!("{:<18}", "Start"), bold),
+            Span::styled(format!("{:<13}", "Last Active"), bold),
+            Span::styled(format!("{:>10}", "Duration"), bold),
+            Span::styled(format!("  {:<8}", "Source"), bold),
+            Span::styled(format!("{:<30}", "Models"), bold),
+            Span::styled(format!("{:>16}", "Tokens"), bold),
+            Span::styled(format!("{:>12}", "Cost"), bold),
+        ]);
+
+        Paragraph::new(header)
+            .alignment(Alignment::Left)
+            .render(self.table_rect(area, area.y), buf);
+    }
+
+    fn render_sessions(&self, area: Rect, buf: &mut Buffer) {
+        let data = match self.data {
+            Some(data) if !data.sessions.is_empty() => data,
+            Some(_) => {
+                self.render_placeholder(area, buf, "No sessions found");
+                return;
+            }
+            None => {
+                self.render_placeholder(area, buf, "Loading sessions...");
+                return;
+            }
+        };
+
+        for (i, session) in data
+            .sessions
+            .iter()
+            .skip(self.scroll_offset)
+            .take(area.height as usize)
+            .enumerate()
+        {
+            let y = area.y + i as u16;
+
+            // Truncate model summary if too long (UTF-8 safe)
+            let models = if session.models.chars().count() > 28 {
+                format!("{}…", session.models.chars().take(27).collect::<String>())
+            } else {
+                session.models.clone()
+            };
+
+            let row = Line::from(vec![
+                Span::styled(
+                    format!("{:<18}", session.start.format("%Y-%m-%d %H:%M")),
+                    Style::default().fg(self.theme.date()),
+                ),
+                Span::styled(
+                    format!("{:<13}", format_last_active(session.start, session.end)),
+                    Style::default().fg(self.theme.date()),
+                ),
+                Span::styled(
+                    format!("{:>10}", format_duration(session.duration_secs)),
+                    Style::default().fg(self.theme.text()),
+                ),
+                Span::styled(
+                    format!("  {:<8}", session.source),
+                    Style::default().fg(self.theme.muted()),
+                ),
+                Span::styled(
+                    format!("{:<30}", models),
+                    Style::default().fg(self.theme.accent()),
+                ),
+                Span::styled(
+                    format!("{:>16}", format_number(session.total_tokens)),
+                    Style::default().fg(self.theme.text()),
+                ),
+                Span::styled(
+                    format!("{:>12}", format!("${:.2}", session.cost_usd)),
+                    Style::default().fg(self.theme.cost()),
+                ),
+            ]);
+
+            Paragraph::new(row)
+                .alignment(Alignment::Left)
+                .render(self.table_rect(area, y), buf);
+        }
+    }
+
+    fn render_placeholder(&self, area: Rect, buf: &mut Buffer, text: &str) {
+        Paragraph::new(Line::from(Span::styled(
+            text,
+            Style::default().fg(self.theme.muted()),
+        )))
+        .alignment(Alignment::Center)
+        .render(area, buf);
+    }
+
+    fn render_keybindings(&self, area: Rect, buf: &mut Buffer) {
+        let bindings = Paragraph::new(Line::from(vec![
+            Span::styled("↑↓", Style::default().fg(self.theme.accent())),
+            Span::styled(": Scroll", Style::default().fg(self.theme.muted())),
+            Span::raw("  "),
+            Span::styled("Tab", Style::default().fg(self.theme.accent())),
+            Span::styled(": Switch view", Style::default().fg(self.theme.muted())),
+            Span::raw("  "),
+            Span::styled("?", Style::default().fg(self.theme.accent())),
+            Span::styled(": Help", Style::default().fg(self.theme.muted())),
+        ]))
+        .alignment(Alignment::Center);
+
+        bindings.render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ModelUsage, TokenCosts};
+    use chrono::{TimeZone, Utc};
+    use std::collections::{BTreeMap, HashMap};
+
+    fn make_session(id: &str, start_hour: u32, models: &[(&str, f64)]) -> SessionSummary {
+        let start = Utc.with_ymd_and_hms(2025, 3, 10, start_hour, 0, 0).unwrap();
+        let mut model_map = HashMap::new();
+        for (name, cost) in models {
+            model_map.insert(
+                name.to_string(),
+                ModelUsage {
+                    input_tokens: 100,
+                    output_tokens: 50,
+                    cache_read_tokens: 0,
+                    cache_creation_tokens: 0,
+                    cache_creation_1h_tokens: 0,
+                    thinking_tokens: 0,
+                    cost_usd: *cost,
+                    count: 1,
+                    web_search_requests: 0,
+                    web_search_cost_usd: 0.0,
+                    service_tier_cost_usd: 0.0,
+                    unpriced_tokens: 0,
+                    token_costs: TokenCosts::default(),
+                    cost_reported: false,
+                    max_prompt_tokens: None,
+                    tier_tokens: BTreeMap::new(),
+                },
+            );
+        }
+        SessionSummary {
+            session_id: id.to_string(),
+            source: "claude".to_string(),
+            project: None,
+            start,
+            end: start + chrono::Duration::minutes(30),
+            duration_secs: 1800,
+            total_input_tokens: 100 * models.len() as u64,
+            total_output_tokens: 50 * models.len() as u64,
+            total_cache_read_tokens: 0,
+            total_cache_creation_tokens: 0,
+            total_thinking_tokens: 0,
+            total_cost_usd: models.iter().map(|(_, c)| c).sum(),
+            models: model_map,
+        }
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(0), "0s");
+        assert_eq!(format_duration(45), "45s");
+        assert_eq!(format_duration(750), "12m");
+        assert_eq!(format_duration(3900), "1h 05m");
+        assert_eq!(format_duration(-5), "0s");
+    }
+
+    #[test]
+    fn test_format_last_active() {
+        let start = Local.with_ymd_and_hms(2025, 3, 10, 23, 30, 0).unwrap();
+        assert_eq!(
+            format_last_active(start, start + chrono::Duration::minutes(20)),
+            "23:50"
+        );
+        assert_eq!(
+            format_last_active(start, start + chrono::Duration::minutes(70)),
+            "03-11 00:40"
+        );
+    }
+
+    #[test]
+    fn test_sessions_data_sorted_most_recent_first() {
+        let sessions = vec![
+            make_session("a", 8, &[("claude-sonnet-4", 0.1)]),
+            make_session("b", 12, &[("claude-sonnet-4", 0.2)]),
+        ];
+        let data = SessionsData::from_sessions(&sessions);
+
+        assert_eq!(data.sessions.len(), 2);
+        assert!(data.sessions[0].start > data.sessions[1].start);
+        assert_eq!(data.sessions[0].total_tokens, 150);
+        assert_eq!(
+            data.sessions[0].end - data.sessions[0].start,
+            chrono::Duration::minutes(30)
+        );
+    }
+
+    #[test]
+    fn test_sessions_data_model_summary() {
+        let sessions = vec![make_session(
+            "a",
+            8,
+            &[("claude-haiku-4", 0.01), ("claude-opus-4", 0.5)],
+        )];
+        let data = SessionsData::from_sessions(&sessions);
+
+        assert_eq!(
+            data.sessions[0].models,
+            format!("{} +1", display_name("claude-opus-4"))
+        );
+    }
+
+    #[test]
+    fn test_sessions_data_max_scroll_offset() {
+        let sessions: Vec<SessionSummary> = (0..20)
+            .map(|h| make_session(&h.to_string(), h, &[("gpt-5", 0.1)]))
+            .collect();
+        let data = SessionsData::from_sessions(&sessions);
+
+        // 20 items, VISIBLE_ROWS = 15, so max offset = 5
+        assert_eq!(data.max_scroll_offset(), 5);
+        assert_eq!(SessionsData::default().max_scroll_offset(), 0);
+    }
+}
@@ -11,7 +11,7 @@ use ratatui::{
 use super::overview::format_number;
 use super::tabs::{Tab, TabBar};
 use crate::tui::theme::Theme;
-use crate::types::StatsData;
+use crate::types::{Forecast, PricingSource, StatsData};
 
 /// Maximum content width for Stats view (consistent with other views)
 const MAX_CONTENT_WIDTH: u16 = 170;
@@ -20,7 +20,7 @@ const MAX_CONTENT_WIDTH: u16 = 170;
 const CARD_WIDTH: u16 = 28;
 const CARD_HEIGHT: u16 = 5;
 
-/// Fixed number of columns for balanced 2x3 grid
+/// Fixed number of columns for a balanced 3-column grid
 const FIXED_COLS: usize = 3;
 
 /// Calculate number of cards per row based on available width (max 3 for balanced grid)
@@ -66,7 +66,7 @@ impl Widget for StatsView<'_> {
 
         // Calculate grid layout
         let cols = cards_per_row(centered_area.width);
-        let rows = 6_usize.div_ceil(cols); // 6 cards total
+        let rows = self.build_cards().len().div_ceil(cols);
         let grid_height = (rows as u16) * (CARD_HEIGHT + 1); // +1 for spacing
 
         let chunks = Layout::vertical([
@@ -203,9 +203,39 @@ impl StatsView<'_> {
                 value_color: self.theme.bar(),
                 border_color: self.theme.bar(),
             },
+            StatCard {
+                title: "Month Forecast".to_string(),
+                value: self
+                    .data
+                    .month_forecast
+                    .as_ref()
+                    .map(format_forecast)
+                    .unwrap_or_else(|| "N/A".to_string()),
+                value_color: self.theme.spike_warn(),
+                border_color: self.theme.spike_warn(),
+            },
+            self.pricing_card(),
         ]
     }
 
+    /// Pricing source and date; the bundled snapshot is highlighted as it may be stale
+    fn pricing_card(&self) -> StatCard {
+        let color = match self.data.pricing.map(|p| p.source) {
+            Some(PricingSource::Snapshot) | None => self.theme.spike_warn(),
+            Some(_) => self.theme.muted(),
+        };
+        StatCard {
+            title: "Pricing".to_string(),
+            value: self
+                .data
+                .pricing
+                .map(|p| format!("{} {}", p.source.label(), p.as_of.format("%Y-%m-%d")))
+                .unwrap_or_else(|| "Unavailable".to_string()),
+            value_color: color,
+            border_color: color,
+        }
+    }
+
     fn render_card(&self, area: Rect, buf: &mut Buffer, card: &StatCard) {
         // Draw card border with card-specific color
         let block = Block::default()
@@ -259,6 +289,14 @@ impl StatsView<'_> {
     }
 }
 
+/// Trend projection with its confidence band, e.g. "$412 ($380-$455)"
+fn format_forecast(forecast: &Forecast) -> String {
+    format!(
+        "${:.0} (${:.0}-${:.0})",
+        forecast.trend_usd, forecast.low_usd, forecast.high_usd
+    )
+}
+
 /// Internal card representation
 struct StatCard {
     title: String,
@@ -270,22 +308,71 @@ struct StatCard {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::{PricingInfo, UnpricedUsage};
     use chrono::NaiveDate;
 
-    #[test]
-    fn test_stats_view_builds_six_cards() {
-        let data = StatsData {
+    fn make_data() -> StatsData {
+        StatsData {
             total_tokens: 1000,
             daily_avg_tokens: 500,
             peak_day: Some((NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(), 1000)),
             total_cost: 1.50,
             daily_avg_cost: 0.75,
             active_days: 2,
-        };
+            week_forecast: None,
+            month_forecast: None,
+            pricing: None,
+            unpriced: UnpricedUsage::default(),
+        }
+    }
+
+    #[test]
+    fn test_stats_view_builds_eight_cards() {
+        let data = make_data();
         let view = StatsView::new(&data, Theme::Dark);
         let cards = view.build_cards();
 
-        assert_eq!(cards.len(), 6);
+        assert_eq!(cards.len(), 8);
+        assert_eq!(cards[6].title, "Month Forecast");
+        assert_eq!(cards[6].value, "N/A");
+        assert_eq!(cards[7].title, "Pricing");
+        assert_eq!(cards[7].value, "Unavailable");
+    }
+
+    #[test]
+    fn test_pricing_card_shows_source_and_date() {
+        let data = make_data().with_pricing(Some(PricingInfo {
+            source: PricingSource::Snapshot,
+            as_of: NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(),
+        }));
+        let view = StatsView::new(&data, Theme::Dark);
+        let card = &view.build_cards()[7];
+
+        assert_eq!(card.value, "Bundled 2026-10-01");
+        assert_eq!(card.value_color, Theme::Dark.spike_warn());
+    }
+
+    #[test]
+    fn test_forecast_card_shows_trend_and_band() {
+        let data = make_data().with_forecasts(
+            None,
+            Some(Forecast {
+                start: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                end: NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(),
+                elapsed_days: 15,
+                total_days: 31,
+                spent_usd: 200.0,
+                linear_usd: 413.3,
+                trend_usd: 412.4,
+                low_usd: 380.2,
+                high_usd: 455.0,
+            }),
+        );
+        let view = StatsView::new(&data, Theme::Dark);
+        let value = &view.build_cards()[6].value;
+
+        assert_eq!(value, "$412 ($380-$455)");
+        assert!(value.len() as u16 <= CARD_WIDTH - 2);
     }
 
     #[test]
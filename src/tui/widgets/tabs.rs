@@ -17,6 +17,7 @@ pub enum Tab {
     Daily,
     Models,
     Stats,
+    Sessions,
 }
 
 impl Tab {
@@ -27,12 +28,19 @@ impl Tab {
             Self::Models => "Models",
             Self::Daily => "Daily",
             Self::Stats => "Stats",
+            Self::Sessions => "Sessions",
         }
     }
 
     /// Get all tabs in order
     pub fn all() -> &'static [Tab] {
-        &[Tab::Overview, Tab::Daily, Tab::Models, Tab::Stats]
+        &[
+            Tab::Overview,
+            Tab::Daily,
+            Tab::Models,
+            Tab::Stats,
+            Tab::Sessions,
+        ]
     }
 
     /// Get the next tab (wrapping)
@@ -41,27 +49,30 @@ impl Tab {
             Self::Overview => Self::Daily,
             Self::Daily => Self::Models,
             Self::Models => Self::Stats,
-            Self::Stats => Self::Overview,
+            Self::Stats => Self::Sessions,
+            Self::Sessions => Self::Overview,
         }
     }
 
     /// Get the previous tab (wrapping)
     pub fn prev(self) -> Self {
         match self {
-            Self::Overview => Self::Stats,
+            Self::Overview => Self::Sessions,
             Self::Daily => Self::Overview,
             Self::Models => Self::Daily,
             Self::Stats => Self::Models,
+            Self::Sessions => Self::Stats,
         }
     }
 
-    /// Get tab from number key (1-4)
+    /// Get tab from number key (1-5)
     pub fn from_number(n: u8) -> Option<Self> {
         match n {
             1 => Some(Self::Overview),
             2 => Some(Self::Daily),
             3 => Some(Self::Models),
             4 => Some(Self::Stats),
+            5 => Some(Self::Sessions),
             _ => None,
         }
     }
@@ -135,6 +146,43 @@ impl Widget for TabBar {
     }
 }
 
+/// Header warning that some usage has no known price, so costs are a lower bound.
+/// Drawn right-aligned on the top line, above the tab bar.
+pub struct UnpricedBadge {
+    models: usize,
+    theme: Theme,
+}
+
+impl UnpricedBadge {
+    pub fn new(models: usize, theme: Theme) -> Self {
+        Self { models, theme }
+    }
+
+    fn text(&self) -> String {
+        match self.models {
+            1 => "⚠ 1 unpriced model".to_string(),
+            n => format!("⚠ {} unpriced models", n),
+        }
+    }
+}
+
+impl Widget for UnpricedBadge {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if self.models == 0 || area.height == 0 {
+            return;
+        }
+        let text = self.text();
+        let width = text.chars().count() as u16 + 1; // right margin
+        if width > area.width {
+            return;
+        }
+        let style = Style::default()
+            .fg(self.theme.spike_warn())
+            .add_modifier(Modifier::BOLD);
+        buf.set_string(area.x + area.width - width, area.y, &text, style);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -145,16 +193,18 @@ mod tests {
         assert_eq!(Tab::Models.label(), "Models");
         assert_eq!(Tab::Daily.label(), "Daily");
         assert_eq!(Tab::Stats.label(), "Stats");
+        assert_eq!(Tab::Sessions.label(), "Sessions");
     }
 
     #[test]
     fn test_tab_all() {
         let all = Tab::all();
-        assert_eq!(all.len(), 4);
+        assert_eq!(all.len(), 5);
         assert_eq!(all[0], Tab::Overview);
         assert_eq!(all[1], Tab::Daily);
         assert_eq!(all[2], Tab::Models);
         assert_eq!(all[3], Tab::Stats);
+        assert_eq!(all[4], Tab::Sessions);
     }
 
     #[test]
@@ -162,17 +212,33 @@ mod tests {
         assert_eq!(Tab::Overview.next(), Tab::Daily);
         assert_eq!(Tab::Daily.next(), Tab::Models);
         assert_eq!(Tab::Models.next(), Tab::Stats);
-        assert_eq!(Tab::Stats.next(), Tab::Overview);
+        assert_eq!(Tab::Stats.next(), Tab::Sessions);
+        assert_eq!(Tab::Sessions.next(), Tab::Overview);
     }
 
     #[test]
     fn test_tab_prev() {
-        assert_eq!(Tab::Overview.prev(), Tab::Stats);
+        assert_eq!(Tab::Overview.prev(), Tab::Sessions);
+        assert_eq!(Tab::Sessions.prev(), Tab::Stats);
         assert_eq!(Tab::Stats.prev(), Tab::Models);
         assert_eq!(Tab::Models.prev(), Tab::Daily);
         assert_eq!(Tab::Daily.prev(), Tab::Overview);
     }
 
+    #[test]
+    fn test_unpriced_badge_right_aligned_on_top_line() {
+        let area = Rect::new(0, 0, 40, 2);
+        let mut buf = Buffer::empty(area);
+        UnpricedBadge::new(2, Theme::Dark).render(area, &mut buf);
+
+        let top: String = (0..40).map(|x| buf[(x, 0)].symbol()).collect();
+        assert_eq!(top, format!("{:>39} ", "⚠ 2 unpriced models"));
+
+        let mut empty = Buffer::empty(area);
+        UnpricedBadge::new(0, Theme::Dark).render(area, &mut empty);
+        assert_eq!(empty, Buffer::empty(area));
+    }
+
     #[test]
     fn test_tab_default() {
         assert_eq!(Tab::default(), Tab::Overview);
@@ -184,7 +250,8 @@ mod tests {
         assert_eq!(Tab::from_number(2), Some(Tab::Daily));
         assert_eq!(Tab::from_number(3), Some(Tab::Models));
         assert_eq!(Tab::from_number(4), Some(Tab::Stats));
+        assert_eq!(Tab::from_number(5), Some(Tab::Sessions));
         assert_eq!(Tab::from_number(0), None);
-        assert_eq!(Tab::from_number(5), None);
+        assert_eq!(Tab::from_number(6), None);
     }
 }
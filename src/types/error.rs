@@ -23,7 +23,6 @@ pub enum ToktrackError {
 
     /// Configuration error
     #[error("config error: {0}")]
-    #[allow(dead_code)]
     Config(String),
 }
 
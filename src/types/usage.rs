@@ -2,7 +2,7 @@
 
 use chrono::{DateTime, Local, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct StatsData {
@@ -12,6 +12,90 @@ pub struct StatsData {
     pub total_cost: f64,
     pub daily_avg_cost: f64,
     pub active_days: u32,
+    /// Cost projection for the current week (`None` if the report range doesn't cover it)
+    pub week_forecast: Option<Forecast>,
+    /// Cost projection for the current month (`None` if the report range doesn't cover it)
+    pub month_forecast: Option<Forecast>,
+    /// Price table used for costs computed in this run
+    pub pricing: Option<PricingInfo>,
+    /// Tokens left out of `total_cost` because no price was found
+    pub unpriced: UnpricedUsage,
+}
+
+/// Where the price table came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PricingSource {
+    /// Fetched from LiteLLM during this run
+    Live,
+    /// Read from `~/.toktrack/pricing.json` (may be past its TTL when offline)
+    Cache,
+    /// Snapshot bundled into the binary; used when neither cache nor network is available
+    Snapshot,
+}
+
+impl PricingSource {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Live => "Live",
+            Self::Cache => "Cache",
+            Self::Snapshot => "Bundled",
+        }
+    }
+}
+
+/// Pricing source and the date its rates were fetched
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PricingInfo {
+    pub source: PricingSource,
+    pub as_of: NaiveDate,
+}
+
+/// Tokens of models no price was found for, so costs are a lower bound
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct UnpricedUsage {
+    pub total_tokens: u64,
+    /// Unpriced tokens by model name
+    pub models: BTreeMap<String, u64>,
+}
+
+impl UnpricedUsage {
+    pub fn from_daily_summaries(summaries: &[DailySummary]) -> Self {
+        let mut models: BTreeMap<String, u64> = BTreeMap::new();
+        for (model, usage) in summaries.iter().flat_map(|s| &s.models) {
+            if usage.unpriced_tokens > 0 {
+                let tokens = models.entry(model.clone()).or_default();
+                *tokens = tokens.saturating_add(usage.unpriced_tokens);
+            }
+        }
+        Self {
+            total_tokens: models.values().fold(0, |acc, t| acc.saturating_add(*t)),
+            models,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.total_tokens == 0
+    }
+}
+
+/// Projected cost for the period containing today
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Forecast {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    /// Days elapsed in the period, including today
+    pub elapsed_days: u32,
+    pub total_days: u32,
+    /// Cost so far this period
+    pub spent_usd: f64,
+    /// Spend so far extrapolated at the period's average daily rate
+    pub linear_usd: f64,
+    /// Spend so far plus the remaining days at a recency-weighted daily rate
+    pub trend_usd: f64,
+    /// Lower/upper bound of the confidence band around both projections
+    pub low_usd: f64,
+    pub high_usd: f64,
 }
 
 impl StatsData {
@@ -24,6 +108,10 @@ impl StatsData {
                 total_cost: 0.0,
                 daily_avg_cost: 0.0,
                 active_days: 0,
+                week_forecast: None,
+                month_forecast: None,
+                pricing: None,
+                unpriced: UnpricedUsage::default(),
             };
         }
 
@@ -63,18 +151,47 @@ impl StatsData {
             total_cost,
             daily_avg_cost,
             active_days,
+            week_forecast: None,
+            month_forecast: None,
+            pricing: None,
+            unpriced: UnpricedUsage::from_daily_summaries(summaries),
         }
     }
+
+    /// Attach week/month forecasts
+    pub fn with_forecasts(mut self, week: Option<Forecast>, month: Option<Forecast>) -> Self {
+        self.week_forecast = week;
+        self.month_forecast = month;
+        self
+    }
+
+    /// Attach the pricing source
+    pub fn with_pricing(mut self, pricing: Option<PricingInfo>) -> Self {
+        self.pricing = pricing;
+        self
+    }
 }
 
+/// A single usage record in canonical token semantics.
+///
+/// The token fields are disjoint so they can be summed and priced
+/// independently; each parser converts its CLI's counts into this shape.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct UsageEntry {
     pub timestamp: DateTime<Utc>,
     pub model: Option<String>,
+    /// Uncached input (excludes cache reads and cache writes)
     pub input_tokens: u64,
+    /// Output, excluding reasoning/thinking
     pub output_tokens: u64,
+    /// Input served from the prompt cache
     pub cache_read_tokens: u64,
+    /// Input written to the prompt cache
     pub cache_creation_tokens: u64,
+    /// Part of `cache_creation_tokens` written with a 1-hour TTL (the rest is 5-minute)
+    #[serde(default)]
+    pub cache_creation_1h_tokens: u64,
+    /// Reasoning/thinking output
     #[serde(default)]
     pub thinking_tokens: u64,
     pub cost_usd: Option<f64>,
@@ -85,10 +202,36 @@ pub struct UsageEntry {
     /// Provider ID (e.g., "anthropic", "github-copilot")
     #[serde(default)]
     pub provider: Option<String>,
+    /// Project path the session ran in (Claude Code only, decoded from its directory name)
+    #[serde(default)]
+    pub project: Option<String>,
+    /// Session identifier within the source CLI
+    #[serde(default)]
+    pub session_id: Option<String>,
+    /// Server-side web search requests (Claude only)
+    #[serde(default)]
+    pub web_search_requests: u64,
+    /// Provider service tier, e.g. "standard", "priority" or "batch"
+    #[serde(default)]
+    pub service_tier: Option<String>,
+    /// Web search charge included in `cost_usd`, set when cost is calculated
+    #[serde(default)]
+    pub web_search_cost_usd: f64,
+    /// Service-tier adjustment included in `cost_usd` (negative for discounts)
+    #[serde(default)]
+    pub service_tier_cost_usd: f64,
+    /// Token part of `cost_usd` split by token type, set when cost is known
+    #[serde(default)]
+    pub token_costs: TokenCosts,
+    /// Threshold of the long-context tier the tokens were billed at
+    #[serde(default)]
+    pub long_context_tier: Option<u64>,
+    /// `cost_usd` was reported by the source rather than calculated
+    #[serde(default)]
+    pub cost_reported: bool,
 }
 
 impl UsageEntry {
-    #[allow(dead_code)]
     pub fn total_tokens(&self) -> u64 {
         self.input_tokens
             + self.output_tokens
@@ -97,6 +240,11 @@ impl UsageEntry {
             + self.thinking_tokens
     }
 
+    /// Whole prompt of the request, cached or not (decides long-context tiers)
+    pub fn prompt_tokens(&self) -> u64 {
+        self.input_tokens + self.cache_read_tokens + self.cache_creation_tokens
+    }
+
     /// Convert UTC timestamp to local timezone date.
     /// Ensures date grouping matches the user's local calendar.
     pub fn local_date(&self) -> NaiveDate {
@@ -128,7 +276,20 @@ pub struct DailySummary {
     #[serde(default)]
     pub total_thinking_tokens: u64,
     pub total_cost_usd: f64,
+    /// Token part of `total_cost_usd` split by token type
+    #[serde(default)]
+    pub total_token_costs: TokenCosts,
     pub models: HashMap<String, ModelUsage>,
+    /// Usage keyed by project path (empty for sources without project info)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub projects: HashMap<String, ModelUsage>,
+    /// Per-model usage keyed by provider (see `services::provider`)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub providers: HashMap<String, HashMap<String, ModelUsage>>,
+    /// Per-model usage keyed by source CLI, filled in when per-source
+    /// summaries are merged (cached summaries are already per source)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub sources: HashMap<String, HashMap<String, ModelUsage>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
@@ -137,10 +298,121 @@ pub struct ModelUsage {
     pub output_tokens: u64,
     pub cache_read_tokens: u64,
     pub cache_creation_tokens: u64,
+    /// Part of `cache_creation_tokens` written with a 1-hour TTL
+    #[serde(default)]
+    pub cache_creation_1h_tokens: u64,
     #[serde(default)]
     pub thinking_tokens: u64,
     pub cost_usd: f64,
     pub count: u64,
+    #[serde(default)]
+    pub web_search_requests: u64,
+    /// Web search charges, included in `cost_usd`
+    #[serde(default)]
+    pub web_search_cost_usd: f64,
+    /// Service-tier adjustments, included in `cost_usd`
+    #[serde(default)]
+    pub service_tier_cost_usd: f64,
+    /// Tokens of requests no price was found for; their cost is missing from `cost_usd`
+    #[serde(default)]
+    pub unpriced_tokens: u64,
+    /// Token part of `cost_usd` split by token type
+    #[serde(default)]
+    pub token_costs: TokenCosts,
+    /// Some of `cost_usd` was reported by the source rather than calculated
+    #[serde(default)]
+    pub cost_reported: bool,
+    /// Largest prompt of a single request; `None` for days cached before it was recorded
+    #[serde(default)]
+    pub max_prompt_tokens: Option<u64>,
+    /// Part of the token counts billed at a long-context tier, by tier threshold
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tier_tokens: BTreeMap<u64, TierTokens>,
+}
+
+/// Token counts of the requests billed at one long-context tier
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub struct TierTokens {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cache_read_tokens: u64,
+    pub cache_creation_tokens: u64,
+    #[serde(default)]
+    pub cache_creation_1h_tokens: u64,
+    #[serde(default)]
+    pub thinking_tokens: u64,
+}
+
+impl std::ops::AddAssign for TierTokens {
+    fn add_assign(&mut self, other: Self) {
+        self.input_tokens = self.input_tokens.saturating_add(other.input_tokens);
+        self.output_tokens = self.output_tokens.saturating_add(other.output_tokens);
+        self.cache_read_tokens = self
+            .cache_read_tokens
+            .saturating_add(other.cache_read_tokens);
+        self.cache_creation_tokens = self
+            .cache_creation_tokens
+            .saturating_add(other.cache_creation_tokens);
+        self.cache_creation_1h_tokens = self
+            .cache_creation_1h_tokens
+            .saturating_add(other.cache_creation_1h_tokens);
+        self.thinking_tokens = self.thinking_tokens.saturating_add(other.thinking_tokens);
+    }
+}
+
+/// Token cost split by token type, service-tier adjustment included.
+///
+/// With the web search charge it adds up to the cost. Reported costs are split
+/// in the proportions list prices give; costs of unpriced models stay unsplit.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub struct TokenCosts {
+    pub input_usd: f64,
+    pub output_usd: f64,
+    pub cache_write_usd: f64,
+    pub cache_read_usd: f64,
+    pub thinking_usd: f64,
+}
+
+impl TokenCosts {
+    pub fn total(&self) -> f64 {
+        self.input_usd
+            + self.output_usd
+            + self.cache_write_usd
+            + self.cache_read_usd
+            + self.thinking_usd
+    }
+
+    /// Labelled parts, in display order
+    pub fn parts(&self) -> [(&'static str, f64); 5] {
+        [
+            ("output", self.output_usd),
+            ("input", self.input_usd),
+            ("cache write", self.cache_write_usd),
+            ("cache read", self.cache_read_usd),
+            ("thinking", self.thinking_usd),
+        ]
+    }
+
+    /// Every part multiplied by `ratio`
+    pub fn scaled(self, ratio: f64) -> Self {
+        Self {
+            input_usd: self.input_usd * ratio,
+            output_usd: self.output_usd * ratio,
+            cache_write_usd: self.cache_write_usd * ratio,
+            cache_read_usd: self.cache_read_usd * ratio,
+            thinking_usd: self.thinking_usd * ratio,
+        }
+    }
+}
+
+impl std::ops::AddAssign for TokenCosts {
+    fn add_assign(&mut self, other: Self) {
+        self.input_usd += other.input_usd;
+        self.output_usd += other.output_usd;
+        self.cache_write_usd += other.cache_write_usd;
+        self.cache_read_usd += other.cache_read_usd;
+        self.thinking_usd += other.thinking_usd;
+    }
 }
 
 impl ModelUsage {
@@ -153,9 +425,43 @@ impl ModelUsage {
         self.cache_creation_tokens = self
             .cache_creation_tokens
             .saturating_add(entry.cache_creation_tokens);
+        self.cache_creation_1h_tokens = self
+            .cache_creation_1h_tokens
+            .saturating_add(entry.cache_creation_1h_tokens);
         self.thinking_tokens = self.thinking_tokens.saturating_add(entry.thinking_tokens);
         self.cost_usd += cost;
         self.count = self.count.saturating_add(1);
+        self.web_search_requests = self
+            .web_search_requests
+            .saturating_add(entry.web_search_requests);
+        self.web_search_cost_usd += entry.web_search_cost_usd;
+        self.service_tier_cost_usd += entry.service_tier_cost_usd;
+        self.token_costs += entry.token_costs;
+        if entry.cost_usd.is_none() {
+            self.unpriced_tokens = self.unpriced_tokens.saturating_add(entry.total_tokens());
+        }
+        self.cost_reported |= entry.cost_reported;
+        self.max_prompt_tokens = Some(
+            self.max_prompt_tokens
+                .unwrap_or(0)
+                .max(entry.prompt_tokens()),
+        );
+        if let Some(threshold) = entry.long_context_tier {
+            *self.tier_tokens.entry(threshold).or_default() += TierTokens {
+                input_tokens: entry.input_tokens,
+                output_tokens: entry.output_tokens,
+                cache_read_tokens: entry.cache_read_tokens,
+                cache_creation_tokens: entry.cache_creation_tokens,
+                cache_creation_1h_tokens: entry.cache_creation_1h_tokens,
+                thinking_tokens: entry.thinking_tokens,
+            };
+        }
+    }
+
+    /// Cache writes with the default 5-minute TTL
+    pub fn cache_creation_5m_tokens(&self) -> u64 {
+        self.cache_creation_tokens
+            .saturating_sub(self.cache_creation_1h_tokens)
     }
 }
 
@@ -172,6 +478,35 @@ pub struct TotalSummary {
     pub day_count: u64,
 }
 
+/// Usage aggregated over a single CLI session
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SessionSummary {
+    pub session_id: String,
+    pub source: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub duration_secs: i64,
+    pub total_input_tokens: u64,
+    pub total_output_tokens: u64,
+    pub total_cache_read_tokens: u64,
+    pub total_cache_creation_tokens: u64,
+    pub total_thinking_tokens: u64,
+    pub total_cost_usd: f64,
+    pub models: HashMap<String, ModelUsage>,
+}
+
+impl SessionSummary {
+    pub fn total_tokens(&self) -> u64 {
+        self.total_input_tokens
+            + self.total_output_tokens
+            + self.total_cache_read_tokens
+            + self.total_cache_creation_tokens
+            + self.total_thinking_tokens
+    }
+}
+
 /// Usage aggregated by source CLI (claude, opencode, gemini, etc.)
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct SourceUsage {
@@ -203,7 +538,11 @@ mod tests {
             total_cache_creation_tokens: cache_creation,
             total_thinking_tokens: 0,
             total_cost_usd: cost,
+            total_token_costs: TokenCosts::default(),
             models: HashMap::new(),
+            projects: HashMap::new(),
+            providers: HashMap::new(),
+            sources: HashMap::new(),
         }
     }
 
@@ -281,12 +620,22 @@ mod tests {
             output_tokens: 50,
             cache_read_tokens: 20,
             cache_creation_tokens: 10,
+            cache_creation_1h_tokens: 0,
             thinking_tokens: 0,
             cost_usd: None,
             message_id: None,
             request_id: None,
             source: None,
             provider: None,
+            project: None,
+            session_id: None,
+            web_search_requests: 0,
+            service_tier: None,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            token_costs: TokenCosts::default(),
+            long_context_tier: None,
+            cost_reported: false,
         };
         assert_eq!(entry.total_tokens(), 180);
     }
@@ -300,12 +649,22 @@ mod tests {
             output_tokens: 50,
             cache_read_tokens: 20,
             cache_creation_tokens: 10,
+            cache_creation_1h_tokens: 0,
             thinking_tokens: 30,
             cost_usd: None,
             message_id: None,
             request_id: None,
             source: Some("gemini".into()),
             provider: None,
+            project: None,
+            session_id: None,
+            web_search_requests: 0,
+            service_tier: None,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            token_costs: TokenCosts::default(),
+            long_context_tier: None,
+            cost_reported: false,
         };
         assert_eq!(entry.total_tokens(), 210);
     }
@@ -319,12 +678,22 @@ mod tests {
             output_tokens: 0,
             cache_read_tokens: 0,
             cache_creation_tokens: 0,
+            cache_creation_1h_tokens: 0,
             thinking_tokens: 0,
             cost_usd: None,
             message_id: Some("msg123".into()),
             request_id: Some("req456".into()),
             source: None,
             provider: None,
+            project: None,
+            session_id: None,
+            web_search_requests: 0,
+            service_tier: None,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            token_costs: TokenCosts::default(),
+            long_context_tier: None,
+            cost_reported: false,
         };
         assert_eq!(entry.dedup_hash(), Some("msg123:req456".into()));
     }
@@ -338,12 +707,22 @@ mod tests {
             output_tokens: 0,
             cache_read_tokens: 0,
             cache_creation_tokens: 0,
+            cache_creation_1h_tokens: 0,
             thinking_tokens: 0,
             cost_usd: None,
             message_id: None,
             request_id: Some("req456".into()),
             source: None,
             provider: None,
+            project: None,
+            session_id: None,
+            web_search_requests: 0,
+            service_tier: None,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            token_costs: TokenCosts::default(),
+            long_context_tier: None,
+            cost_reported: false,
         };
         assert_eq!(entry.dedup_hash(), None);
     }
@@ -357,12 +736,22 @@ mod tests {
             output_tokens: 50,
             cache_read_tokens: 0,
             cache_creation_tokens: 0,
+            cache_creation_1h_tokens: 0,
             thinking_tokens: 0,
             cost_usd: None,
             message_id: Some("msg789".into()),
             request_id: None,
             source: None,
             provider: None,
+            project: None,
+            session_id: None,
+            web_search_requests: 0,
+            service_tier: None,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            token_costs: TokenCosts::default(),
+            long_context_tier: None,
+            cost_reported: false,
         };
         assert_eq!(entry.dedup_hash(), Some("msg789:gpt-4:100:50".into()));
     }
@@ -381,12 +770,22 @@ mod tests {
             output_tokens: 50,
             cache_read_tokens: 0,
             cache_creation_tokens: 0,
+            cache_creation_1h_tokens: 0,
             thinking_tokens: 0,
             cost_usd: None,
             message_id: None,
             request_id: None,
             source: None,
             provider: None,
+            project: None,
+            session_id: None,
+            web_search_requests: 0,
+            service_tier: None,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            token_costs: TokenCosts::default(),
+            long_context_tier: None,
+            cost_reported: false,
         };
 
         let local_date = entry.local_date();
@@ -405,12 +804,22 @@ mod tests {
             output_tokens: 0,
             cache_read_tokens: 0,
             cache_creation_tokens: 0,
+            cache_creation_1h_tokens: 0,
             thinking_tokens: 0,
             cost_usd: None,
             message_id: None,
             request_id: None,
             source: None,
             provider: None,
+            project: None,
+            session_id: None,
+            web_search_requests: 0,
+            service_tier: None,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            token_costs: TokenCosts::default(),
+            long_context_tier: None,
+            cost_reported: false,
         };
         let local = late_entry.local_date();
         let utc_naive = late_utc.date_naive();
@@ -431,19 +840,110 @@ mod tests {
             output_tokens: 50,
             cache_read_tokens: 20,
             cache_creation_tokens: 10,
+            cache_creation_1h_tokens: 4,
             thinking_tokens: 0,
             cost_usd: None,
             message_id: None,
             request_id: None,
             source: None,
             provider: None,
+            project: None,
+            session_id: None,
+            web_search_requests: 0,
+            service_tier: None,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            token_costs: TokenCosts::default(),
+            long_context_tier: None,
+            cost_reported: false,
         };
         usage.add(&entry, 0.01);
 
         assert_eq!(usage.input_tokens, 100);
         assert_eq!(usage.output_tokens, 50);
         assert_eq!(usage.cache_read_tokens, 20);
+        assert_eq!(usage.cache_creation_1h_tokens, 4);
+        assert_eq!(usage.cache_creation_5m_tokens(), 6);
         assert_eq!(usage.cost_usd, 0.01);
         assert_eq!(usage.count, 1);
+
+        let entry = UsageEntry {
+            web_search_requests: 2,
+            web_search_cost_usd: 0.02,
+            service_tier_cost_usd: -0.005,
+            ..entry
+        };
+        usage.add(&entry, 0.025);
+        assert_eq!(usage.web_search_requests, 2);
+        assert_eq!(usage.web_search_cost_usd, 0.02);
+        assert_eq!(usage.service_tier_cost_usd, -0.005);
+        assert_eq!(usage.count, 2);
+    }
+
+    #[test]
+    fn test_unpriced_tokens_counted_from_uncosted_entries() {
+        let entry = UsageEntry {
+            timestamp: Utc::now(),
+            model: Some("internal-llm".into()),
+            input_tokens: 100,
+            output_tokens: 50,
+            cache_read_tokens: 20,
+            cache_creation_tokens: 10,
+            cache_creation_1h_tokens: 0,
+            thinking_tokens: 5,
+            cost_usd: None,
+            message_id: None,
+            request_id: None,
+            source: None,
+            provider: None,
+            project: None,
+            session_id: None,
+            web_search_requests: 0,
+            service_tier: None,
+            web_search_cost_usd: 0.0,
+            service_tier_cost_usd: 0.0,
+            token_costs: TokenCosts::default(),
+            long_context_tier: None,
+            cost_reported: false,
+        };
+        let mut unpriced = ModelUsage::default();
+        unpriced.add(&entry, 0.0);
+        unpriced.add(&entry, 0.0);
+        assert_eq!(unpriced.unpriced_tokens, 370);
+
+        let mut priced = ModelUsage::default();
+        priced.add(
+            &UsageEntry {
+                cost_usd: Some(0.0),
+                ..entry
+            },
+            0.0,
+        );
+        assert_eq!(priced.unpriced_tokens, 0);
+
+        let summary = |date: u32| DailySummary {
+            date: NaiveDate::from_ymd_opt(2026, 9, date).unwrap(),
+            total_input_tokens: 0,
+            total_output_tokens: 0,
+            total_cache_read_tokens: 0,
+            total_cache_creation_tokens: 0,
+            total_thinking_tokens: 0,
+            total_cost_usd: 0.0,
+            total_token_costs: TokenCosts::default(),
+            models: HashMap::from([
+                ("internal-llm".to_string(), unpriced.clone()),
+                ("gpt-5".to_string(), priced.clone()),
+            ]),
+            projects: HashMap::new(),
+            providers: HashMap::new(),
+            sources: HashMap::new(),
+        };
+        let totals = UnpricedUsage::from_daily_summaries(&[summary(1), summary(2)]);
+        assert_eq!(totals.total_tokens, 740);
+        assert_eq!(
+            totals.models,
+            BTreeMap::from([("internal-llm".to_string(), 740)])
+        );
+        assert!(UnpricedUsage::from_daily_summaries(&[]).is_empty());
     }
 }